@@ -1526,7 +1526,7 @@ impl App {
             .add_dataset(Dataset::new("C").with_data(vec![20.0, 25.0, 30.0, 35.0]));
         if let Some(mut chart) = self.ui.widget(ids!(detail_bar_stacked)).borrow_mut::<BarChart>() {
             chart.set_data(stacked_data);
-            chart.set_stacked(true);
+            chart.set_stacked(StackMode::Absolute);
             chart.set_options(ChartOptions::new().with_begin_at_zero(true));
         }
 
@@ -1588,7 +1588,7 @@ impl App {
             .add_dataset(Dataset::new("C").with_data(vec![20.0, 25.0, 30.0, 35.0]));
         if let Some(mut chart) = self.ui.widget(ids!(detail_bar_gradient_stacked)).borrow_mut::<BarChart>() {
             chart.set_data(gradient_stacked);
-            chart.set_stacked(true);
+            chart.set_stacked(StackMode::Absolute);
             chart.set_gradient(true);
             chart.set_options(ChartOptions::new().with_begin_at_zero(true));
             chart.replay_animation(cx);
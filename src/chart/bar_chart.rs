@@ -4,6 +4,8 @@ use crate::coord::*;
 use crate::scale::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartAnnotations, ChartAxis, ChartBrush, BrushTarget, ChartDataLabels, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LabelTarget, LegendItemData, TooltipContent, format_stacked_value};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
@@ -16,6 +18,10 @@ live_design! {
     pub BarChart = {{BarChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -31,6 +37,24 @@ pub struct BarChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
+    #[live]
+    brush: ChartBrush,
+
+    #[live]
+    annotations: ChartAnnotations,
+
     #[rust]
     data: ChartData,
 
@@ -40,6 +64,21 @@ pub struct BarChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
@@ -50,17 +89,22 @@ pub struct BarChart {
     #[rust]
     initialized: bool,
 
-    /// Bar width as percentage of band (0.0 - 1.0)
-    #[rust(0.8)]
-    bar_percent: f64,
+    /// Share of each category taken by its bars (0.0 - 1.0), overriding the
+    /// `category_percentage` of every dataset when set
+    #[rust]
+    category_percent: Option<f64>,
 
     /// Hover state - index of hovered bar (-1 for none)
     #[rust(-1)]
     hovered_bar: i32,
 
-    /// Whether to stack bars on top of each other
-    #[rust(false)]
-    stacked: bool,
+    /// Base and top of every bar while the Y axis is stacked, empty otherwise
+    #[rust]
+    stacks: Vec<Vec<Option<(f64, f64)>>>,
+
+    /// Labels of the bars drawn in the current frame
+    #[rust]
+    data_labels: ChartDataLabels,
 
     /// Enable delay animation (bars animate in sequence)
     #[rust(false)]
@@ -83,12 +127,31 @@ impl Widget for BarChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+        let target = BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks);
+        if self.brush.handle_event(cx, event, scope, uid, &target) {
+            self.redraw(cx);
+        }
+        self.annotations.handle_event(cx, event, scope, uid, &self.coord);
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
             }
             Event::MouseDown(e) => {
-                self.handle_mouse_down(cx, e.abs);
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
                 let time = cx.seconds_since_app_start();
@@ -128,7 +191,7 @@ impl Widget for BarChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.update_coord(cx, rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -138,25 +201,39 @@ impl Widget for BarChart {
             self.draw_background(cx, rect);
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.hit_tester.clear();
+            self.brush.draw(cx, &self.coord);
+            self.zoom.begin_clip(cx, &self.coord);
+            self.annotations.draw_below(cx, &self.coord, &self.options.annotations);
             self.draw_bars(cx);
+            self.data_labels.draw(cx, &mut self.draw_label, self.coord.chart_area().to_rect());
+            self.annotations.draw_above(cx, &self.coord, &self.options.annotations);
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
             self.draw_labels(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
     }
 }
 
-
 impl BarChart {
     /// Set chart data
     pub fn set_data(&mut self, data: ChartData) {
         self.data = data;
         self.initialized = false;
         self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks));
     }
 
-    /// Set chart options
+    /// Set chart options, keeping the stack mode of `set_stacked` unless they set one
     pub fn set_options(&mut self, options: ChartOptions) {
+        let options = options.with_stacking_of(&self.options);
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.effective_zoom());
+        self.brush.set_options(options.brush.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -164,20 +241,59 @@ impl BarChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
+    /// Select the bars whose category and value are inside `x` and `y` as if brushed;
+    /// `None` leaves an axis unbounded
+    pub fn set_selection(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.brush.select(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks), x, y);
+        self.redraw(cx);
+    }
+
+    /// Clear the brush selection
+    pub fn clear_selection(&mut self, cx: &mut Cx) {
+        if self.brush.clear() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Current brush selection
+    pub fn selection(&self) -> Option<&ChartSelection> {
+        self.brush.selection()
+    }
+
     /// Get a reference to the data
     pub fn data(&self) -> &ChartData {
         &self.data
     }
 
-    /// Set bar width percentage
+    /// Set the share of each category taken by its bars for all datasets
     pub fn set_bar_percent(&mut self, percent: f64) {
-        self.bar_percent = percent.clamp(0.1, 1.0);
+        self.category_percent = Some(percent.clamp(0.1, 1.0));
     }
 
-    /// Set stacked mode
-    pub fn set_stacked(&mut self, stacked: bool) {
-        self.stacked = stacked;
+    /// Stack the bars on the Y axis, the same as setting `scales.y.stacked` in the options.
+    /// Datasets with different `stack` ids form separate stacks side by side.
+    pub fn set_stacked(&mut self, mode: impl Into<StackMode>) {
+        self.options.scales.y.stacked = mode.into();
         self.initialized = false;
+        self.setup_coord_from_data();
     }
 
     /// Enable delay animation (bars animate in sequence)
@@ -254,33 +370,43 @@ impl BarChart {
 
         self.coord = CartesianCoord::new()
             .with_x_scale(ScaleType::Category(category_scale))
-            .with_y_scale(ScaleType::Linear(
+            .with_y_scale(ScaleType::value_scale(
+                &self.options.scales.y,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.y.begin_at_zero)
                     .with_nice(true)
-            ));
-
-        // Set Y data range from data extent
-        if self.stacked {
-            // For stacked charts, calculate sum of all values at each index
-            let num_points = self.data.datasets.first().map(|d| d.data.len()).unwrap_or(0);
-            let mut max_sum = 0.0f64;
-            for i in 0..num_points {
-                let sum: f64 = self.data.datasets.iter()
-                    .filter_map(|d| d.data.get(i))
-                    .map(|p| p.y.max(0.0))
-                    .sum();
-                max_sum = max_sum.max(sum);
+            ))
+            .with_y_axes(&self.options.scales);
+
+        self.coord.apply_axis_options(&self.options.scales);
+
+        // Fit each Y axis to the datasets drawn against it, or to their stacks, and to its annotations
+        let scales = &self.options.scales;
+        self.stacks = if scales.y.stacked == StackMode::None { Vec::new() } else { stack_values(&self.data, scales, scales.y.stacked) };
+        for id in scales.y_axis_ids() {
+            let bound = |dataset: &Dataset| scales.y_axis_of(dataset) == id;
+            let extent = if self.stacks.is_empty() {
+                self.data.get_y_extent_where(|_, dataset| bound(dataset))
+            } else {
+                stack_extent(self.data.datasets.iter().zip(&self.stacks).filter(|(d, _)| bound(*d)).map(|(_, s)| s))
+            };
+            let extent = merge_extents(extent, annotation_y_extent(&self.options.annotations, id, |id| scales.y_axis_id(id)));
+            let min_positive = self.data.get_min_positive_y_where(|_, dataset| scales.y_axis_of(dataset) == id);
+            self.coord.set_y_axis_min_positive(id, min_positive);
+            if let Some((min, max)) = extent {
+                let min = if scales.y_axis(id).begin_at_zero && min > 0.0 { 0.0 } else { min };
+                self.coord.set_y_axis_data_range(id, min, max);
             }
-            self.coord.set_y_data_range(0.0, max_sum);
-        } else if let Some((min, max)) = self.data.get_y_extent() {
-            let min = if self.options.scales.y.begin_at_zero && min > 0.0 { 0.0 } else { min };
-            self.coord.set_y_data_range(min, max);
         }
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| self.data.datasets[i].get_background_color(i));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
     }
 
     fn start_animation(&mut self, cx: &mut Cx) {
@@ -333,173 +459,141 @@ impl BarChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        // Draw horizontal grid lines based on Y ticks
-        let tick_options = TickOptions::default();
-        let y_ticks = self.coord.build_y_ticks(&tick_options);
-
-        for tick in &y_ticks {
-            let (p1, p2) = self.coord.get_horizontal_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // For bar charts, X axis is always at the bottom (baseline for bars)
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.right, area.bottom), axis_width);
-
-        // Y axis is always at the left
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.left, area.top), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, false, false);
     }
 
     fn draw_bars(&mut self, cx: &mut Cx2d) {
-        let bar_width = self.coord.get_bar_width(self.bar_percent);
-        let num_datasets = self.data.datasets.len();
-
-        if self.stacked {
-            // Stacked bars - all bars have same width, stacked on top of each other
-            let num_points = self.data.datasets.first().map(|d| d.data.len()).unwrap_or(0);
-
-            for data_idx in 0..num_points {
-                let x_center = self.coord.x_scale().get_pixel_for_value(data_idx as f64);
-                let bar_x = x_center - bar_width / 2.0;
-
-                let mut cumulative_value = 0.0;
-
-                for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-                    let color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
-                    self.draw_bar.color = color;
-
-                    // Apply gradient if enabled
-                    if self.gradient_enabled {
-                        let lighter = lighten(color, 0.3);
-                        self.draw_bar.set_vertical_gradient(color, lighter);
-                    } else {
-                        self.draw_bar.disable_gradient();
-                    }
-
-                    if let Some(point) = dataset.data.get(data_idx) {
-                        let progress = self.get_bar_progress(dataset_idx, data_idx);
-                        let y_value = point.y.max(0.0) * progress;
-
-                        if y_value > 0.0 {
-                            // Calculate base position (top of previous bars)
-                            let base_value = cumulative_value;
-                            let top_value = cumulative_value + y_value;
-
-                            let base_y = self.coord.y_scale().get_pixel_for_value(base_value);
-                            let top_y = self.coord.y_scale().get_pixel_for_value(top_value);
-
-                            let bar_height = base_y - top_y;
-
-                            if bar_height > 0.5 {
-                                let bar_rect = Rect {
-                                    pos: dvec2(bar_x, top_y),
-                                    size: dvec2(bar_width, bar_height),
-                                };
-                                self.draw_bar.draw_bar(cx, bar_rect);
-                            }
-
-                            cumulative_value = top_value;
-                        }
-                    }
-                }
-            }
+        let ruler = self.coord.x_bar_ruler(&self.data);
+        let num_datasets = self.data.visible_dataset_count();
+        self.data_labels.clear();
+
+        // Slots sit side by side in each category: one per stack when stacked,
+        // otherwise one per visible dataset
+        let (slots, num_slots) = if self.stacks.is_empty() {
+            let mut next = 0;
+            let slots = self.data.datasets.iter().map(|dataset| {
+                (!dataset.hidden).then(|| {
+                    next += 1;
+                    next - 1
+                })
+            }).collect();
+            (slots, num_datasets)
         } else {
-            // Grouped bars (original behavior)
-            let group_bar_width = if num_datasets > 0 {
-                bar_width / num_datasets as f64
+            stack_groups(&self.data, &self.options.scales)
+        };
+
+        for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            let Some(slot) = slots[dataset_idx] else { continue };
+            let mut sizing = BarSizing::from(dataset);
+            sizing.category_percentage = self.category_percent.unwrap_or(sizing.category_percentage);
+            let y_scale = self.coord.y_scale_for(self.options.scales.y_axis_of(dataset));
+            let color = self.legend.highlight(dataset_idx, dataset.get_background_color(dataset_idx));
+            self.draw_bar.color = color;
+
+            // Apply gradient if enabled
+            if self.gradient_enabled {
+                let lighter = lighten(color, 0.3);
+                self.draw_bar.set_vertical_gradient(color, lighter);
             } else {
-                bar_width
-            };
+                self.draw_bar.disable_gradient();
+            }
 
-            for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-                let color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
-                self.draw_bar.color = color;
+            // Bars get a border when the dataset has a border color
+            match dataset.border_color {
+                Some(border_color) => {
+                    let border_color = self.legend.highlight(dataset_idx, border_color);
+                    self.draw_bar.set_border(dataset.border_width as f32, border_color);
+                }
+                None => self.draw_bar.disable_border(),
+            }
 
-                // Apply gradient if enabled
+            for (data_idx, point) in dataset.data.iter().enumerate() {
+                if point.is_missing() {
+                    continue;
+                }
+                let Some((bar_x, bar_width)) = ruler.bar(data_idx, slot, num_slots, &sizing) else { continue };
+                let progress = self.get_bar_progress(dataset_idx, data_idx);
+
+                // Stacked bars span their place in the stack; floating bars start
+                // at y_min and all others at zero. Animation grows them from zero.
+                let (base_value, top_value) = match self.stacks.get(dataset_idx).and_then(|stack| stack.get(data_idx)) {
+                    Some(Some(place)) => *place,
+                    _ => (point.y_min.unwrap_or(0.0), point.y),
+                };
+                let base_y = y_scale.get_pixel_for_value(base_value * progress);
+                let top_y = y_scale.get_pixel_for_value(top_value * progress);
+
+                // Negative values and reversed Y axes grow bars downwards
+                let bar_height = (base_y - top_y).abs();
+                let bar_top = top_y.min(base_y);
+
+                // Check if this bar is hovered
+                let is_hovered = self.hovered_bar >= 0 &&
+                    self.hovered_bar as usize == data_idx &&
+                    num_datasets == 1; // Simple hover for single dataset
+
+                // Slightly brighter on hover, dimmed outside the brush selection
+                let bar_color = if is_hovered { lighten(color, 0.15) } else { color };
+                let bar_color = self.brush.highlight(dataset_idx, data_idx, bar_color);
+                self.draw_bar.color = bar_color;
                 if self.gradient_enabled {
-                    let lighter = lighten(color, 0.3);
-                    self.draw_bar.set_vertical_gradient(color, lighter);
-                } else {
-                    self.draw_bar.disable_gradient();
+                    let lighter = lighten(bar_color, 0.3);
+                    self.draw_bar.set_vertical_gradient(bar_color, lighter);
                 }
 
-                let border_radius = 4.0;
-                self.draw_bar.set_top_radius(border_radius);
-
-                for (data_idx, point) in dataset.data.iter().enumerate() {
-                    let progress = self.get_bar_progress(dataset_idx, data_idx);
-                    let x_center = self.coord.x_scale().get_pixel_for_value(data_idx as f64);
-
-                    // Offset for grouped bars
-                    let group_offset = if num_datasets > 1 {
-                        let total_group_width = bar_width;
-                        let start_offset = -total_group_width / 2.0 + group_bar_width / 2.0;
-                        start_offset + dataset_idx as f64 * group_bar_width
-                    } else {
-                        0.0
+                if bar_height > 0.5 {
+                    let bar_rect = Rect {
+                        pos: dvec2(bar_x, bar_top),
+                        size: dvec2(bar_width, bar_height),
                     };
 
-                    let bar_x = x_center + group_offset - group_bar_width / 2.0;
-
-                    // Get y value with animation
-                    let y_value = point.y * progress;
-                    let y_pixel = self.coord.y_scale().get_pixel_for_value(y_value);
-
-                    // For floating bars, use y_min as base; otherwise use 0
-                    let base_value = point.y_min.map(|m| m * progress).unwrap_or(0.0);
-                    let base_y = self.coord.y_scale().get_pixel_for_value(base_value);
-
-                    // Calculate bar height (y_pixel is at top since Y is inverted)
-                    let bar_height = (base_y - y_pixel).abs();
-                    let bar_top = y_pixel.min(base_y);
-
-                    // Check if this bar is hovered
-                    let is_hovered = self.hovered_bar >= 0 &&
-                        self.hovered_bar as usize == data_idx &&
-                        num_datasets == 1; // Simple hover for single dataset
-
-                    if is_hovered {
-                        // Slightly brighter on hover
-                        let hover_color = lighten(color, 0.15);
-                        self.draw_bar.color = hover_color;
-                        if self.gradient_enabled {
-                            let lighter = lighten(hover_color, 0.3);
-                            self.draw_bar.set_vertical_gradient(hover_color, lighter);
-                        }
+                    // Round the end of the bar, and of a stack only its outer end
+                    let base = if base_y >= top_y { AxisPosition::Bottom } else { AxisPosition::Top };
+                    let radius = if self.ends_stack(&slots, dataset_idx, data_idx) {
+                        dataset.bar_border_radius
                     } else {
-                        self.draw_bar.color = color;
-                    }
+                        CornerRadius::default()
+                    };
+                    let (radii, sides) = bar_corners(bar_rect.size, base, radius, dataset.border_skipped);
+                    self.draw_bar.set_shape(radii, sides);
 
-                    if bar_height > 0.5 {
-                        let bar_rect = Rect {
-                            pos: dvec2(bar_x, bar_top),
-                            size: dvec2(group_bar_width, bar_height),
-                        };
+                    self.draw_bar.draw_bar(cx, bar_rect);
+                    self.hit_tester.register(bar_rect, HitData::Bar { dataset_index: dataset_idx, data_index: data_idx });
 
-                        self.draw_bar.draw_bar(cx, bar_rect);
+                    if let Some(label_options) = &dataset.data_labels {
+                        let context = DataLabelContext::of(&self.data, dataset_idx, data_idx);
+                        let target = LabelTarget::Bar { rect: bar_rect, base };
+                        self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, bar_color);
                     }
                 }
             }
         }
     }
 
-    fn draw_labels(&mut self, _cx: &mut Cx2d) {
-        // TODO: Draw axis labels using text rendering
-        // For now, labels are handled through the Makepad text system
-        // This would require integrating with DrawText
+    /// Whether a bar is the outermost of its stack at `data_index`, with no other bar
+    /// of the stack starting where it ends; unstacked bars always are
+    fn ends_stack(&self, slots: &[Option<usize>], dataset_index: usize, data_index: usize) -> bool {
+        let Some(Some((_, end))) = self.stacks.get(dataset_index).and_then(|stack| stack.get(data_index)) else {
+            return true;
+        };
+        !self.stacks.iter().enumerate().any(|(other, stack)| {
+            other != dataset_index
+                && slots[other] == slots[dataset_index]
+                && matches!(stack.get(data_index), Some(Some((base, top))) if base == end && top != base)
+        })
+    }
+
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn handle_mouse_move(&mut self, cx: &mut Cx, pos: DVec2) {
@@ -516,16 +610,59 @@ impl BarChart {
         if old_hovered != self.hovered_bar {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos);
     }
 
-    fn handle_mouse_down(&mut self, _cx: &mut Cx, pos: DVec2) {
-        if self.coord.contains_pixel(pos.x, pos.y) {
-            let x_value = self.coord.x_scale().get_value_for_pixel(pos.x);
-            let bar_index = x_value.round() as usize;
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let color_for = |ds: usize, _| data.datasets[ds].background_color.unwrap_or_else(|| get_color(ds));
+        let content = if self.stacks.is_empty() {
+            TooltipContent::from_hits(data, &hits, color_for)
+        } else {
+            // Stacked values also show the total up to them
+            let elements: Vec<(usize, usize)> = hits.iter().filter_map(|hit| hit.data.indices()).collect();
+            let mode = self.options.scales.y.stacked;
+            let stacks = &self.stacks;
+            TooltipContent::from_elements_formatted(data, &elements, color_for, |ds, i, point| {
+                let top = stacks[ds][i].map_or(point.y, |(_, top)| top);
+                format_stacked_value(point, top, mode)
+            })
+        };
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
 
-            // Could emit an action here for bar click events
-            log!("Bar clicked: index={}", bar_index);
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
         }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for BarChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks));
     }
 }
 
@@ -544,10 +681,10 @@ impl BarChartRef {
         }
     }
 
-    /// Set stacked mode
-    pub fn set_stacked(&self, stacked: bool) {
+    /// Stack the bars on the Y axis
+    pub fn set_stacked(&self, mode: impl Into<StackMode>) {
         if let Some(mut inner) = self.borrow_mut() {
-            inner.set_stacked(stacked);
+            inner.set_stacked(mode);
         }
     }
 
@@ -594,4 +731,48 @@ impl BarChartRef {
             false
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
+
+    pub fn set_selection(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_selection(cx, x, y);
+        }
+    }
+
+    pub fn clear_selection(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.clear_selection(cx);
+        }
+    }
+
+    pub fn selection(&self) -> Option<ChartSelection> {
+        self.borrow().and_then(|inner| inner.selection().cloned())
+    }
 }
+
+impl ChartActions for BarChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
+
+impl CartesianChartActions for BarChartRef {}
@@ -4,6 +4,8 @@ use crate::coord::*;
 use crate::scale::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartAxis, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LegendItemData, TooltipContent};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
@@ -16,6 +18,10 @@ live_design! {
     pub BubbleChart = {{BubbleChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -31,6 +37,18 @@ pub struct BubbleChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
     #[rust]
     data: ChartData,
 
@@ -40,6 +58,21 @@ pub struct BubbleChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
@@ -67,9 +100,26 @@ impl Widget for BubbleChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
+            }
+            Event::MouseDown(e) => {
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -93,7 +143,7 @@ impl Widget for BubbleChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.update_coord(cx, rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -102,7 +152,13 @@ impl Widget for BubbleChart {
 
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.hit_tester.clear();
+            self.zoom.begin_clip(cx, &self.coord);
             self.draw_bubbles(cx);
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
+            self.draw_labels(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -117,6 +173,9 @@ impl BubbleChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.zoom.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -124,6 +183,24 @@ impl BubbleChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
     pub fn set_radius_range(&mut self, min: f64, max: f64) {
         self.min_radius = min;
         self.max_radius = max;
@@ -135,17 +212,24 @@ impl BubbleChart {
     }
 
     fn setup_coord_from_data(&mut self) {
-        self.coord = CartesianCoord::new()
-            .with_x_scale(ScaleType::Linear(
+        let x_scale = match self.options.scales.x.scale_type {
+            AxisType::Time => ScaleType::Time(TimeScale::new().with_timestamps(self.data.get_x_values())),
+            _ => ScaleType::value_scale(
+                &self.options.scales.x,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.x.begin_at_zero)
                     .with_nice(true)
-            ))
-            .with_y_scale(ScaleType::Linear(
+            ),
+        };
+        self.coord = CartesianCoord::new()
+            .with_x_scale(x_scale)
+            .with_y_scale(ScaleType::value_scale(
+                &self.options.scales.y,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.y.begin_at_zero)
                     .with_nice(true)
             ));
+        self.coord.apply_axis_options(&self.options.scales);
 
         // Calculate data ranges
         let mut x_min = f64::MAX;
@@ -167,14 +251,20 @@ impl BubbleChart {
             let x_min = if self.options.scales.x.begin_at_zero && x_min > 0.0 { 0.0 } else { x_min };
             self.coord.set_x_data_range(x_min, x_max);
         }
+        self.coord.set_y_axis_min_positive(None, self.data.get_min_positive_y_where(|_, _| true));
         if y_min < y_max {
             let y_min = if self.options.scales.y.begin_at_zero && y_min > 0.0 { 0.0 } else { y_min };
             self.coord.set_y_data_range(y_min, y_max);
         }
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| self.data.datasets[i].get_background_color(i));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
     }
 
     fn start_animation(&mut self, cx: &mut Cx) {
@@ -209,53 +299,20 @@ impl BubbleChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        let tick_options = TickOptions::default();
-
-        // Draw horizontal grid lines based on Y ticks
-        let y_ticks = self.coord.build_y_ticks(&tick_options);
-        for tick in &y_ticks {
-            let (p1, p2) = self.coord.get_horizontal_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
-
-        // Draw vertical grid lines based on X ticks
-        let x_ticks = self.coord.build_x_ticks(&tick_options);
-        for tick in &x_ticks {
-            let (p1, p2) = self.coord.get_vertical_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // Draw X axis at y=0 if in range, otherwise at bottom
-        let (y_min, y_max) = self.coord.y_scale().get_data_bounds();
-        let x_axis_y = if y_min <= 0.0 && y_max >= 0.0 {
-            self.coord.y_scale().get_pixel_for_value(0.0)
-        } else {
-            area.bottom
-        };
-        self.draw_grid.draw_line(cx, dvec2(area.left, x_axis_y), dvec2(area.right, x_axis_y), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, true, true);
+    }
 
-        // Draw Y axis at x=0 if in range, otherwise at left
-        let (x_min, x_max) = self.coord.x_scale().get_data_bounds();
-        let y_axis_x = if x_min <= 0.0 && x_max >= 0.0 {
-            self.coord.x_scale().get_pixel_for_value(0.0)
-        } else {
-            area.left
-        };
-        self.draw_grid.draw_line(cx, dvec2(y_axis_x, area.bottom), dvec2(y_axis_x, area.top), axis_width);
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn get_bubble_radius(&self, r_value: f64, max_r: f64) -> f64 {
@@ -279,11 +336,15 @@ impl BubbleChart {
         let area = self.coord.chart_area();
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-            let color = dataset.background_color.unwrap_or_else(|| {
+            if dataset.hidden {
+                continue;
+            }
+            let color = self.legend.highlight(dataset_idx, dataset.background_color.unwrap_or_else(|| {
                 let mut c = get_color(dataset_idx);
                 c.w = 0.6; // Semi-transparent
                 c
-            });
+            }));
+            self.draw_point.apply_dataset(cx, dataset, dataset_idx);
 
             for (point_idx, point) in dataset.data.iter().enumerate() {
                 let x_val = point.x.unwrap_or(point_idx as f64);
@@ -326,6 +387,12 @@ impl BubbleChart {
                     size: dvec2(draw_radius * 2.0, draw_radius * 2.0),
                 };
                 self.draw_point.draw_point(cx, rect);
+
+                let hit_rect = Rect {
+                    pos: dvec2(x - base_radius, final_y - base_radius),
+                    size: dvec2(base_radius * 2.0, base_radius * 2.0),
+                };
+                self.hit_tester.register(hit_rect, HitData::Point { dataset_index: dataset_idx, data_index: point_idx });
             }
         }
     }
@@ -340,6 +407,7 @@ impl BubbleChart {
             if old_hovered_bubble != self.hovered_bubble {
                 self.redraw(cx);
             }
+            self.update_tooltip(cx, pos);
             return;
         }
 
@@ -352,6 +420,9 @@ impl BubbleChart {
         let mut min_dist = f64::MAX;
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            if dataset.hidden {
+                continue;
+            }
             for (point_idx, point) in dataset.data.iter().enumerate() {
                 let x_val = point.x.unwrap_or(point_idx as f64);
                 let x = self.coord.x_scale().get_pixel_for_value(x_val);
@@ -375,6 +446,48 @@ impl BubbleChart {
         if old_hovered_bubble != self.hovered_bubble || old_hovered_dataset != self.hovered_dataset {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos);
+    }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let content = TooltipContent::from_hits(data, &hits, |ds, _| {
+            data.datasets[ds].background_color.unwrap_or_else(|| get_color(ds))
+        });
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for BubbleChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
     }
 }
 
@@ -410,4 +523,32 @@ impl BubbleChartRef {
             false
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
 }
+
+impl ChartActions for BubbleChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
+
+impl CartesianChartActions for BubbleChartRef {}
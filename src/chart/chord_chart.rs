@@ -3,6 +3,9 @@ use std::f64::consts::PI;
 use crate::core::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::scale::format_number;
+use crate::component::{ChartTitle, ChartTooltip, TooltipContent};
+use crate::interaction::{ChartAction, ChartActions, ChartHit};
 
 live_design! {
     use link::theme::*;
@@ -15,6 +18,10 @@ live_design! {
     pub ChordChart = {{ChordChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -44,14 +51,11 @@ impl ChordData {
 /// Computed group arc for layout
 #[derive(Clone, Debug)]
 struct GroupArc {
-    #[allow(dead_code)]
     index: usize,
     start_angle: f64,
     end_angle: f64,
-    #[allow(dead_code)]
     value: f64,
     color: Vec4,
-    #[allow(dead_code)]
     label: String,
     /// Track how much of this group's angle has been consumed by chords
     source_offset: f64,
@@ -62,13 +66,11 @@ struct GroupArc {
 #[derive(Clone, Debug)]
 struct Chord {
     source_index: usize,
-    #[allow(dead_code)]
     target_index: usize,
     source_start: f64,
     source_end: f64,
     target_start: f64,
     target_end: f64,
-    #[allow(dead_code)]
     value: f64,
 }
 
@@ -84,12 +86,21 @@ pub struct ChordChart {
     #[live]
     draw_ribbon: DrawTriangle,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    tooltip: ChartTooltip,
+
     #[rust]
     chord_data: ChordData,
 
     #[rust]
     options: ChartOptions,
 
+    #[rust]
+    title: ChartTitle,
+
     #[rust]
     animator: ChartAnimator,
 
@@ -119,6 +130,10 @@ pub struct ChordChart {
     #[rust(-1)]
     hovered_chord: i32,
 
+    /// Last hovered (group, chord) reported through actions
+    #[rust((-1, -1))]
+    active_element: (i32, i32),
+
     /// Enable gradient on ribbons (radial from center)
     #[rust(false)]
     gradient_enabled: bool,
@@ -146,6 +161,10 @@ impl Widget for ChordChart {
         match event {
             Event::MouseMove(e) => {
                 self.handle_mouse_move(cx, e.abs);
+                self.update_active_element(cx, scope);
+            }
+            Event::MouseDown(_) => {
+                self.handle_mouse_down(cx, scope);
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -168,7 +187,8 @@ impl Widget for ChordChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_layout(rect);
+            let plot_rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+            self.update_layout(plot_rect);
 
             if !self.initialized {
                 self.compute_chord_layout();
@@ -178,6 +198,8 @@ impl Widget for ChordChart {
 
             self.draw_chords(cx);
             self.draw_group_arcs(cx);
+            self.title.draw(cx, &mut self.draw_label, &self.options);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -191,6 +213,7 @@ impl ChordChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
         self.options = options;
     }
 
@@ -470,6 +493,45 @@ impl ChordChart {
         base_color: Vec4,
         gradient: bool,
     ) {
+        let polygon = self.ribbon_polygon(source_start, source_end, target_start, target_end, radius);
+
+        // Draw as triangles from center
+        self.draw_ribbon.color = color;
+
+        if gradient {
+            let center_color = vec4(
+                (base_color.x + 0.3).min(1.0),
+                (base_color.y + 0.3).min(1.0),
+                (base_color.z + 0.3).min(1.0),
+                color.w * 0.8,
+            );
+            let outer_color = vec4(
+                base_color.x * 0.7,
+                base_color.y * 0.7,
+                base_color.z * 0.7,
+                color.w * 0.4,
+            );
+            self.draw_ribbon.set_radial_gradient(center_color, outer_color);
+        } else {
+            self.draw_ribbon.disable_gradient();
+        }
+
+        for i in 0..polygon.len() {
+            let p1 = polygon[i];
+            let p2 = polygon[(i + 1) % polygon.len()];
+            self.draw_ribbon.draw_triangle(cx, self.center, p1, p2);
+        }
+    }
+
+    /// Outline of a ribbon connecting two arcs at `radius`
+    fn ribbon_polygon(
+        &self,
+        source_start: f64,
+        source_end: f64,
+        target_start: f64,
+        target_end: f64,
+        radius: f64,
+    ) -> Vec<DVec2> {
         let segments = 16;
 
         // Source arc points
@@ -516,33 +578,7 @@ impl ChordChart {
         polygon.extend(&curve1[1..]);
         polygon.extend(&target_points[1..]);
         polygon.extend(&curve2[1..curve2.len() - 1]);
-
-        // Draw as triangles from center
-        self.draw_ribbon.color = color;
-
-        if gradient {
-            let center_color = vec4(
-                (base_color.x + 0.3).min(1.0),
-                (base_color.y + 0.3).min(1.0),
-                (base_color.z + 0.3).min(1.0),
-                color.w * 0.8,
-            );
-            let outer_color = vec4(
-                base_color.x * 0.7,
-                base_color.y * 0.7,
-                base_color.z * 0.7,
-                color.w * 0.4,
-            );
-            self.draw_ribbon.set_radial_gradient(center_color, outer_color);
-        } else {
-            self.draw_ribbon.disable_gradient();
-        }
-
-        for i in 0..polygon.len() {
-            let p1 = polygon[i];
-            let p2 = polygon[(i + 1) % polygon.len()];
-            self.draw_ribbon.draw_triangle(cx, self.center, p1, p2);
-        }
+        polygon
     }
 
     fn draw_directed_ribbon(
@@ -664,23 +700,31 @@ impl ChordChart {
         let dx = pos.x - self.center.x;
         let dy = pos.y - self.center.y;
         let dist = (dx * dx + dy * dy).sqrt();
-
-        if dist > self.radius {
-            if old_group != self.hovered_group || old_chord != self.hovered_chord {
-                self.redraw(cx);
-            }
-            return;
-        }
-
         let angle = dy.atan2(dx);
+
         let outer_radius = self.radius;
         let inner_radius = self.radius * (1.0 - self.arc_thickness);
+        let group_at_angle = self.groups
+            .iter()
+            .position(|group| self.is_angle_in_range(angle, group.start_angle, group.end_angle))
+            .map(|i| i as i32)
+            .unwrap_or(-1);
 
-        // Check if hovering over group arcs
         if dist >= inner_radius && dist <= outer_radius {
-            for (i, group) in self.groups.iter().enumerate() {
-                if self.is_angle_in_range(angle, group.start_angle, group.end_angle) {
-                    self.hovered_group = i as i32;
+            // Check if hovering over group arcs
+            self.hovered_group = group_at_angle;
+        } else if dist < inner_radius {
+            // Ribbons drawn last are on top
+            for (i, chord) in self.chords.iter().enumerate().rev() {
+                let polygon = self.ribbon_polygon(
+                    chord.source_start,
+                    chord.source_end,
+                    chord.target_start,
+                    chord.target_end,
+                    inner_radius,
+                );
+                if polygon_contains(&polygon, pos) {
+                    self.hovered_chord = i as i32;
                     break;
                 }
             }
@@ -689,6 +733,83 @@ impl ChordChart {
         if old_group != self.hovered_group || old_chord != self.hovered_chord {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos, group_at_angle);
+    }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2, group_at_angle: i32) {
+        let opts = &self.options.tooltip;
+        let list_flows = matches!(opts.mode, InteractionMode::Index | InteractionMode::Dataset);
+
+        let content = if self.hovered_chord >= 0 {
+            self.chord_tooltip(self.hovered_chord as usize, list_flows)
+        } else if self.hovered_group >= 0 {
+            self.group_tooltip(self.hovered_group as usize, list_flows)
+        } else if !opts.intersect && group_at_angle >= 0 && self.tooltip.bounds().contains(pos) {
+            self.group_tooltip(group_at_angle as usize, list_flows)
+        } else {
+            TooltipContent::default()
+        };
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    fn group_label(&self, index: usize) -> String {
+        self.groups
+            .get(index)
+            .map(|g| g.label.clone())
+            .unwrap_or_else(|| format!("Group {}", index + 1))
+    }
+
+    fn group_color(&self, index: usize) -> Vec4 {
+        self.groups.get(index).map(|g| g.color).unwrap_or_else(|| get_color(index))
+    }
+
+    /// Group total, optionally followed by each outgoing flow
+    fn group_tooltip(&self, index: usize, list_flows: bool) -> TooltipContent {
+        let Some(group) = self.groups.get(index) else {
+            return TooltipContent::default();
+        };
+
+        let mut content = TooltipContent::new(group.label.clone())
+            .with_item(format!("Total: {}", format_number(group.value)), group.color);
+
+        if list_flows {
+            for chord in self.chords.iter().filter(|c| c.source_index == index) {
+                content = content.with_item(
+                    format!("→ {}: {}", self.group_label(chord.target_index), format_number(chord.value)),
+                    self.group_color(chord.target_index),
+                );
+            }
+        }
+        content
+    }
+
+    /// Flow along a ribbon, optionally followed by the flow in the opposite direction
+    fn chord_tooltip(&self, index: usize, list_flows: bool) -> TooltipContent {
+        let Some(chord) = self.chords.get(index) else {
+            return TooltipContent::default();
+        };
+
+        let source = self.group_label(chord.source_index);
+        let target = self.group_label(chord.target_index);
+        let mut content = TooltipContent::new(format!("{} → {}", source, target))
+            .with_item(format_number(chord.value), self.group_color(chord.source_index));
+
+        if list_flows {
+            let reverse = self.chords.iter().find(|c| {
+                c.source_index == chord.target_index && c.target_index == chord.source_index
+            });
+            if let Some(reverse) = reverse.filter(|_| chord.source_index != chord.target_index) {
+                content = content.with_item(
+                    format!("{} → {}: {}", target, source, format_number(reverse.value)),
+                    self.group_color(reverse.source_index),
+                );
+            }
+        }
+        content
     }
 
     fn is_angle_in_range(&self, angle: f64, start: f64, end: f64) -> bool {
@@ -708,6 +829,67 @@ impl ChordChart {
 
         check_angle >= range_start && check_angle < range_end
     }
+
+    /// Hovered group arc or ribbon as a chart hit
+    ///
+    /// Group arcs report the group as both indices with the group total as `y`;
+    /// ribbons report `(source, target)` with the flow value as `y`.
+    fn hovered_hit(&self) -> Option<ChartHit> {
+        if self.hovered_chord >= 0 {
+            let chord = self.chords.get(self.hovered_chord as usize)?;
+            let label = format!(
+                "{} → {}",
+                self.group_label(chord.source_index),
+                self.group_label(chord.target_index)
+            );
+            let point = DataPoint::from_y(chord.value).with_label(label);
+            return Some(ChartHit::new(chord.source_index, chord.target_index, point));
+        }
+        if self.hovered_group >= 0 {
+            let group = self.groups.get(self.hovered_group as usize)?;
+            let point = DataPoint::from_y(group.value).with_label(group.label.clone());
+            return Some(ChartHit::new(group.index, group.index, point));
+        }
+        None
+    }
+
+    fn update_active_element(&mut self, cx: &mut Cx, scope: &mut Scope) {
+        let element = (self.hovered_group, self.hovered_chord);
+        if element == self.active_element {
+            return;
+        }
+        self.active_element = element;
+
+        let action = match self.hovered_hit() {
+            Some(hit) => ChartAction::ElementHovered(hit),
+            None => ChartAction::HoverCleared,
+        };
+        let uid = self.widget_uid();
+        cx.widget_action(uid, &scope.path, action);
+    }
+
+    fn handle_mouse_down(&mut self, cx: &mut Cx, scope: &mut Scope) {
+        if let Some(hit) = self.hovered_hit() {
+            let uid = self.widget_uid();
+            cx.widget_action(uid, &scope.path, ChartAction::ElementClicked(hit));
+        }
+    }
+}
+
+/// Even-odd test for a point inside a polygon
+fn polygon_contains(polygon: &[DVec2], pos: DVec2) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > pos.y) != (b.y > pos.y)
+            && pos.x < (b.x - a.x) * (pos.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
 }
 
 impl ChordChartRef {
@@ -767,3 +949,9 @@ impl ChordChartRef {
         }
     }
 }
+
+impl ChartActions for ChordChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
@@ -4,6 +4,8 @@ use crate::coord::*;
 use crate::scale::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartAnnotations, ChartAxis, ChartDataLabels, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LabelTarget, LegendItemData, TooltipContent};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
@@ -11,16 +13,24 @@ live_design! {
     use link::widgets::*;
 
     use crate::element::bar::DrawBar;
-    use crate::element::line::DrawChartLine;
+    use crate::element::polyline::DrawPolyline;
     use crate::element::point::DrawPoint;
     use crate::element::grid::DrawGridLine;
 
     pub ComboChart = {{ComboChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
+/// Share of the category the bars take when their datasets do not set one,
+/// narrower than in bar charts so the lines over them stay readable
+const BAR_CATEGORY_PERCENTAGE: f64 = 0.6;
+
 /// Dataset type for combo charts
 #[derive(Clone, Debug, Default, PartialEq)]
 pub enum DatasetType {
@@ -39,7 +49,7 @@ pub struct ComboChart {
     draw_bar: DrawBar,
 
     #[live]
-    draw_line: DrawChartLine,
+    draw_line: DrawPolyline,
 
     #[live]
     draw_point: DrawPoint,
@@ -47,6 +57,21 @@ pub struct ComboChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
+    #[live]
+    annotations: ChartAnnotations,
+
     #[rust]
     data: ChartData,
 
@@ -56,21 +81,37 @@ pub struct ComboChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
     #[rust]
     initialized: bool,
 
-    #[rust(0.6)]
-    bar_percent: f64,
-
     #[rust(4.0)]
     point_radius: f64,
 
     #[rust]
     dataset_types: Vec<DatasetType>,
 
+    /// Labels of the bars and points drawn in the current frame
+    #[rust]
+    data_labels: ChartDataLabels,
+
     /// Enable gradient for bars (vertical gradient)
     #[rust(false)]
     gradient_enabled: bool,
@@ -80,7 +121,28 @@ impl Widget for ComboChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+        self.annotations.handle_event(cx, event, scope, uid, &self.coord);
+
         match event {
+            Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
+                self.update_tooltip(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
+            }
+            Event::MouseDown(e) => {
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
+            }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
                     let time = cx.seconds_since_app_start();
@@ -105,7 +167,7 @@ impl Widget for ComboChart {
         let rect = cx.turtle().rect();
 
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.update_coord(cx, rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -114,8 +176,17 @@ impl Widget for ComboChart {
 
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.hit_tester.clear();
+            self.zoom.begin_clip(cx, &self.coord);
+            self.annotations.draw_below(cx, &self.coord, &self.options.annotations);
             self.draw_bars(cx);
             self.draw_lines(cx);
+            self.data_labels.draw(cx, &mut self.draw_label, self.coord.chart_area().to_rect());
+            self.annotations.draw_above(cx, &self.coord, &self.options.annotations);
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
+            self.draw_labels(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -130,6 +201,9 @@ impl ComboChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.zoom.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -137,6 +211,24 @@ impl ComboChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
     /// Set the type for each dataset (Bar or Line)
     pub fn set_dataset_types(&mut self, types: Vec<DatasetType>) {
         self.dataset_types = types;
@@ -160,20 +252,37 @@ impl ComboChart {
 
         self.coord = CartesianCoord::new()
             .with_x_scale(ScaleType::Category(category_scale))
-            .with_y_scale(ScaleType::Linear(
+            .with_y_scale(ScaleType::value_scale(
+                &self.options.scales.y,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.y.begin_at_zero)
                     .with_nice(true)
-            ));
-
-        if let Some((min, max)) = self.data.get_y_extent() {
-            let min = if self.options.scales.y.begin_at_zero && min > 0.0 { 0.0 } else { min };
-            self.coord.set_y_data_range(min, max);
+            ))
+            .with_y_axes(&self.options.scales);
+
+        self.coord.apply_axis_options(&self.options.scales);
+
+        // Fit each Y axis to the datasets drawn against it and the annotations on it
+        let scales = &self.options.scales;
+        for id in scales.y_axis_ids() {
+            let extent = self.data.get_y_extent_where(|_, dataset| scales.y_axis_of(dataset) == id);
+            let extent = merge_extents(extent, annotation_y_extent(&self.options.annotations, id, |id| scales.y_axis_id(id)));
+            let min_positive = self.data.get_min_positive_y_where(|_, dataset| scales.y_axis_of(dataset) == id);
+            self.coord.set_y_axis_min_positive(id, min_positive);
+            if let Some((min, max)) = extent {
+                let min = if scales.y_axis(id).begin_at_zero && min > 0.0 { 0.0 } else { min };
+                self.coord.set_y_axis_data_range(id, min, max);
+            }
         }
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| dataset_color(&self.data, &self.dataset_types, i));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
     }
 
     fn start_animation(&mut self, cx: &mut Cx) {
@@ -208,54 +317,39 @@ impl ComboChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        let tick_options = TickOptions::default();
-        let y_ticks = self.coord.build_y_ticks(&tick_options);
-
-        for tick in &y_ticks {
-            let (p1, p2) = self.coord.get_horizontal_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // X axis at the bottom
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.right, area.bottom), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, false, false);
+    }
 
-        // Y axis at the left
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.left, area.top), axis_width);
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn draw_bars(&mut self, cx: &mut Cx2d) {
         let progress = self.animator.get_progress();
-        let bar_width = self.coord.get_bar_width(self.bar_percent);
+        let ruler = self.coord.x_bar_ruler(&self.data);
+        self.data_labels.clear();
 
         // Count bar datasets
         let bar_datasets: Vec<usize> = (0..self.data.datasets.len())
-            .filter(|&i| self.get_dataset_type(i) == DatasetType::Bar)
+            .filter(|&i| !self.data.datasets[i].hidden && self.get_dataset_type(i) == DatasetType::Bar)
             .collect();
 
         let num_bar_datasets = bar_datasets.len();
-        let group_bar_width = if num_bar_datasets > 0 {
-            bar_width / num_bar_datasets as f64
-        } else {
-            bar_width
-        };
 
         for (bar_idx, &dataset_idx) in bar_datasets.iter().enumerate() {
             let dataset = &self.data.datasets[dataset_idx];
-            let color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
+            let sizing = BarSizing::of(dataset, BAR_CATEGORY_PERCENTAGE);
+            let y_scale = self.coord.y_scale_for(self.options.scales.y_axis_of(dataset));
+            let color = self.legend.highlight(dataset_idx, dataset.get_background_color(dataset_idx));
             self.draw_bar.color = color;
 
             // Apply gradient if enabled
@@ -267,29 +361,28 @@ impl ComboChart {
             }
 
             for (data_idx, point) in dataset.data.iter().enumerate() {
-                let x_center = self.coord.x_scale().get_pixel_for_value(data_idx as f64);
-
-                let group_offset = if num_bar_datasets > 1 {
-                    let start_offset = -bar_width / 2.0 + group_bar_width / 2.0;
-                    start_offset + bar_idx as f64 * group_bar_width
-                } else {
-                    0.0
-                };
-
-                let bar_x = x_center + group_offset - group_bar_width / 2.0;
+                let Some((bar_x, bar_width)) = ruler.bar(data_idx, bar_idx, num_bar_datasets, &sizing) else { continue };
 
                 let y_value = point.y * progress;
-                let y_pixel = self.coord.y_scale().get_pixel_for_value(y_value);
-                let base_y = self.coord.y_scale().get_pixel_for_value(0.0);
+                let y_pixel = y_scale.get_pixel_for_value(y_value);
+                let base_y = y_scale.get_pixel_for_value(0.0);
 
-                let bar_height = base_y - y_pixel;
+                let bar_height = (base_y - y_pixel).abs();
 
                 if bar_height > 0.0 {
                     let bar_rect = Rect {
-                        pos: dvec2(bar_x, y_pixel),
-                        size: dvec2(group_bar_width, bar_height),
+                        pos: dvec2(bar_x, y_pixel.min(base_y)),
+                        size: dvec2(bar_width, bar_height),
                     };
                     self.draw_bar.draw_bar(cx, bar_rect);
+                    self.hit_tester.register(bar_rect, HitData::Bar { dataset_index: dataset_idx, data_index: data_idx });
+
+                    if let Some(label_options) = &dataset.data_labels {
+                        let context = DataLabelContext::of(&self.data, dataset_idx, data_idx);
+                        let base = if y_pixel <= base_y { AxisPosition::Bottom } else { AxisPosition::Top };
+                        let target = LabelTarget::Bar { rect: bar_rect, base };
+                        self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, color);
+                    }
                 }
             }
         }
@@ -304,38 +397,102 @@ impl ComboChart {
                 continue;
             }
 
-            if dataset.data.len() < 2 {
+            if dataset.hidden || dataset.data.len() < 2 {
                 continue;
             }
 
-            let color = dataset.border_color
+            let color = self.legend.highlight(dataset_idx, dataset.border_color
                 .or(dataset.background_color)
-                .unwrap_or_else(|| get_color(dataset_idx));
+                .unwrap_or_else(|| get_color(dataset_idx)));
             self.draw_line.color = color;
 
+            let y_scale = self.coord.y_scale_for(self.options.scales.y_axis_of(dataset));
             let points: Vec<DVec2> = dataset.data.iter().enumerate().map(|(i, point)| {
                 let x = self.coord.x_scale().get_pixel_for_value(i as f64);
                 let y_value = point.y * progress;
-                let y = self.coord.y_scale().get_pixel_for_value(y_value);
+                let y = y_scale.get_pixel_for_value(y_value);
                 dvec2(x, y)
             }).collect();
 
             // Draw lines
-            for i in 0..points.len() - 1 {
-                self.draw_line.draw_line(cx, points[i], points[i + 1], line_width);
-            }
+            self.draw_line.set_dash(&dataset.border_dash, dataset.border_dash_offset);
+            self.draw_line.draw_polyline(cx, &points, line_width, dataset.border_join_style, dataset.border_cap_style);
 
             // Draw points
-            self.draw_point.color = color;
-            for point in &points {
+            let point_color = dataset.point_background_color.unwrap_or(color);
+            self.draw_point.color = point_color;
+            self.draw_point.apply_dataset(cx, dataset, dataset_idx);
+            let hit_radius = self.point_radius + 4.0;
+            for (data_idx, point) in points.iter().enumerate() {
                 let rect = Rect {
                     pos: dvec2(point.x - self.point_radius, point.y - self.point_radius),
                     size: dvec2(self.point_radius * 2.0, self.point_radius * 2.0),
                 };
                 self.draw_point.draw_point(cx, rect);
+
+                let hit_rect = Rect {
+                    pos: dvec2(point.x - hit_radius, point.y - hit_radius),
+                    size: dvec2(hit_radius * 2.0, hit_radius * 2.0),
+                };
+                self.hit_tester.register(hit_rect, HitData::Point { dataset_index: dataset_idx, data_index: data_idx });
+
+                if let Some(label_options) = &dataset.data_labels {
+                    let context = DataLabelContext::of(&self.data, dataset_idx, data_idx);
+                    let target = LabelTarget::Point { center: *point, radius: self.point_radius };
+                    self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, point_color);
+                }
             }
         }
     }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let types = &self.dataset_types;
+        let content = TooltipContent::from_hits(data, &hits, |ds, _| dataset_color(data, types, ds));
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+/// Series color of a dataset: the line color for line datasets, the fill for bars
+fn dataset_color(data: &ChartData, types: &[DatasetType], index: usize) -> Vec4 {
+    let dataset = &data.datasets[index];
+    let color = match types.get(index) {
+        Some(DatasetType::Line) => dataset.border_color.or(dataset.background_color),
+        _ => dataset.background_color,
+    };
+    color.unwrap_or_else(|| get_color(index))
+}
+
+impl DatasetToggle for ComboChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
+    }
 }
 
 impl ComboChartRef {
@@ -376,4 +533,32 @@ impl ComboChartRef {
             false
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
+}
+
+impl ChartActions for ComboChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
 }
+
+impl CartesianChartActions for ComboChartRef {}
@@ -4,6 +4,8 @@ use crate::coord::*;
 use crate::scale::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartAxis, ChartDataLabels, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LabelTarget, LegendItemData, TooltipContent};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
@@ -16,6 +18,10 @@ live_design! {
     pub HorizontalBarChart = {{HorizontalBarChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -31,6 +37,18 @@ pub struct HorizontalBarChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
     #[rust]
     data: ChartData,
 
@@ -40,31 +58,65 @@ pub struct HorizontalBarChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
     #[rust]
     initialized: bool,
 
-    /// Bar height as percentage of band (0.0 - 1.0)
-    #[rust(0.8)]
-    bar_percent: f64,
+    /// Share of each category taken by its bars (0.0 - 1.0), overriding the
+    /// `category_percentage` of every dataset when set
+    #[rust]
+    category_percent: Option<f64>,
 
     /// Hover state - index of hovered bar (-1 for none)
     #[rust(-1)]
     hovered_bar: i32,
+
+    /// Labels of the bars drawn in the current frame
+    #[rust]
+    data_labels: ChartDataLabels,
 }
 
 impl Widget for HorizontalBarChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
             }
             Event::MouseDown(e) => {
-                self.handle_mouse_down(cx, e.abs);
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -86,7 +138,7 @@ impl Widget for HorizontalBarChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.update_coord(cx, rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -95,7 +147,14 @@ impl Widget for HorizontalBarChart {
 
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.hit_tester.clear();
+            self.zoom.begin_clip(cx, &self.coord);
             self.draw_bars(cx);
+            self.data_labels.draw(cx, &mut self.draw_label, self.coord.chart_area().to_rect());
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
+            self.draw_labels(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -110,6 +169,9 @@ impl HorizontalBarChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.zoom.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -117,8 +179,27 @@ impl HorizontalBarChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
+    /// Set the share of each category taken by its bars for all datasets
     pub fn set_bar_percent(&mut self, percent: f64) {
-        self.bar_percent = percent.clamp(0.1, 1.0);
+        self.category_percent = Some(percent.clamp(0.1, 1.0));
     }
 
     fn setup_coord_from_data(&mut self) {
@@ -130,14 +211,18 @@ impl HorizontalBarChart {
             .with_offset(true);
 
         self.coord = CartesianCoord::new()
-            .with_x_scale(ScaleType::Linear(
+            .with_x_scale(ScaleType::value_scale(
+                &self.options.scales.x,
                 LinearScale::new()
                     .with_begin_at_zero(false)
                     .with_nice(true)
             ))
             .with_y_scale(ScaleType::Category(category_scale));
 
+        self.coord.apply_axis_options(&self.options.scales);
+
         // Set X data range from data extent (values) - include negative values
+        self.coord.set_x_min_positive(self.data.get_min_positive_y_where(|_, _| true));
         if let Some((min, max)) = self.data.get_y_extent() {
             // For horizontal bars, we want to show zero line if data crosses zero
             // or if begin_at_zero is set
@@ -155,8 +240,13 @@ impl HorizontalBarChart {
         }
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| self.data.datasets[i].get_background_color(i));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
 
         // For horizontal bar charts, categories on Y axis should go from top to bottom
         // (first category at top, last at bottom), so we need to override the default
@@ -175,71 +265,47 @@ impl HorizontalBarChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        // For horizontal bar charts, draw vertical grid lines based on X ticks
-        let tick_options = TickOptions::default();
-        let x_ticks = self.coord.build_x_ticks(&tick_options);
-
-        for tick in &x_ticks {
-            let (p1, p2) = self.coord.get_vertical_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // Draw X axis at bottom
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.right, area.bottom), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, false, true);
+    }
 
-        // Draw Y axis at x=0 if in range, otherwise at left
-        let (x_min, x_max) = self.coord.x_scale().get_data_bounds();
-        let y_axis_x = if x_min <= 0.0 && x_max >= 0.0 {
-            self.coord.x_scale().get_pixel_for_value(0.0)
-        } else {
-            area.left
-        };
-        self.draw_grid.draw_line(cx, dvec2(y_axis_x, area.bottom), dvec2(y_axis_x, area.top), axis_width);
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn draw_bars(&mut self, cx: &mut Cx2d) {
         let progress = self.animator.get_progress();
-        let bar_height = self.get_bar_height();
-
-        let num_datasets = self.data.datasets.len();
-        let group_bar_height = if num_datasets > 0 {
-            bar_height / num_datasets as f64
-        } else {
-            bar_height
-        };
-
-        for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-            let color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
+        let ruler = self.coord.y_bar_ruler(&self.data);
+        let num_datasets = self.data.visible_dataset_count();
+        self.data_labels.clear();
+
+        let visible = self.data.datasets.iter().enumerate().filter(|(_, d)| !d.hidden);
+        for (group_idx, (dataset_idx, dataset)) in visible.enumerate() {
+            let mut sizing = BarSizing::from(dataset);
+            sizing.category_percentage = self.category_percent.unwrap_or(sizing.category_percentage);
+            let color = self.legend.highlight(dataset_idx, dataset.get_background_color(dataset_idx));
             self.draw_bar.color = color;
 
-            for (data_idx, point) in dataset.data.iter().enumerate() {
-                // Y position based on category index
-                let y_center = self.coord.y_scale().get_pixel_for_value(data_idx as f64);
-
-                // Offset for grouped bars
-                let group_offset = if num_datasets > 1 {
-                    let total_group_height = bar_height;
-                    let start_offset = -total_group_height / 2.0 + group_bar_height / 2.0;
-                    start_offset + dataset_idx as f64 * group_bar_height
-                } else {
-                    0.0
-                };
+            // Bars get a border when the dataset has a border color
+            match dataset.border_color {
+                Some(border_color) => {
+                    let border_color = self.legend.highlight(dataset_idx, border_color);
+                    self.draw_bar.set_border(dataset.border_width as f32, border_color);
+                }
+                None => self.draw_bar.disable_border(),
+            }
 
-                let bar_y = y_center + group_offset - group_bar_height / 2.0;
+            for (data_idx, point) in dataset.data.iter().enumerate() {
+                // Grouped bars sit side by side along the category
+                let Some((bar_y, bar_height)) = ruler.bar(data_idx, group_idx, num_datasets, &sizing) else { continue };
 
                 // Get x value with animation (bar grows from zero)
                 let x_value = point.y * progress;
@@ -254,11 +320,8 @@ impl HorizontalBarChart {
                     self.hovered_bar as usize == data_idx &&
                     num_datasets == 1;
 
-                if is_hovered {
-                    self.draw_bar.color = lighten(color, 0.15);
-                } else {
-                    self.draw_bar.color = color;
-                }
+                let bar_color = if is_hovered { lighten(color, 0.15) } else { color };
+                self.draw_bar.color = bar_color;
 
                 // Handle both positive and negative bars
                 if bar_width.abs() > 0.5 {
@@ -271,28 +334,27 @@ impl HorizontalBarChart {
 
                     let bar_rect = Rect {
                         pos: dvec2(bar_x, bar_y),
-                        size: dvec2(abs_width, group_bar_height),
+                        size: dvec2(abs_width, bar_height),
                     };
 
+                    // Round the end of the bar, away from zero
+                    let base = if bar_width >= 0.0 { AxisPosition::Left } else { AxisPosition::Right };
+                    let (radii, sides) = bar_corners(bar_rect.size, base, dataset.bar_border_radius, dataset.border_skipped);
+                    self.draw_bar.set_shape(radii, sides);
+
                     self.draw_bar.draw_bar(cx, bar_rect);
+                    self.hit_tester.register(bar_rect, HitData::Bar { dataset_index: dataset_idx, data_index: data_idx });
+
+                    if let Some(label_options) = &dataset.data_labels {
+                        let context = DataLabelContext::of(&self.data, dataset_idx, data_idx);
+                        let target = LabelTarget::Bar { rect: bar_rect, base };
+                        self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, bar_color);
+                    }
                 }
             }
         }
     }
 
-    fn get_bar_height(&self) -> f64 {
-        let chart_area = self.coord.chart_area();
-        let num_categories = self.data.labels.len().max(
-            self.data.datasets.first().map(|d| d.data.len()).unwrap_or(1)
-        );
-
-        if num_categories > 0 {
-            (chart_area.height() / num_categories as f64) * self.bar_percent
-        } else {
-            chart_area.height() * self.bar_percent
-        }
-    }
-
     fn handle_mouse_move(&mut self, cx: &mut Cx, pos: DVec2) {
         let old_hovered = self.hovered_bar;
 
@@ -306,15 +368,49 @@ impl HorizontalBarChart {
         if old_hovered != self.hovered_bar {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos);
     }
 
-    fn handle_mouse_down(&mut self, _cx: &mut Cx, pos: DVec2) {
-        if self.coord.contains_pixel(pos.x, pos.y) {
-            let y_value = self.coord.y_scale().get_value_for_pixel(pos.y);
-            let bar_index = y_value.round() as usize;
-            log!("Horizontal bar clicked: index={}", bar_index);
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let content = TooltipContent::from_hits(data, &hits, |ds, _| {
+            data.datasets[ds].background_color.unwrap_or_else(|| get_color(ds))
+        });
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
         }
     }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for HorizontalBarChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
+    }
 }
 
 impl HorizontalBarChartRef {
@@ -335,4 +431,32 @@ impl HorizontalBarChartRef {
             inner.redraw(cx);
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
+}
+
+impl ChartActions for HorizontalBarChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
 }
+
+impl CartesianChartActions for HorizontalBarChartRef {}
@@ -4,13 +4,15 @@ use crate::coord::*;
 use crate::scale::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartAnnotations, ChartAxis, ChartBrush, BrushTarget, ChartCrosshair, ChartDataLabels, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LabelTarget, LegendItemData, TooltipContent, format_stacked_value};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
     use link::shaders::*;
     use link::widgets::*;
 
-    use crate::element::line::DrawChartLine;
+    use crate::element::polyline::DrawPolyline;
     use crate::element::point::DrawPoint;
     use crate::element::triangle::DrawTriangle;
     use crate::element::grid::DrawGridLine;
@@ -18,6 +20,10 @@ live_design! {
     pub LineChart = {{LineChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -28,7 +34,7 @@ pub struct LineChart {
     view: View,
 
     #[live]
-    draw_line: DrawChartLine,
+    draw_line: DrawPolyline,
 
     #[live]
     draw_point: DrawPoint,
@@ -39,6 +45,27 @@ pub struct LineChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
+    #[live]
+    crosshair: ChartCrosshair,
+
+    #[live]
+    brush: ChartBrush,
+
+    #[live]
+    annotations: ChartAnnotations,
+
     #[rust]
     data: ChartData,
 
@@ -48,12 +75,62 @@ pub struct LineChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
     #[rust]
     initialized: bool,
 
+    /// Scroll animation for streamed points
+    #[rust]
+    stream_animator: ChartAnimator,
+
+    /// Category bands the data scrolls in from during the current step
+    #[rust]
+    stream_shift: f64,
+
+    /// X range the current step scrolls from and to on linear and time axes
+    #[rust]
+    stream_x_from: Option<(f64, f64)>,
+
+    #[rust]
+    stream_x_to: Option<(f64, f64)>,
+
+    /// First point added in the current step, as (dataset index, data index)
+    #[rust]
+    stream_new: Vec<(usize, usize)>,
+
+    /// Data indices kept by decimation per dataset, `None` to draw every point
+    #[rust]
+    decimated: Vec<Option<Vec<usize>>>,
+
+    /// Labels of the points drawn in the current frame
+    #[rust]
+    data_labels: ChartDataLabels,
+
+    /// Chart area and visible ranges the decimation was computed for
+    #[rust]
+    decimation_key: Option<[f64; 8]>,
+
+    /// Base and top of every value while the Y axis is stacked, empty otherwise
+    #[rust]
+    stacks: Vec<Vec<Option<(f64, f64)>>>,
+
     /// Point radius
     #[rust(4.0)]
     point_radius: f64,
@@ -62,9 +139,9 @@ pub struct LineChart {
     #[rust(true)]
     show_points: bool,
 
-    /// Whether to fill area under line
-    #[rust(false)]
-    fill: bool,
+    /// Fill target of lines without one of their own
+    #[rust]
+    fill: FillTarget,
 
     /// Line tension (0 = straight lines, 0.4 = smooth curves)
     #[rust(0.0)]
@@ -112,16 +189,47 @@ impl Widget for LineChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+        let target = BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks);
+        if self.brush.handle_event(cx, event, scope, uid, &target) {
+            self.redraw(cx);
+        }
+        self.annotations.handle_event(cx, event, scope, uid, &self.coord);
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
+                let cursor = self.coord.contains_pixel(e.abs.x, e.abs.y).then_some(e.abs);
+                if self.crosshair.set_cursor(cursor) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
+            }
+            Event::MouseDown(e) => {
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
-                if self.animator.is_running() {
+                if self.animator.is_running() || self.stream_animator.is_running() {
                     let time = cx.seconds_since_app_start();
                     if self.animator.update(time) {
                         self.redraw(cx);
                     }
+                    if self.stream_animator.is_running() {
+                        // Also redraw the frame that completes the scroll
+                        self.stream_animator.update(time);
+                        self.redraw(cx);
+                    }
                     // Keep requesting frames while animation is running
                     cx.new_next_frame();
                 }
@@ -138,7 +246,9 @@ impl Widget for LineChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.apply_stream_scroll();
+            self.update_coord(cx, rect);
+            self.update_decimation();
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -148,27 +258,50 @@ impl Widget for LineChart {
             self.draw_background(cx, rect);
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.brush.draw(cx, &self.coord);
+            self.zoom.begin_clip(cx, &self.coord);
+            self.annotations.draw_below(cx, &self.coord, &self.options.annotations);
             self.draw_lines(cx);
-            if self.show_points {
+            // Selected points are shown even when points are hidden
+            if self.show_points || self.brush.selection().is_some() {
                 self.draw_points(cx);
             }
+            self.register_hit_regions();
+            self.draw_data_labels(cx);
+            self.annotations.draw_above(cx, &self.coord, &self.options.annotations);
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
+            self.draw_labels(cx);
+            self.crosshair.draw(cx, &mut self.draw_grid, &self.coord, &self.hit_tester);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
     }
 }
 
-
 impl LineChart {
     /// Set chart data
     pub fn set_data(&mut self, data: ChartData) {
         self.data = data;
         self.initialized = false;
+        self.stream_animator.reset();
+        self.stream_shift = 0.0;
+        self.stream_x_from = None;
+        self.stream_x_to = None;
+        self.stream_new.clear();
         self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks));
     }
 
-    /// Set chart options
+    /// Set chart options, keeping the stack mode of `set_stacked` unless they set one
     pub fn set_options(&mut self, options: ChartOptions) {
+        let options = options.with_stacking_of(&self.options);
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.effective_zoom());
+        self.crosshair.set_options(options.crosshair.clone());
+        self.brush.set_options(options.brush.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -176,6 +309,42 @@ impl LineChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
+    /// Select the points inside `x` and `y` as if brushed; `None` leaves an axis unbounded
+    pub fn set_selection(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.brush.select(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks), x, y);
+        self.redraw(cx);
+    }
+
+    /// Clear the brush selection
+    pub fn clear_selection(&mut self, cx: &mut Cx) {
+        if self.brush.clear() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Current brush selection
+    pub fn selection(&self) -> Option<&ChartSelection> {
+        self.brush.selection()
+    }
+
     /// Enable/disable points
     pub fn set_show_points(&mut self, show: bool) {
         self.show_points = show;
@@ -186,9 +355,9 @@ impl LineChart {
         self.point_radius = radius;
     }
 
-    /// Enable/disable area fill
-    pub fn set_fill(&mut self, fill: bool) {
-        self.fill = fill;
+    /// Fill target of datasets without their own; `true` fills to the origin
+    pub fn set_fill(&mut self, fill: impl Into<FillTarget>) {
+        self.fill = fill.into();
     }
 
     /// Set line tension (smoothness)
@@ -216,6 +385,12 @@ impl LineChart {
         self.gradient_enabled = enabled;
     }
 
+    /// Stack the datasets on the Y axis, the same as setting `scales.y.stacked` in the options
+    pub fn set_stacked(&mut self, mode: impl Into<StackMode>) {
+        self.options.scales.y.stacked = mode.into();
+        self.update_data_ranges();
+    }
+
     /// Replay the animation from the beginning
     pub fn replay_animation(&mut self, cx: &mut Cx) {
         // Reset animation state
@@ -239,31 +414,298 @@ impl LineChart {
         self.animator.is_running()
     }
 
-    fn setup_coord_from_data(&mut self) {
-        // Set up category scale for X axis
-        let labels: Vec<String> = self.data.labels.clone();
+    /// Append a point to a dataset and scroll the chart to it.
+    /// Points beyond the dataset's `max_length` or `time_window` are dropped;
+    /// on category axes push the matching label with [`Self::push_labels`].
+    pub fn push_point(&mut self, cx: &mut Cx, dataset_index: usize, point: DataPoint) {
+        if dataset_index >= self.data.datasets.len() {
+            return;
+        }
+        self.begin_stream_step(cx);
+
+        let dropped = self.data.push_point(dataset_index, point);
+        for (index, first_new) in self.stream_new.iter_mut() {
+            if *index == dataset_index {
+                *first_new = first_new.saturating_sub(dropped);
+            }
+        }
+        let new_index = self.data.datasets[dataset_index].data.len() - 1;
+        if !self.stream_new.iter().any(|(index, _)| *index == dataset_index) {
+            self.stream_new.push((dataset_index, new_index));
+        }
+
+        self.end_stream_step(cx);
+    }
+
+    /// Append category labels for streamed points and scroll the X axis by the
+    /// number of labels dropped from the front (see `ChartData::push_labels`)
+    pub fn push_labels<S: Into<String>>(&mut self, cx: &mut Cx, labels: Vec<S>) {
+        self.begin_stream_step(cx);
+        let dropped = self.data.push_labels(labels);
+        self.stream_shift += dropped as f64;
+        self.end_stream_step(cx);
+    }
+
+    /// Start a scroll step from what is currently on screen. Pushes made before
+    /// the step's first frame join it.
+    fn begin_stream_step(&mut self, cx: &mut Cx) {
+        if self.stream_animator.is_running() && self.stream_animator.get_raw_progress() == 0.0 {
+            return;
+        }
+
+        let progress = self.stream_progress();
+        self.stream_shift *= 1.0 - progress;
+        self.stream_x_from = self.current_stream_x_range();
+        self.stream_new.clear();
+        self.stream_animator = ChartAnimator::new(self.options.animation.duration)
+            .with_easing(self.options.animation.easing);
+        self.stream_animator.start(cx.seconds_since_app_start());
+    }
+
+    /// Refresh the scales for the new data and remember where the step scrolls to
+    fn end_stream_step(&mut self, cx: &mut Cx) {
+        self.update_data_ranges();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks));
+        self.stream_x_to = if self.coord.x_scale().as_category().is_none() {
+            Some(self.coord.x_scale().get_data_bounds())
+        } else {
+            None
+        };
+        cx.new_next_frame();
+        self.redraw(cx);
+    }
+
+    /// Progress of the current scroll step, 1.0 when idle
+    fn stream_progress(&self) -> f64 {
+        if self.stream_animator.is_running() {
+            self.stream_animator.get_progress()
+        } else {
+            1.0
+        }
+    }
+
+    /// X range currently on screen for linear and time axes
+    fn current_stream_x_range(&self) -> Option<(f64, f64)> {
+        if self.coord.x_scale().as_category().is_some() {
+            return None;
+        }
+        let progress = self.stream_progress();
+        match (self.stream_x_from, self.stream_x_to) {
+            (Some(from), Some(to)) => Some((
+                from.0 + (to.0 - from.0) * progress,
+                from.1 + (to.1 - from.1) * progress,
+            )),
+            _ => Some(self.coord.x_scale().get_data_bounds()),
+        }
+    }
+
+    /// Move the X axis to the current position of the scroll step
+    fn apply_stream_scroll(&mut self) {
+        let progress = self.stream_progress();
+        let shift = self.stream_shift * (1.0 - progress);
+        if let Some(category) = self.coord.x_scale_mut().as_category_mut() {
+            category.set_shift(shift);
+        }
+        if self.stream_x_to.is_some() {
+            if let Some((min, max)) = self.current_stream_x_range() {
+                self.coord.set_x_data_range(min, max);
+            }
+        }
+        if progress >= 1.0 {
+            self.stream_shift = 0.0;
+            self.stream_x_from = None;
+            self.stream_x_to = None;
+            self.stream_new.clear();
+        }
+    }
 
-        let category_scale = CategoryScale::new()
-            .with_labels(labels)
-            .with_offset(false); // Lines go through grid lines
+    /// Pixel position of a point with its Y value scaled by `y_factor`, moving points
+    /// added in the current scroll step out from the last point that was already on screen
+    fn point_pixel(&self, dataset_index: usize, data_index: usize, y_factor: f64) -> DVec2 {
+        self.moving_pixel(dataset_index, data_index, |i| self.plotted_value(dataset_index, i) * y_factor)
+    }
+
+    /// Pixel position of the base of a stacked point, like `point_pixel`
+    fn base_pixel(&self, dataset_index: usize, data_index: usize, y_factor: f64) -> DVec2 {
+        self.moving_pixel(dataset_index, data_index, |i| self.stack_base(dataset_index, i) * y_factor)
+    }
+
+    /// Pixel position of a point at the Y value `value` gives for its index, moving
+    /// points added in the current scroll step like `point_pixel`
+    fn moving_pixel(&self, dataset_index: usize, data_index: usize, value: impl Fn(usize) -> f64) -> DVec2 {
+        let dataset = &self.data.datasets[dataset_index];
+        let y_scale = self.y_scale_of(dataset_index);
+        let pixel = |i: usize| {
+            let point = &dataset.data[i];
+            dvec2(
+                self.coord.x_scale().get_pixel_for_value(self.coord.x_value(point.x, i)),
+                y_scale.get_pixel_for_value(value(i)),
+            )
+        };
+        let target = pixel(data_index);
+
+        let first_new = self.stream_new.iter()
+            .find(|(index, _)| *index == dataset_index)
+            .map(|(_, first_new)| *first_new);
+        match first_new {
+            Some(first_new) if data_index >= first_new && first_new > 0 => {
+                // From the last value that is there
+                let Some(last) = (0..first_new).rev().find(|&i| !dataset.data[i].is_missing()) else {
+                    return target;
+                };
+                let progress = self.stream_progress();
+                let from = pixel(last);
+                from + (target - from) * progress
+            }
+            _ => target,
+        }
+    }
+
+    fn setup_coord_from_data(&mut self) {
+        // X axis uses the labels by default, or `DataPoint.x` on linear and time axes
+        let x_scale = match self.options.scales.x.scale_type {
+            AxisType::Time => ScaleType::Time(TimeScale::new().with_timestamps(self.data.get_x_values())),
+            AxisType::Linear | AxisType::Logarithmic => ScaleType::value_scale(
+                &self.options.scales.x,
+                LinearScale::new().with_nice(false),
+            ),
+            AxisType::Auto | AxisType::Category => ScaleType::Category(
+                CategoryScale::new()
+                    .with_labels(self.data.labels.clone())
+                    .with_offset(false), // Lines go through grid lines
+            ),
+        };
 
         self.coord = CartesianCoord::new()
-            .with_x_scale(ScaleType::Category(category_scale))
-            .with_y_scale(ScaleType::Linear(
+            .with_x_scale(x_scale)
+            .with_y_scale(ScaleType::value_scale(
+                &self.options.scales.y,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.y.begin_at_zero)
                     .with_nice(true)
-            ));
+            ))
+            .with_y_axes(&self.options.scales);
+
+        self.coord.apply_axis_options(&self.options.scales);
+        self.update_data_ranges();
+    }
+
+    /// Update the scales of the current coordinate system for changed data
+    fn update_data_ranges(&mut self) {
+        self.decimation_key = None;
+        self.decimated.clear();
+        let labels = self.data.labels.clone();
+        let timestamps = self.data.get_x_values();
+        let x_scale = self.coord.x_scale_mut();
+        if let Some(category) = x_scale.as_category_mut() {
+            category.set_labels(labels);
+        } else if let Some(time) = x_scale.as_time_mut() {
+            time.set_timestamps(timestamps);
+        }
+
+        if self.coord.x_scale().as_category().is_none() {
+            self.coord.set_x_min_positive(self.data.get_min_positive_x());
+            if let Some((min, max)) = self.data.get_x_extent() {
+                self.coord.set_x_data_range(min, max);
+            }
+        }
+
+        // Fit each Y axis to the datasets drawn against it, or to their stacks, and to its annotations
+        let scales = &self.options.scales;
+        self.stacks = if scales.y.stacked == StackMode::None { Vec::new() } else { stack_values(&self.data, scales, scales.y.stacked) };
+        for id in scales.y_axis_ids() {
+            let bound = |dataset: &Dataset| scales.y_axis_of(dataset) == id;
+            let extent = if self.stacks.is_empty() {
+                self.data.get_y_extent_where(|_, dataset| bound(dataset))
+            } else {
+                stack_extent(self.data.datasets.iter().zip(&self.stacks).filter(|(d, _)| bound(*d)).map(|(_, s)| s))
+            };
+            let extent = merge_extents(extent, annotation_y_extent(&self.options.annotations, id, |id| scales.y_axis_id(id)));
+            let min_positive = self.data.get_min_positive_y_where(|_, dataset| scales.y_axis_of(dataset) == id);
+            self.coord.set_y_axis_min_positive(id, min_positive);
+            if let Some((min, max)) = extent {
+                let min = if scales.y_axis(id).begin_at_zero && min > 0.0 { 0.0 } else { min };
+                self.coord.set_y_axis_data_range(id, min, max);
+            }
+        }
+    }
+
+    /// Scale of the Y axis a dataset is drawn against
+    fn y_scale_of(&self, dataset_index: usize) -> &ScaleType {
+        self.coord.y_scale_for(self.options.scales.y_axis_of(&self.data.datasets[dataset_index]))
+    }
+
+    /// Y value a point is drawn at: the top of its stack when stacked, else its own value
+    fn plotted_value(&self, dataset_index: usize, data_index: usize) -> f64 {
+        match self.stacks.get(dataset_index).and_then(|stack| stack.get(data_index)) {
+            Some(Some((_, top))) => *top,
+            _ => self.data.datasets[dataset_index].data[data_index].y,
+        }
+    }
 
-        // Set Y data range from data extent
-        if let Some((min, max)) = self.data.get_y_extent() {
-            let min = if self.options.scales.y.begin_at_zero && min > 0.0 { 0.0 } else { min };
-            self.coord.set_y_data_range(min, max);
+    /// Y value a stacked point starts from, zero when not stacked
+    fn stack_base(&self, dataset_index: usize, data_index: usize) -> f64 {
+        match self.stacks.get(dataset_index).and_then(|stack| stack.get(data_index)) {
+            Some(Some((base, _))) => *base,
+            _ => 0.0,
+        }
+    }
+
+    /// Pick the points of large datasets to draw when the chart area, the visible
+    /// ranges or the data changed since the last decimation
+    fn update_decimation(&mut self) {
+        let options = &self.options.decimation;
+        if !options.enabled {
+            self.decimated.clear();
+            return;
         }
+        let area = self.coord.chart_area().clone();
+        let (x_min, x_max) = self.coord.x_scale().get_data_bounds();
+        let (y_min, y_max) = self.coord.y_scale().get_data_bounds();
+        let key = [area.left, area.right, area.top, area.bottom, x_min, x_max, y_min, y_max];
+        if self.decimation_key == Some(key) {
+            return;
+        }
+        self.decimation_key = Some(key);
+
+        let (samples, threshold) = options.limits(area.right - area.left);
+        self.decimated = self.data.datasets.iter().enumerate().map(|(dataset_index, dataset)| {
+            if dataset.hidden || dataset.data.len() <= threshold {
+                return None;
+            }
+            // Decimate the final positions of the values that are there, not the animated ones
+            let present: Vec<usize> = (0..dataset.data.len()).filter(|&i| !dataset.data[i].is_missing()).collect();
+            let y_scale = self.y_scale_of(dataset_index);
+            let points: Vec<DVec2> = present.iter()
+                .map(|&i| dvec2(
+                    self.coord.x_scale().get_pixel_for_value(self.coord.x_value(dataset.data[i].x, i)),
+                    y_scale.get_pixel_for_value(self.plotted_value(dataset_index, i)),
+                ))
+                .collect();
+            let kept = decimate(&points, options.algorithm, samples, area.left, area.right);
+            Some(kept.into_iter().map(|k| present[k]).collect())
+        }).collect();
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    /// Data indices of the points of a dataset that are drawn and hit tested, in order,
+    /// leaving out missing values. Takes the decimated field alone so callers can keep
+    /// drawing while iterating.
+    fn drawn_indices<'a>(decimated: &'a [Option<Vec<usize>>], dataset_index: usize, data: &'a [DataPoint]) -> impl Iterator<Item = usize> + 'a {
+        // Either every index or the decimated ones
+        let (all, kept) = match decimated.get(dataset_index).and_then(|kept| kept.as_deref()) {
+            Some(kept) => (0..0, kept),
+            None => (0..data.len(), &[][..]),
+        };
+        all.chain(kept.iter().copied()).filter(move |&i| data.get(i).is_some_and(|point| !point.is_missing()))
+    }
+
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| self.data.datasets[i].border_color.or(self.data.datasets[i].background_color).unwrap_or_else(|| get_color(i)));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
     }
 
     fn start_animation(&mut self, cx: &mut Cx) {
@@ -279,54 +721,49 @@ impl LineChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        // Draw horizontal grid lines based on Y ticks
-        let tick_options = TickOptions::default();
-        let y_ticks = self.coord.build_y_ticks(&tick_options);
-
-        for tick in &y_ticks {
-            let (p1, p2) = self.coord.get_horizontal_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // X axis at the bottom
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.right, area.bottom), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, false, false);
+    }
 
-        // Y axis at the left
-        self.draw_grid.draw_line(cx, dvec2(area.left, area.bottom), dvec2(area.left, area.top), axis_width);
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn draw_lines(&mut self, cx: &mut Cx2d) {
         let progress = self.animator.get_progress();
         let line_width = 3.0;
-        let base_y = self.coord.y_scale().get_pixel_for_value(0.0);
         let tension = self.tension;
-        let fill = self.fill;
         let stepped = self.stepped.clone();
         let cubic_mode = self.cubic_interpolation_mode.clone();
         let progressive = self.progressive_animation;
         let gradient_enabled = self.gradient_enabled;
 
-        // Pre-calculate all points and colors to avoid borrow conflicts
-        let draw_data: Vec<(Vec4, Vec<DVec2>)> = self.data.datasets.iter().enumerate()
-            .filter(|(_, dataset)| dataset.data.len() >= 2)
+        let stacked = !self.stacks.is_empty();
+        let shape = |points: Vec<DVec2>| {
+            // Use cubic interpolation if tension > 0 and not stepped
+            if tension > 0.0 && stepped == SteppedMode::None {
+                let control_points = Self::calculate_control_points_static(&points, tension, &cubic_mode);
+                Self::cubic_path(&points, &control_points)
+            } else {
+                Self::stepped_path(&points, &stepped)
+            }
+        };
+
+        // Pre-calculate all paths, the paths of their stack bases and colors to avoid borrow conflicts
+        let draw_data: Vec<(usize, Vec4, Vec<Vec<DVec2>>, Vec<Vec<DVec2>>)> = self.data.datasets.iter().enumerate()
+            .filter(|(_, dataset)| !dataset.hidden && dataset.data.len() >= 2)
             .map(|(dataset_idx, dataset)| {
-                let color = dataset.border_color
+                let color = self.legend.highlight(dataset_idx, dataset.border_color
                     .or(dataset.background_color)
-                    .unwrap_or_else(|| get_color(dataset_idx));
+                    .unwrap_or_else(|| get_color(dataset_idx)));
 
                 // For progressive animation, calculate how many points to show
                 let total_points = dataset.data.len();
@@ -336,107 +773,131 @@ impl LineChart {
                     total_points
                 };
 
-                let points: Vec<DVec2> = dataset.data.iter().enumerate()
-                    .take(visible_points)
-                    .map(|(i, point)| {
-                        let x = self.coord.x_scale().get_pixel_for_value(i as f64);
-                        // For progressive, show full Y value; otherwise animate Y
-                        let y_value = if progressive { point.y } else { point.y * progress };
-                        let y = self.coord.y_scale().get_pixel_for_value(y_value);
-                        dvec2(x, y)
-                    }).collect();
-
-                (color, points)
-            }).collect();
-
-        // Now draw using the pre-calculated data
-        for (color, points) in draw_data {
-            // Draw fill first (behind the line)
-            if fill && points.len() >= 2 {
-                let fill_color = vec4(color.x, color.y, color.z, 0.3);
-                self.draw_fill.color = fill_color;
-
-                // Apply gradient if enabled
-                if gradient_enabled {
-                    let top_color = vec4(color.x, color.y, color.z, 0.6);
-                    let bottom_color = vec4(color.x, color.y, color.z, 0.05);
-                    self.draw_fill.set_vertical_gradient(top_color, bottom_color);
+                // For progressive, show full Y value; otherwise animate Y
+                let y_factor = if progressive { 1.0 } else { progress };
+                let indices: Vec<usize> = Self::drawn_indices(&self.decimated, dataset_idx, &dataset.data)
+                    .take_while(|&i| i < visible_points)
+                    .collect();
+
+                // Missing values split the line into runs drawn on their own
+                let runs: Vec<Vec<usize>> = dataset.line_runs(&indices, |x, i| self.coord.x_value(x, i))
+                    .into_iter()
+                    .filter(|run| run.len() >= 2)
+                    .collect();
+                let paths = runs.iter()
+                    .map(|run| shape(run.iter().map(|&i| self.point_pixel(dataset_idx, i, y_factor)).collect()))
+                    .collect();
+                let bases = if stacked {
+                    runs.iter().map(|run| shape(run.iter().map(|&i| self.base_pixel(dataset_idx, i, y_factor)).collect())).collect()
                 } else {
-                    self.draw_fill.disable_gradient();
-                }
+                    Vec::new()
+                };
 
-                if tension > 0.0 && stepped == SteppedMode::None {
-                    // Fill with cubic curve approximation
-                    let control_points = Self::calculate_control_points_static(&points, tension, &cubic_mode);
-                    self.draw_cubic_fill(cx, &points, &control_points, base_y);
-                } else {
-                    // Draw triangles from each line segment down to the base
-                    for i in 0..points.len() - 1 {
-                        let p1 = points[i];
-                        let p2 = points[i + 1];
-                        let b1 = dvec2(p1.x, base_y);
-                        let b2 = dvec2(p2.x, base_y);
-
-                        // Two triangles to fill the area
-                        self.draw_fill.draw_triangle(cx, p1, p2, b1);
-                        self.draw_fill.draw_triangle(cx, p2, b2, b1);
+                (dataset_idx, color, paths, bases)
+            })
+            // Decimation can leave nothing of a progressive line that has not reached the view yet
+            .filter(|(_, _, paths, _)| !paths.is_empty())
+            .collect();
+
+        // Draw every fill first, behind all lines
+        let (groups, _) = stack_groups(&self.data, &self.options.scales);
+        for (dataset_idx, color, paths, bases) in &draw_data {
+            let Some(target) = self.data.resolve_fill(*dataset_idx, self.fill) else {
+                continue;
+            };
+            // Stacked areas fill down to their base, which is the dataset below them in
+            // the same stack, and the first one of each stack to the origin
+            let to_base = stacked && target == FillTarget::Origin
+                && groups[..*dataset_idx].contains(&groups[*dataset_idx]);
+            let boundaries: Vec<Vec<DVec2>> = match target {
+                FillTarget::Dataset(target_idx) => draw_data.iter()
+                    .find(|(index, _, _, _)| *index == target_idx)
+                    .map(|(_, _, target_paths, _)| target_paths.clone())
+                    .unwrap_or_default(),
+                level => {
+                    let y = self.fill_level_pixel(*dataset_idx, level);
+                    paths.iter().map(|path| vec![dvec2(path[0].x, y), dvec2(path[path.len() - 1].x, y)]).collect()
+                }
+            };
+
+            let dataset = &self.data.datasets[*dataset_idx];
+            let reversed = self.options.scales.y_axis(self.options.scales.y_axis_of(dataset)).reverse;
+            let default_fill = vec4(color.x, color.y, color.z, 0.3);
+            let above_color = dataset.fill_above_color.map_or(default_fill, |c| self.legend.highlight(*dataset_idx, c));
+            let below_color = dataset.fill_below_color.map_or(above_color, |c| self.legend.highlight(*dataset_idx, c));
+
+            for (run, path) in paths.iter().enumerate() {
+                // Each run fills down to the base of the same run
+                let boundaries = if to_base { std::slice::from_ref(&bases[run]) } else { &boundaries[..] };
+                for boundary in boundaries {
+                    for ([p0, p1, p2], above) in fill_between(path, boundary) {
+                        // Pixel Y grows downwards unless the axis is reversed
+                        let fill_color = if above != reversed { above_color } else { below_color };
+                        self.draw_fill.color = fill_color;
+
+                        // Apply gradient if enabled
+                        if gradient_enabled {
+                            let top_color = vec4(fill_color.x, fill_color.y, fill_color.z, (fill_color.w * 2.0).min(1.0));
+                            let bottom_color = vec4(fill_color.x, fill_color.y, fill_color.z, fill_color.w / 6.0);
+                            self.draw_fill.set_vertical_gradient(top_color, bottom_color);
+                        } else {
+                            self.draw_fill.disable_gradient();
+                        }
+                        self.draw_fill.draw_triangle(cx, p0, p1, p2);
                     }
                 }
             }
+        }
 
-            // Draw line segments
+        // Then each line as one polyline per run
+        for (dataset_idx, color, paths, _) in draw_data {
+            let dataset = &self.data.datasets[dataset_idx];
+            let (join, cap) = (dataset.border_join_style, dataset.border_cap_style);
             self.draw_line.color = color;
+            self.draw_line.set_dash(&dataset.border_dash, dataset.border_dash_offset);
+            for path in paths {
+                self.draw_line.draw_polyline(cx, &path, line_width, join, cap);
+            }
+        }
+    }
 
-            // Use cubic interpolation if tension > 0 and not stepped
-            if tension > 0.0 && stepped == SteppedMode::None {
-                let control_points = Self::calculate_control_points_static(&points, tension, &cubic_mode);
-                self.draw_cubic_lines(cx, &points, &control_points, line_width);
-            } else {
-                match stepped {
-                    SteppedMode::None => {
-                        // Standard lines connecting points (linear)
-                        for i in 0..points.len() - 1 {
-                            let p1 = points[i];
-                            let p2 = points[i + 1];
-                            self.draw_line.draw_line(cx, p1, p2, line_width);
-                        }
-                    }
-                    SteppedMode::Before => {
-                        // Step before: vertical first, then horizontal
-                        for i in 0..points.len() - 1 {
-                            let p1 = points[i];
-                            let p2 = points[i + 1];
-                            let mid = dvec2(p1.x, p2.y);
-                            self.draw_line.draw_line(cx, p1, mid, line_width);
-                            self.draw_line.draw_line(cx, mid, p2, line_width);
-                        }
-                    }
-                    SteppedMode::After => {
-                        // Step after: horizontal first, then vertical
-                        for i in 0..points.len() - 1 {
-                            let p1 = points[i];
-                            let p2 = points[i + 1];
-                            let mid = dvec2(p2.x, p1.y);
-                            self.draw_line.draw_line(cx, p1, mid, line_width);
-                            self.draw_line.draw_line(cx, mid, p2, line_width);
-                        }
-                    }
-                    SteppedMode::Middle => {
-                        // Step in middle: horizontal, vertical, horizontal
-                        for i in 0..points.len() - 1 {
-                            let p1 = points[i];
-                            let p2 = points[i + 1];
-                            let mid_x = (p1.x + p2.x) / 2.0;
-                            let mid1 = dvec2(mid_x, p1.y);
-                            let mid2 = dvec2(mid_x, p2.y);
-                            self.draw_line.draw_line(cx, p1, mid1, line_width);
-                            self.draw_line.draw_line(cx, mid1, mid2, line_width);
-                            self.draw_line.draw_line(cx, mid2, p2, line_width);
-                        }
-                    }
+    /// Pixel Y of a fill target on the Y axis of a dataset: its visible bounds, a value,
+    /// or zero kept inside the bounds for `Origin`
+    fn fill_level_pixel(&self, dataset_index: usize, target: FillTarget) -> f64 {
+        let y_scale = self.y_scale_of(dataset_index);
+        let (min, max) = y_scale.get_data_bounds();
+        let value = match target {
+            FillTarget::Start => min,
+            FillTarget::End => max,
+            FillTarget::Value(value) => value,
+            _ => 0.0f64.clamp(min.min(max), min.max(max)),
+        };
+        y_scale.get_pixel_for_value(value)
+    }
+
+    /// Corners of the line through `points` for a stepped mode
+    fn stepped_path(points: &[DVec2], stepped: &SteppedMode) -> Vec<DVec2> {
+        let mut path = Vec::with_capacity(points.len() * 3);
+        path.extend(points.first().copied());
+        for pair in points.windows(2) {
+            let (p1, p2) = (pair[0], pair[1]);
+            match stepped {
+                // Standard lines connecting points (linear)
+                SteppedMode::None => {}
+                // Step before: vertical first, then horizontal
+                SteppedMode::Before => path.push(dvec2(p1.x, p2.y)),
+                // Step after: horizontal first, then vertical
+                SteppedMode::After => path.push(dvec2(p2.x, p1.y)),
+                // Step in middle: horizontal, vertical, horizontal
+                SteppedMode::Middle => {
+                    let mid_x = (p1.x + p2.x) / 2.0;
+                    path.push(dvec2(mid_x, p1.y));
+                    path.push(dvec2(mid_x, p2.y));
                 }
             }
+            path.push(p2);
         }
+        path
     }
 
     /// Calculate control points for cubic Bezier curves (static version)
@@ -547,46 +1008,22 @@ impl LineChart {
         control_points
     }
 
-    /// Draw cubic Bezier curves
-    fn draw_cubic_lines(&mut self, cx: &mut Cx2d, points: &[DVec2], control_points: &[(DVec2, DVec2)], line_width: f64) {
+    /// Points along the cubic Bezier curves through `points`
+    fn cubic_path(points: &[DVec2], control_points: &[(DVec2, DVec2)]) -> Vec<DVec2> {
         let segments = 20; // Number of segments to approximate each curve
 
+        let mut path = Vec::with_capacity(control_points.len() * segments + 1);
+        path.extend(points.first().copied());
         for (i, (cp1, cp2)) in control_points.iter().enumerate() {
             let p0 = points[i];
             let p3 = points[i + 1];
 
-            let mut prev = p0;
             for s in 1..=segments {
                 let t = s as f64 / segments as f64;
-                let pt = Self::cubic_bezier(p0, *cp1, *cp2, p3, t);
-                self.draw_line.draw_line(cx, prev, pt, line_width);
-                prev = pt;
-            }
-        }
-    }
-
-    /// Draw fill area under cubic curves
-    fn draw_cubic_fill(&mut self, cx: &mut Cx2d, points: &[DVec2], control_points: &[(DVec2, DVec2)], base_y: f64) {
-        let segments = 20;
-
-        for (i, (cp1, cp2)) in control_points.iter().enumerate() {
-            let p0 = points[i];
-            let p3 = points[i + 1];
-
-            let mut prev = p0;
-            for s in 1..=segments {
-                let t = s as f64 / segments as f64;
-                let pt = Self::cubic_bezier(p0, *cp1, *cp2, p3, t);
-
-                let b1 = dvec2(prev.x, base_y);
-                let b2 = dvec2(pt.x, base_y);
-
-                self.draw_fill.draw_triangle(cx, prev, pt, b1);
-                self.draw_fill.draw_triangle(cx, pt, b2, b1);
-
-                prev = pt;
+                path.push(Self::cubic_bezier(p0, *cp1, *cp2, p3, t));
             }
         }
+        path
     }
 
     /// Evaluate cubic Bezier curve at parameter t
@@ -607,22 +1044,32 @@ impl LineChart {
         let progress = self.animator.get_progress();
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-            let color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
-            self.draw_point.color = color;
+            if dataset.hidden {
+                continue;
+            }
+            self.draw_point.color = self.legend.highlight(dataset_idx, dataset.get_point_background_color(dataset_idx));
+            self.draw_point.apply_dataset(cx, dataset, dataset_idx);
+
+            let color = self.draw_point.color;
 
-            for (data_idx, point) in dataset.data.iter().enumerate() {
-                let x = self.coord.x_scale().get_pixel_for_value(data_idx as f64);
-                let y_value = point.y * progress;
-                let y = self.coord.y_scale().get_pixel_for_value(y_value);
+            for data_idx in Self::drawn_indices(&self.decimated, dataset_idx, &dataset.data) {
+                let is_selected = self.brush.is_selected(dataset_idx, data_idx);
+                if !self.show_points && !is_selected {
+                    continue;
+                }
+                let DVec2 { x, y } = self.point_pixel(dataset_idx, data_idx, progress);
 
                 let is_hovered = self.hovered_point >= 0 &&
                     self.hovered_point as usize == data_idx;
 
                 let radius = if is_hovered {
                     self.point_radius * 1.5
+                } else if is_selected {
+                    self.point_radius * 1.25
                 } else {
                     self.point_radius
                 };
+                self.draw_point.color = self.brush.highlight(dataset_idx, data_idx, color);
 
                 let rect = Rect {
                     pos: dvec2(x - radius, y - radius),
@@ -633,6 +1080,50 @@ impl LineChart {
         }
     }
 
+    /// Label the points of datasets with data labels, whether or not points are drawn
+    fn draw_data_labels(&mut self, cx: &mut Cx2d) {
+        let progress = self.animator.get_progress();
+        self.data_labels.clear();
+
+        for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            let Some(label_options) = dataset.data_labels.as_ref().filter(|_| !dataset.hidden) else {
+                continue;
+            };
+            let color = self.legend.highlight(dataset_idx, dataset.get_point_background_color(dataset_idx));
+            for data_idx in Self::drawn_indices(&self.decimated, dataset_idx, &dataset.data) {
+                let context = DataLabelContext::of(&self.data, dataset_idx, data_idx);
+                let target = LabelTarget::Point {
+                    center: self.point_pixel(dataset_idx, data_idx, progress),
+                    radius: self.point_radius,
+                };
+                self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, color);
+            }
+        }
+        self.data_labels.draw(cx, &mut self.draw_label, self.coord.chart_area().to_rect());
+    }
+
+    /// Register a hit region around every data point, whether or not points are drawn
+    fn register_hit_regions(&mut self) {
+        self.hit_tester.clear();
+        let hit_radius = self.point_radius.max(4.0) + 4.0;
+
+        for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            if dataset.hidden {
+                continue;
+            }
+            for data_idx in Self::drawn_indices(&self.decimated, dataset_idx, &dataset.data) {
+                let point = &dataset.data[data_idx];
+                let x = self.coord.x_scale().get_pixel_for_value(self.coord.x_value(point.x, data_idx));
+                let y = self.y_scale_of(dataset_idx).get_pixel_for_value(self.plotted_value(dataset_idx, data_idx));
+                let rect = Rect {
+                    pos: dvec2(x - hit_radius, y - hit_radius),
+                    size: dvec2(hit_radius * 2.0, hit_radius * 2.0),
+                };
+                self.hit_tester.register(rect, HitData::Point { dataset_index: dataset_idx, data_index: data_idx });
+            }
+        }
+    }
+
     fn handle_mouse_move(&mut self, cx: &mut Cx, pos: DVec2) {
         let old_hovered = self.hovered_point;
         self.hovered_point = -1;
@@ -641,16 +1132,18 @@ impl LineChart {
             if old_hovered != self.hovered_point {
                 self.redraw(cx);
             }
+            self.update_tooltip(cx, pos);
             return;
         }
 
         // Find nearest point
         let mut min_dist = f64::MAX;
 
-        for dataset in &self.data.datasets {
-            for (i, point) in dataset.data.iter().enumerate() {
-                let px = self.coord.x_scale().get_pixel_for_value(i as f64);
-                let py = self.coord.y_scale().get_pixel_for_value(point.y);
+        for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            for i in Self::drawn_indices(&self.decimated, dataset_idx, &dataset.data) {
+                let point = &dataset.data[i];
+                let px = self.coord.x_scale().get_pixel_for_value(self.coord.x_value(point.x, i));
+                let py = self.y_scale_of(dataset_idx).get_pixel_for_value(self.plotted_value(dataset_idx, i));
 
                 let dx = pos.x - px;
                 let dy = pos.y - py;
@@ -666,6 +1159,60 @@ impl LineChart {
         if old_hovered != self.hovered_point {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos);
+    }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let color_for = |ds: usize, _| {
+            let dataset = &data.datasets[ds];
+            dataset.border_color.or(dataset.background_color).unwrap_or_else(|| get_color(ds))
+        };
+        let content = if self.stacks.is_empty() {
+            TooltipContent::from_hits(data, &hits, color_for)
+        } else {
+            // Stacked values also show the total up to them
+            let elements: Vec<(usize, usize)> = hits.iter().filter_map(|hit| hit.data.indices()).collect();
+            let mode = self.options.scales.y.stacked;
+            TooltipContent::from_elements_formatted(data, &elements, color_for, |ds, i, point| {
+                format_stacked_value(point, self.plotted_value(ds, i), mode)
+            })
+        };
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for LineChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &self.stacks));
     }
 }
 
@@ -682,7 +1229,7 @@ impl LineChartRef {
         }
     }
 
-    pub fn set_fill(&self, fill: bool) {
+    pub fn set_fill(&self, fill: impl Into<FillTarget>) {
         if let Some(mut inner) = self.borrow_mut() {
             inner.set_fill(fill);
         }
@@ -724,12 +1271,30 @@ impl LineChartRef {
         }
     }
 
+    pub fn set_stacked(&self, mode: impl Into<StackMode>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_stacked(mode);
+        }
+    }
+
     pub fn redraw(&self, cx: &mut Cx) {
         if let Some(mut inner) = self.borrow_mut() {
             inner.redraw(cx);
         }
     }
 
+    pub fn push_point(&self, cx: &mut Cx, dataset_index: usize, point: DataPoint) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.push_point(cx, dataset_index, point);
+        }
+    }
+
+    pub fn push_labels<S: Into<String>>(&self, cx: &mut Cx, labels: Vec<S>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.push_labels(cx, labels);
+        }
+    }
+
     pub fn replay_animation(&self, cx: &mut Cx) {
         if let Some(mut inner) = self.borrow_mut() {
             inner.replay_animation(cx);
@@ -743,4 +1308,48 @@ impl LineChartRef {
             false
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
+
+    pub fn set_selection(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_selection(cx, x, y);
+        }
+    }
+
+    pub fn clear_selection(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.clear_selection(cx);
+        }
+    }
+
+    pub fn selection(&self) -> Option<ChartSelection> {
+        self.borrow().and_then(|inner| inner.selection().cloned())
+    }
 }
+
+impl ChartActions for LineChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
+
+impl CartesianChartActions for LineChartRef {}
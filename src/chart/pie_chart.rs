@@ -2,8 +2,11 @@ use makepad_widgets::*;
 use std::f64::consts::PI;
 use crate::core::*;
 use crate::coord::*;
+use crate::scale::format_percent;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartDataLabels, ChartLegend, ChartTitle, ChartTooltip, LabelTarget, LegendItemData, TooltipContent};
+use crate::interaction::{ChartAction, ChartActions, ElementPicker};
 
 live_design! {
     use link::theme::*;
@@ -15,6 +18,10 @@ live_design! {
     pub PieChart = {{PieChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -27,12 +34,24 @@ pub struct PieChart {
     #[live]
     draw_arc: DrawArc,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
     #[rust]
     data: ChartData,
 
     #[rust]
     options: ChartOptions,
 
+    #[rust]
+    title: ChartTitle,
+
     #[rust]
     coord: PolarCoord,
 
@@ -58,10 +77,17 @@ pub struct PieChart {
     #[rust(-1)]
     hovered_slice: i32,
 
+    #[rust]
+    picker: ElementPicker,
+
     /// Precomputed slice info
     #[rust]
     slices: Vec<SliceInfo>,
 
+    /// Labels of the slices drawn in the current frame
+    #[rust]
+    data_labels: ChartDataLabels,
+
     /// Gradient enabled
     #[rust(false)]
     gradient_enabled: bool,
@@ -84,12 +110,23 @@ impl Widget for PieChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
             }
             Event::MouseDown(e) => {
-                self.handle_mouse_down(cx, e.abs);
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_data(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -113,7 +150,11 @@ impl Widget for PieChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            let items = LegendItemData::from_labels(&self.data, get_color);
+            self.legend.set_items(items);
+            let plot_rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+            let plot_rect = self.legend.fit(cx, plot_rect);
+            self.update_coord(plot_rect);
 
             if !self.initialized {
                 self.compute_slices();
@@ -123,13 +164,16 @@ impl Widget for PieChart {
 
             self.draw_background(cx, rect);
             self.draw_slices(cx);
+            self.data_labels.draw(cx, &mut self.draw_label, rect);
+            self.title.draw(cx, &mut self.draw_label, &self.options);
+            self.legend.draw_legend(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
     }
 }
 
-
 impl PieChart {
     /// Set chart data
     pub fn set_data(&mut self, data: ChartData) {
@@ -139,6 +183,8 @@ impl PieChart {
 
     /// Set chart options
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
     }
 
@@ -189,15 +235,18 @@ impl PieChart {
             return;
         };
 
-        let total: f64 = dataset.data.iter().map(|p| p.y.max(0.0)).sum();
+        // Slices hidden from the legend keep their place with a zero value
+        let values: Vec<f64> = dataset.data.iter().enumerate()
+            .map(|(i, p)| if self.data.is_data_visible(i) { p.y.max(0.0) } else { 0.0 })
+            .collect();
+        let total: f64 = values.iter().sum();
         if total == 0.0 {
             return;
         }
 
         let mut cumulative = 0.0;
 
-        for (i, point) in dataset.data.iter().enumerate() {
-            let value = point.y.max(0.0);
+        for (i, &value) in values.iter().enumerate() {
             let percentage = value / total;
 
             let start_value = cumulative;
@@ -266,6 +315,8 @@ impl PieChart {
         let center = self.coord.center();
         let outer_radius = self.coord.outer_radius();
         let inner_radius = self.coord.inner_radius();
+        let label_options = self.data.datasets.first().and_then(|dataset| dataset.data_labels.as_ref());
+        self.data_labels.clear();
 
         for (i, slice) in self.slices.iter().enumerate() {
             let is_hovered = self.hovered_slice >= 0 && self.hovered_slice as usize == i;
@@ -300,6 +351,7 @@ impl PieChart {
             } else {
                 slice.color
             };
+            let base_color = self.legend.highlight(i, base_color);
             self.draw_arc.color = base_color;
 
             // Apply gradient if enabled
@@ -328,21 +380,30 @@ impl PieChart {
                 size: dvec2(outer_radius * 2.0, outer_radius * 2.0),
             };
             self.draw_arc.draw_arc(cx, rect);
+
+            if let Some(label_options) = label_options {
+                let mut context = DataLabelContext::of(&self.data, 0, i);
+                context.percentage = Some(slice.percentage);
+                let target = LabelTarget::Arc { center: draw_center, inner_radius, outer_radius, start_angle, end_angle };
+                self.data_labels.push(cx, &mut self.draw_label, label_options, target, &context, base_color);
+            }
         }
     }
 
     fn handle_mouse_move(&mut self, cx: &mut Cx, pos: DVec2) {
         let old_hovered = self.hovered_slice;
-        self.hovered_slice = -1;
+        let slice = self.slice_at_angle(pos);
+        self.hovered_slice = if self.coord.contains(pos) { slice } else { -1 };
 
-        if !self.coord.contains(pos) {
-            if old_hovered != self.hovered_slice {
-                self.redraw(cx);
-            }
-            return;
+        if old_hovered != self.hovered_slice {
+            self.redraw(cx);
         }
 
-        // Find which slice contains this point
+        self.update_tooltip(cx, pos, slice);
+    }
+
+    /// Index of the slice whose angular range contains `pos`, ignoring the radius
+    fn slice_at_angle(&self, pos: DVec2) -> i32 {
         let (angle, _radius) = self.coord.pixel_to_polar(pos);
 
         // Convert angle to value (0-1)
@@ -355,23 +416,71 @@ impl PieChart {
         }
         let value = normalized_angle / (2.0 * PI);
 
-        for (i, slice) in self.slices.iter().enumerate() {
-            if value >= slice.start_value && value < slice.end_value {
-                self.hovered_slice = i as i32;
-                break;
+        self.slices
+            .iter()
+            .position(|slice| value >= slice.start_value && value < slice.end_value)
+            .map(|i| i as i32)
+            .unwrap_or(-1)
+    }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2, slice_at_angle: i32) {
+        let opts = &self.options.tooltip;
+        let index = if opts.intersect {
+            self.hovered_slice
+        } else if self.tooltip.bounds().contains(pos) {
+            slice_at_angle
+        } else {
+            -1
+        };
+
+        let elements: Vec<(usize, usize)> = if index < 0 {
+            Vec::new()
+        } else if opts.mode == InteractionMode::Dataset {
+            (0..self.slices.len()).map(|i| (0, i)).collect()
+        } else {
+            vec![(0, index as usize)]
+        };
+
+        let slices = &self.slices;
+        let mut content = TooltipContent::from_elements(&self.data, &elements, |_, i| {
+            slices.get(i).map(|s| s.color).unwrap_or_else(|| get_color(i))
+        });
+
+        // Slices always have a label and a share of the whole
+        if let [(_, i)] = elements.as_slice() {
+            if let Some(slice) = slices.get(*i).filter(|_| content.title.is_empty()) {
+                content.title = slice.label.clone();
+            }
+        }
+        for (item, &(_, i)) in content.items.iter_mut().zip(&elements) {
+            if let Some(slice) = slices.get(i) {
+                item.text = format!("{} ({})", item.text, format_percent(slice.percentage));
             }
         }
 
-        if old_hovered != self.hovered_slice {
+        if self.tooltip.show(pos, content) {
             self.redraw(cx);
         }
     }
 
-    fn handle_mouse_down(&mut self, _cx: &mut Cx, _pos: DVec2) {
-        if self.hovered_slice >= 0 {
-            let slice = &self.slices[self.hovered_slice as usize];
-            log!("Slice clicked: {} ({}%)", slice.label, (slice.percentage * 100.0).round());
+    /// Slice under `pos`, as `(dataset_index, data_index)`
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains(pos) {
+            return None;
         }
+        let slice = self.slice_at_angle(pos);
+        (slice >= 0).then(|| (0, slice as usize))
+    }
+
+    /// Show or hide a data index from its legend item and animate into the new layout
+    fn toggle_data(&mut self, cx: &mut Cx, scope: &mut Scope, index: usize) {
+        self.data.toggle_data_visibility(index);
+        self.compute_slices();
+        self.start_animation(cx);
+
+        let action = ChartAction::DataToggled { data_index: index, visible: self.data.is_data_visible(index) };
+        let uid = self.widget_uid();
+        cx.widget_action(uid, &scope.path, action);
     }
 }
 
@@ -432,3 +541,9 @@ impl PieChartRef {
         }
     }
 }
+
+impl ChartActions for PieChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
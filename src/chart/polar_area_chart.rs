@@ -3,6 +3,8 @@ use std::f64::consts::PI;
 use crate::core::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartLegend, ChartTitle, ChartTooltip, LegendItemData, TooltipContent};
+use crate::interaction::{ChartAction, ChartActions, ElementPicker};
 
 live_design! {
     use link::theme::*;
@@ -14,6 +16,10 @@ live_design! {
     pub PolarAreaChart = {{PolarAreaChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -26,12 +32,24 @@ pub struct PolarAreaChart {
     #[live]
     draw_arc: DrawArc,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
     #[rust]
     data: ChartData,
 
     #[rust]
     options: ChartOptions,
 
+    #[rust]
+    title: ChartTitle,
+
     #[rust]
     animator: ChartAnimator,
 
@@ -50,6 +68,9 @@ pub struct PolarAreaChart {
     #[rust(-1)]
     hovered_segment: i32,
 
+    #[rust]
+    picker: ElementPicker,
+
     /// Enable radial gradient (inner to outer)
     #[rust(false)]
     gradient_enabled: bool,
@@ -67,9 +88,23 @@ impl Widget for PolarAreaChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.hovered_element();
+                self.picker.set_active(cx, scope, uid, &self.data, element);
+            }
+            Event::MouseDown(e) => {
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_data(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.hovered_element());
+                }
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -94,7 +129,11 @@ impl Widget for PolarAreaChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_layout(rect);
+            let items = LegendItemData::from_labels(&self.data, get_color);
+            self.legend.set_items(items);
+            let plot_rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+            let plot_rect = self.legend.fit(cx, plot_rect);
+            self.update_layout(plot_rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -102,6 +141,9 @@ impl Widget for PolarAreaChart {
             }
 
             self.draw_segments(cx);
+            self.title.draw(cx, &mut self.draw_label, &self.options);
+            self.legend.draw_legend(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -115,6 +157,8 @@ impl PolarAreaChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
     }
 
@@ -176,8 +220,12 @@ impl PolarAreaChart {
         }
 
         // Find max value for radius scaling
-        let max_value = dataset.data.iter()
-            .map(|p| p.y.max(0.0))
+        // Segments hidden from the legend keep their angle with a zero radius
+        let values: Vec<f64> = dataset.data.iter().enumerate()
+            .map(|(i, p)| if self.data.is_data_visible(i) { p.y.max(0.0) } else { 0.0 })
+            .collect();
+        let max_value = values.iter()
+            .copied()
             .fold(0.0f64, |a, b| a.max(b));
 
         if max_value == 0.0 {
@@ -187,10 +235,10 @@ impl PolarAreaChart {
         // Equal angles for each segment
         let angle_per_segment = 2.0 * PI / num_segments as f64;
 
-        for (i, point) in dataset.data.iter().enumerate() {
+        for (i, value) in values.into_iter().enumerate() {
             let start_angle = -PI / 2.0 + i as f64 * angle_per_segment;
             let end_angle = start_angle + angle_per_segment;
-            let radius_ratio = point.y.max(0.0) / max_value;
+            let radius_ratio = value / max_value;
 
             segments.push(SegmentInfo {
                 start_angle,
@@ -256,6 +304,7 @@ impl PolarAreaChart {
                 segment.color
             };
 
+            let color = self.legend.highlight(i, color);
             self.draw_arc.color = color;
 
             // Apply gradient if enabled
@@ -285,15 +334,9 @@ impl PolarAreaChart {
         let dy = pos.y - self.center.y;
         let dist = (dx * dx + dy * dy).sqrt();
 
-        if dist > self.max_radius {
-            if old_hovered != self.hovered_segment {
-                self.redraw(cx);
-            }
-            return;
-        }
-
         let angle = dy.atan2(dx);
         let segments = self.compute_segments();
+        let mut segment_at_angle = -1;
 
         for (i, segment) in segments.iter().enumerate() {
             // Normalize angle to check range
@@ -307,17 +350,61 @@ impl PolarAreaChart {
             while end < 0.0 { end += 2.0 * PI; }
 
             if check_angle >= start && check_angle < end {
+                segment_at_angle = i as i32;
                 let segment_radius = segment.radius_ratio * self.max_radius;
-                if dist <= segment_radius {
+                if dist <= self.max_radius && dist <= segment_radius {
                     self.hovered_segment = i as i32;
-                    break;
                 }
+                break;
             }
         }
 
         if old_hovered != self.hovered_segment {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos, segment_at_angle);
+    }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2, segment_at_angle: i32) {
+        let opts = &self.options.tooltip;
+        let index = if opts.intersect {
+            self.hovered_segment
+        } else if self.tooltip.bounds().contains(pos) {
+            segment_at_angle
+        } else {
+            -1
+        };
+
+        let count = self.data.datasets.first().map(|d| d.data.len()).unwrap_or(0);
+        let elements: Vec<(usize, usize)> = if index < 0 {
+            Vec::new()
+        } else if opts.mode == InteractionMode::Dataset {
+            (0..count).map(|i| (0, i)).collect()
+        } else {
+            vec![(0, index as usize)]
+        };
+
+        let content = TooltipContent::from_elements(&self.data, &elements, |_, i| get_color(i));
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    /// Hovered segment, as `(dataset_index, data_index)`
+    fn hovered_element(&self) -> Option<(usize, usize)> {
+        (self.hovered_segment >= 0).then(|| (0, self.hovered_segment as usize))
+    }
+
+    /// Show or hide a data index from its legend item and animate into the new layout
+    fn toggle_data(&mut self, cx: &mut Cx, scope: &mut Scope, index: usize) {
+        self.data.toggle_data_visibility(index);
+        self.start_animation(cx);
+
+        let action = ChartAction::DataToggled { data_index: index, visible: self.data.is_data_visible(index) };
+        let uid = self.widget_uid();
+        cx.widget_action(uid, &scope.path, action);
     }
 }
 
@@ -354,3 +441,9 @@ impl PolarAreaChartRef {
         }
     }
 }
+
+impl ChartActions for PolarAreaChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
@@ -3,6 +3,8 @@ use std::f64::consts::PI;
 use crate::core::*;
 use crate::element::*;
 use crate::animation::*;
+use crate::component::{ChartLegend, ChartTitle, ChartTooltip, LegendItemData, TooltipContent};
+use crate::interaction::*;
 
 live_design! {
     use link::theme::*;
@@ -10,12 +12,17 @@ live_design! {
     use link::widgets::*;
 
     use crate::element::line::DrawChartLine;
+    use crate::element::polyline::DrawPolyline;
     use crate::element::point::DrawPoint;
     use crate::element::triangle::DrawTriangle;
 
     pub RadarChart = {{RadarChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -26,7 +33,7 @@ pub struct RadarChart {
     view: View,
 
     #[live]
-    draw_line: DrawChartLine,
+    draw_line: DrawPolyline,
 
     #[live]
     draw_point: DrawPoint,
@@ -37,15 +44,33 @@ pub struct RadarChart {
     #[live]
     draw_fill: DrawTriangle,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
     #[rust]
     data: ChartData,
 
     #[rust]
     options: ChartOptions,
 
+    #[rust]
+    title: ChartTitle,
+
     #[rust]
     animator: ChartAnimator,
 
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     initialized: bool,
 
@@ -85,7 +110,24 @@ impl Widget for RadarChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+
         match event {
+            Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
+                self.update_tooltip(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
+            }
+            Event::MouseDown(e) => {
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
+            }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
                     let time = cx.seconds_since_app_start();
@@ -109,7 +151,14 @@ impl Widget for RadarChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_layout(rect);
+            let items = LegendItemData::from_datasets(&self.data, |i| {
+                let dataset = &self.data.datasets[i];
+                dataset.border_color.or(dataset.background_color).unwrap_or_else(|| get_color(i))
+            });
+            self.legend.set_items(items);
+            let plot_rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+            let plot_rect = self.legend.fit(cx, plot_rect);
+            self.update_layout(plot_rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -119,7 +168,11 @@ impl Widget for RadarChart {
             if self.show_grid {
                 self.draw_grid_lines(cx);
             }
+            self.hit_tester.clear();
             self.draw_datasets(cx);
+            self.title.draw(cx, &mut self.draw_label, &self.options);
+            self.legend.draw_legend(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
@@ -133,6 +186,8 @@ impl RadarChart {
     }
 
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
     }
 
@@ -222,31 +277,31 @@ impl RadarChart {
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
         let num_axes = self.get_num_axes();
-        if num_axes < 3 {
+        // The radial (value) scale is configured through the Y axis options
+        let axis = &self.options.scales.y;
+        if num_axes < 3 || !axis.display || !axis.grid.display {
             return;
         }
 
-        self.draw_grid.color = vec4(0.8, 0.8, 0.8, 1.0);
+        self.draw_grid.color = axis.grid.color;
+        self.draw_grid.set_dash(&axis.grid.dash, axis.grid.dash_offset);
+        let line_width = axis.grid.line_width;
 
-        // Draw concentric polygons
+        // Draw concentric polygons, each dashed as one path
         for level in 1..=self.grid_levels {
             let ratio = level as f64 / self.grid_levels as f64;
 
-            for i in 0..num_axes {
-                let angle1 = self.get_angle(i, num_axes);
-                let angle2 = self.get_angle((i + 1) % num_axes, num_axes);
-
-                let p1 = dvec2(
-                    self.center.x + self.radius * ratio * angle1.cos(),
-                    self.center.y + self.radius * ratio * angle1.sin(),
-                );
-                let p2 = dvec2(
-                    self.center.x + self.radius * ratio * angle2.cos(),
-                    self.center.y + self.radius * ratio * angle2.sin(),
-                );
-
-                self.draw_grid.draw_line(cx, p1, p2, 1.0);
-            }
+            let corners: Vec<DVec2> = (0..num_axes)
+                .map(|i| {
+                    let angle = self.get_angle(i, num_axes);
+                    dvec2(
+                        self.center.x + self.radius * ratio * angle.cos(),
+                        self.center.y + self.radius * ratio * angle.sin(),
+                    )
+                })
+                .collect();
+
+            self.draw_grid.draw_path(cx, &corners, line_width, true);
         }
 
         // Draw axis lines from center
@@ -256,7 +311,7 @@ impl RadarChart {
                 self.center.x + self.radius * angle.cos(),
                 self.center.y + self.radius * angle.sin(),
             );
-            self.draw_grid.draw_line(cx, self.center, outer, 1.0);
+            self.draw_grid.draw_line(cx, self.center, outer, line_width);
         }
     }
 
@@ -270,22 +325,31 @@ impl RadarChart {
 
         // Find max value across all datasets
         let max_value = self.data.datasets.iter()
+            .filter(|d| !d.hidden)
             .flat_map(|d| d.data.iter())
+            .filter(|p| !p.is_missing())
             .map(|p| p.y)
             .fold(0.0f64, |a, b| a.max(b));
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-            let color = dataset.border_color
+            if dataset.hidden {
+                continue;
+            }
+            let color = self.legend.highlight(dataset_idx, dataset.border_color
                 .or(dataset.background_color)
-                .unwrap_or_else(|| get_color(dataset_idx));
+                .unwrap_or_else(|| get_color(dataset_idx)));
 
-            // Collect points
+            // Collect points; missing values are left out of the outline
             let points: Vec<DVec2> = (0..num_axes).map(|i| {
                 let value = dataset.data.get(i).map(|p| p.y).unwrap_or(0.0);
                 let animated_value = value * progress;
                 let angle = self.get_angle(i, num_axes);
                 self.get_point(angle, animated_value, max_value)
             }).collect();
+            let outlines: Vec<(Vec<DVec2>, bool)> = dataset.closed_line_runs(num_axes)
+                .into_iter()
+                .map(|(run, closed)| (run.into_iter().map(|i| points[i]).collect(), closed))
+                .collect();
 
             // Draw fill first (behind lines)
             if self.show_fill {
@@ -317,26 +381,38 @@ impl RadarChart {
                     self.draw_fill.disable_gradient();
                 }
 
-                // Draw triangles from center to each edge
-                for i in 0..points.len() {
-                    let p1 = points[i];
-                    let p2 = points[(i + 1) % points.len()];
-                    self.draw_fill.draw_triangle(cx, self.center, p1, p2);
+                // Draw triangles from center to each edge of the outline
+                for (outline, closed) in &outlines {
+                    let edges = if *closed { outline.len() } else { outline.len() - 1 };
+                    for i in 0..edges {
+                        let p1 = outline[i];
+                        let p2 = outline[(i + 1) % outline.len()];
+                        self.draw_fill.draw_triangle(cx, self.center, p1, p2);
+                    }
                 }
             }
 
-            // Draw polygon lines
+            // Draw polygon lines, or the open runs between missing values
             self.draw_line.color = color;
-            for i in 0..points.len() {
-                let p1 = points[i];
-                let p2 = points[(i + 1) % points.len()];
-                self.draw_line.draw_line(cx, p1, p2, 2.0);
+            self.draw_line.set_dash(&dataset.border_dash, dataset.border_dash_offset);
+            for (outline, closed) in &outlines {
+                if *closed {
+                    self.draw_line.draw_polygon(cx, outline, 2.0, dataset.border_join_style);
+                } else {
+                    self.draw_line.draw_polyline(cx, outline, 2.0, dataset.border_join_style, dataset.border_cap_style);
+                }
             }
 
+            let is_present = |i: usize| dataset.data.get(i).is_none_or(|p| !p.is_missing());
+
             // Draw points
             if self.show_points {
-                self.draw_point.color = color;
-                for point in &points {
+                self.draw_point.color = dataset.point_background_color.unwrap_or(color);
+                self.draw_point.apply_dataset(cx, dataset, dataset_idx);
+                for (i, point) in points.iter().enumerate() {
+                    if !is_present(i) {
+                        continue;
+                    }
                     let rect = Rect {
                         pos: dvec2(point.x - self.point_radius, point.y - self.point_radius),
                         size: dvec2(self.point_radius * 2.0, self.point_radius * 2.0),
@@ -344,8 +420,58 @@ impl RadarChart {
                     self.draw_point.draw_point(cx, rect);
                 }
             }
+
+            let hit_radius = self.point_radius + 4.0;
+            for (data_idx, point) in points.iter().enumerate().take(dataset.data.len()).filter(|(i, _)| is_present(*i)) {
+                let hit_rect = Rect {
+                    pos: dvec2(point.x - hit_radius, point.y - hit_radius),
+                    size: dvec2(hit_radius * 2.0, hit_radius * 2.0),
+                };
+                self.hit_tester.register(hit_rect, HitData::Point { dataset_index: dataset_idx, data_index: data_idx });
+            }
         }
     }
+
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.tooltip.bounds().contains(pos) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let content = TooltipContent::from_hits(data, &hits, |ds, _| {
+            let dataset = &data.datasets[ds];
+            dataset.border_color.or(dataset.background_color).unwrap_or_else(|| get_color(ds))
+        });
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
+        }
+    }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.tooltip.bounds().contains(pos) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for RadarChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        // The scale follows the visible datasets when drawn
+    }
 }
 
 impl RadarChartRef {
@@ -393,3 +519,9 @@ impl RadarChartRef {
         }
     }
 }
+
+impl ChartActions for RadarChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
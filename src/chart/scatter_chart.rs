@@ -1,9 +1,11 @@
 use makepad_widgets::*;
-use crate::core::{ChartData, ChartOptions, TickOptions, get_color, lighten};
+use crate::core::{annotation_y_extent, merge_extents, AxisType, ChartData, ChartOptions, get_color, lighten};
 use crate::coord::{CartesianCoord, ScaleType};
-use crate::scale::LinearScale;
+use crate::scale::{LinearScale, TimeScale};
 use crate::element::{DrawPoint, PointStyle, DrawGridLine};
 use crate::animation::ChartAnimator;
+use crate::component::{ChartAnnotations, ChartAxis, ChartBrush, BrushTarget, ChartGrid, ChartLegend, ChartTitle, ChartTooltip, ChartZoom, LegendItemData, TooltipContent};
+use crate::interaction::{CartesianChartActions, ChartActions, ChartSelection, DatasetToggle, ElementPicker, HitData, HitRegion, HitTester};
 
 live_design! {
     use link::theme::*;
@@ -16,6 +18,10 @@ live_design! {
     pub ScatterChart = {{ScatterChart}} {
         width: Fill,
         height: Fill,
+
+        draw_label: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
@@ -31,6 +37,24 @@ pub struct ScatterChart {
     #[live]
     draw_grid: DrawGridLine,
 
+    #[live]
+    draw_label: DrawText,
+
+    #[live]
+    legend: ChartLegend,
+
+    #[live]
+    tooltip: ChartTooltip,
+
+    #[live]
+    zoom: ChartZoom,
+
+    #[live]
+    brush: ChartBrush,
+
+    #[live]
+    annotations: ChartAnnotations,
+
     #[rust]
     data: ChartData,
 
@@ -40,6 +64,21 @@ pub struct ScatterChart {
     #[rust]
     coord: CartesianCoord,
 
+    #[rust]
+    axis: ChartAxis,
+
+    #[rust]
+    grid: ChartGrid,
+
+    #[rust]
+    title: ChartTitle,
+
+    #[rust]
+    hit_tester: HitTester,
+
+    #[rust]
+    picker: ElementPicker,
+
     #[rust]
     animator: ChartAnimator,
 
@@ -50,9 +89,9 @@ pub struct ScatterChart {
     #[rust(6.0)]
     point_radius: f64,
 
-    /// Point style
+    /// Point style overriding the per-dataset `point_style`
     #[rust]
-    point_style: PointStyle,
+    point_style: Option<PointStyle>,
 
     /// Hovered point info (dataset_idx, point_idx)
     #[rust((-1, -1))]
@@ -67,12 +106,31 @@ impl Widget for ScatterChart {
     fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         self.view.handle_event(cx, event, scope);
 
+        let uid = self.widget_uid();
+        if self.zoom.handle_event(cx, event, scope, uid, &mut self.coord) {
+            self.redraw(cx);
+        }
+        let target = BrushTarget::new(&self.data, &self.coord, &self.options.scales, &[]);
+        if self.brush.handle_event(cx, event, scope, uid, &target) {
+            self.redraw(cx);
+        }
+        self.annotations.handle_event(cx, event, scope, uid, &self.coord);
+
         match event {
             Event::MouseMove(e) => {
+                if self.legend.set_hovered_at(e.abs) {
+                    self.redraw(cx);
+                }
                 self.handle_mouse_move(cx, e.abs);
+                let element = self.element_at(e.abs);
+                self.picker.set_active(cx, scope, uid, &self.data, element);
             }
             Event::MouseDown(e) => {
-                self.handle_mouse_down(cx, e.abs);
+                if let Some(index) = self.legend.item_at(e.abs) {
+                    self.toggle_dataset(cx, scope, index);
+                } else {
+                    self.picker.click(cx, scope, uid, &self.data, self.element_at(e.abs));
+                }
             }
             Event::NextFrame(_) => {
                 if self.animator.is_running() {
@@ -96,7 +154,7 @@ impl Widget for ScatterChart {
 
         let rect = cx.turtle().rect();
         if rect.size.x > 0.0 && rect.size.y > 0.0 {
-            self.update_coord(rect);
+            self.update_coord(cx, rect);
 
             if !self.initialized {
                 self.start_animation(cx);
@@ -106,24 +164,37 @@ impl Widget for ScatterChart {
             self.draw_background(cx, rect);
             self.draw_grid_lines(cx);
             self.draw_axes(cx);
+            self.hit_tester.clear();
+            self.brush.draw(cx, &self.coord);
+            self.zoom.begin_clip(cx, &self.coord);
+            self.annotations.draw_below(cx, &self.coord, &self.options.annotations);
             self.draw_points(cx);
+            self.annotations.draw_above(cx, &self.coord, &self.options.annotations);
+            self.zoom.end_clip(cx);
+            self.zoom.draw_drag_box(cx, &self.coord);
+            self.draw_labels(cx);
+            self.tooltip.draw_tooltip(cx, rect);
         }
 
         DrawStep::done()
     }
 }
 
-
 impl ScatterChart {
     /// Set chart data
     pub fn set_data(&mut self, data: ChartData) {
         self.data = data;
         self.initialized = false;
         self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &[]));
     }
 
     /// Set chart options
     pub fn set_options(&mut self, options: ChartOptions) {
+        self.tooltip.set_options(options.tooltip.clone());
+        self.zoom.set_options(options.effective_zoom());
+        self.brush.set_options(options.brush.clone());
+        self.legend.set_options(options.legend.clone());
         self.options = options;
         // Re-setup coordinate system with new options
         if !self.data.datasets.is_empty() {
@@ -131,14 +202,50 @@ impl ScatterChart {
         }
     }
 
+    /// Show `x` and `y` instead of the data ranges, as if zoomed; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.zoom.set_view(x, y);
+        self.redraw(cx);
+    }
+
+    /// Go back to the data ranges after zooming or panning
+    pub fn reset_zoom(&mut self, cx: &mut Cx) {
+        if self.zoom.reset() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Whether the chart is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.is_zoomed()
+    }
+
+    /// Select the points inside `x` and `y` as if brushed; `None` leaves an axis unbounded
+    pub fn set_selection(&mut self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.brush.select(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &[]), x, y);
+        self.redraw(cx);
+    }
+
+    /// Clear the brush selection
+    pub fn clear_selection(&mut self, cx: &mut Cx) {
+        if self.brush.clear() {
+            self.redraw(cx);
+        }
+    }
+
+    /// Current brush selection
+    pub fn selection(&self) -> Option<&ChartSelection> {
+        self.brush.selection()
+    }
+
     /// Set point radius
     pub fn set_point_radius(&mut self, radius: f64) {
         self.point_radius = radius;
     }
 
-    /// Set point style
+    /// Draw every dataset with `style`, ignoring the per-dataset `point_style`
     pub fn set_point_style(&mut self, style: PointStyle) {
-        self.point_style = style;
+        self.point_style = Some(style);
     }
 
     /// Enable radial gradient for points (center to edge)
@@ -147,31 +254,47 @@ impl ScatterChart {
     }
 
     fn setup_coord_from_data(&mut self) {
-        // Scatter charts use linear scales for both axes
-        self.coord = CartesianCoord::new()
-            .with_x_scale(ScaleType::Linear(
+        // Scatter charts use linear scales, or a time scale for timestamps on the X axis
+        let x_scale = match self.options.scales.x.scale_type {
+            AxisType::Time => ScaleType::Time(TimeScale::new().with_timestamps(self.data.get_x_values())),
+            _ => ScaleType::value_scale(
+                &self.options.scales.x,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.x.begin_at_zero)
                     .with_nice(true)
-            ))
-            .with_y_scale(ScaleType::Linear(
+            ),
+        };
+        self.coord = CartesianCoord::new()
+            .with_x_scale(x_scale)
+            .with_y_scale(ScaleType::value_scale(
+                &self.options.scales.y,
                 LinearScale::new()
                     .with_begin_at_zero(self.options.scales.y.begin_at_zero)
                     .with_nice(true)
             ));
+        self.coord.apply_axis_options(&self.options.scales);
 
         // Set data ranges from extent
+        self.coord.set_x_min_positive(self.data.get_min_positive_x());
         if let Some((x_min, x_max)) = self.data.get_x_extent() {
             self.coord.set_x_data_range(x_min, x_max);
         }
-        if let Some((y_min, y_max)) = self.data.get_y_extent() {
+        // Scatter charts have a single Y axis that every annotation is drawn on
+        let annotations = annotation_y_extent(&self.options.annotations, None, |_| None);
+        self.coord.set_y_axis_min_positive(None, self.data.get_min_positive_y_where(|_, _| true));
+        if let Some((y_min, y_max)) = merge_extents(self.data.get_y_extent(), annotations) {
             let y_min = if self.options.scales.y.begin_at_zero && y_min > 0.0 { 0.0 } else { y_min };
             self.coord.set_y_data_range(y_min, y_max);
         }
     }
 
-    fn update_coord(&mut self, rect: Rect) {
-        self.coord.update(rect);
+    fn update_coord(&mut self, cx: &mut Cx2d, rect: Rect) {
+        self.zoom.apply(&mut self.coord);
+        let items = LegendItemData::from_datasets(&self.data, |i| self.data.datasets[i].get_point_background_color(i));
+        self.legend.set_items(items);
+        let rect = self.title.fit(cx, &mut self.draw_label, &self.options, rect);
+        let rect = self.legend.fit(cx, rect);
+        self.axis.fit(cx, &mut self.draw_label, &mut self.coord, rect, &self.options.scales);
     }
 
     fn start_animation(&mut self, cx: &mut Cx) {
@@ -210,63 +333,38 @@ impl ScatterChart {
     }
 
     fn draw_grid_lines(&mut self, cx: &mut Cx2d) {
-        let grid_color = vec4(0.9, 0.9, 0.9, 1.0);
-        let grid_width = 0.5;
-
-        self.draw_grid.color = grid_color;
-
-        let tick_options = TickOptions::default();
-
-        // Draw horizontal grid lines based on Y ticks
-        let y_ticks = self.coord.build_y_ticks(&tick_options);
-        for tick in &y_ticks {
-            let (p1, p2) = self.coord.get_horizontal_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
-
-        // Draw vertical grid lines based on X ticks
-        let x_ticks = self.coord.build_x_ticks(&tick_options);
-        for tick in &x_ticks {
-            let (p1, p2) = self.coord.get_vertical_grid_line(tick.value);
-            self.draw_grid.draw_line(cx, p1, p2, grid_width);
-        }
+        self.grid.draw_y_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales);
+        self.grid.draw_x_lines(cx, &mut self.draw_grid, &self.coord, &self.options.scales.x);
     }
 
     fn draw_axes(&mut self, cx: &mut Cx2d) {
-        let axis_color = vec4(0.7, 0.7, 0.7, 1.0);
-        let axis_width = 0.5;
-
-        self.draw_grid.color = axis_color;
-
-        let area = self.coord.chart_area();
-
-        // Draw X axis at y=0 if in range, otherwise at bottom
-        let (y_min, y_max) = self.coord.y_scale().get_data_bounds();
-        let x_axis_y = if y_min <= 0.0 && y_max >= 0.0 {
-            self.coord.y_scale().get_pixel_for_value(0.0)
-        } else {
-            area.bottom
-        };
-        self.draw_grid.draw_line(cx, dvec2(area.left, x_axis_y), dvec2(area.right, x_axis_y), axis_width);
+        let scales = &self.options.scales;
+        self.grid.draw_tick_marks(cx, &mut self.draw_grid, &self.coord, scales);
+        self.grid.draw_borders(cx, &mut self.draw_grid, &self.coord, scales, true, true);
+    }
 
-        // Draw Y axis at x=0 if in range, otherwise at left
-        let (x_min, x_max) = self.coord.x_scale().get_data_bounds();
-        let y_axis_x = if x_min <= 0.0 && x_max >= 0.0 {
-            self.coord.x_scale().get_pixel_for_value(0.0)
-        } else {
-            area.left
-        };
-        self.draw_grid.draw_line(cx, dvec2(y_axis_x, area.bottom), dvec2(y_axis_x, area.top), axis_width);
+    fn draw_labels(&mut self, cx: &mut Cx2d) {
+        self.axis.draw(cx, &mut self.draw_label, &self.coord, &self.options.scales);
+        self.title.draw(cx, &mut self.draw_label, &self.options);
+        self.legend.draw_legend(cx);
     }
 
     fn draw_points(&mut self, cx: &mut Cx2d) {
         let progress = self.animator.get_progress();
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
-            let base_color = dataset.background_color.unwrap_or_else(|| get_color(dataset_idx));
+            if dataset.hidden {
+                continue;
+            }
+            let base_color = self.legend.highlight(dataset_idx, dataset.get_point_background_color(dataset_idx));
+            self.draw_point.apply_dataset(cx, dataset, dataset_idx);
+            if let Some(style) = self.point_style {
+                self.draw_point.set_style(style);
+            }
 
             for (point_idx, point) in dataset.data.iter().enumerate() {
                 let is_hovered = self.hovered_point == (dataset_idx as i32, point_idx as i32);
+                let is_selected = self.brush.is_selected(dataset_idx, point_idx);
 
                 // Get x value (either from point.x or index)
                 let x_value = point.x.unwrap_or(point_idx as f64);
@@ -277,9 +375,11 @@ impl ScatterChart {
                 // Animation: points fade in and scale up
                 let animated_radius = self.point_radius * progress;
 
-                // Hover effect
+                // Hover effect, selected points grow a little
                 let radius = if is_hovered {
                     animated_radius * 1.5
+                } else if is_selected {
+                    animated_radius * 1.25
                 } else {
                     animated_radius
                 };
@@ -289,6 +389,7 @@ impl ScatterChart {
                 } else {
                     base_color
                 };
+                let color = self.brush.highlight(dataset_idx, point_idx, color);
 
                 self.draw_point.color = color;
 
@@ -305,6 +406,13 @@ impl ScatterChart {
                     size: dvec2(radius * 2.0, radius * 2.0),
                 };
                 self.draw_point.draw_point(cx, rect);
+
+                let hit_radius = self.point_radius + 4.0;
+                let hit_rect = Rect {
+                    pos: dvec2(px - hit_radius, py - hit_radius),
+                    size: dvec2(hit_radius * 2.0, hit_radius * 2.0),
+                };
+                self.hit_tester.register(hit_rect, HitData::Point { dataset_index: dataset_idx, data_index: point_idx });
             }
         }
     }
@@ -317,6 +425,7 @@ impl ScatterChart {
             if old_hovered != self.hovered_point {
                 self.redraw(cx);
             }
+            self.update_tooltip(cx, pos);
             return;
         }
 
@@ -325,6 +434,9 @@ impl ScatterChart {
         let hit_radius = self.point_radius * 2.0 + 5.0;
 
         for (dataset_idx, dataset) in self.data.datasets.iter().enumerate() {
+            if dataset.hidden {
+                continue;
+            }
             for (point_idx, point) in dataset.data.iter().enumerate() {
                 let x_value = point.x.unwrap_or(point_idx as f64);
                 let px = self.coord.x_scale().get_pixel_for_value(x_value);
@@ -344,19 +456,50 @@ impl ScatterChart {
         if old_hovered != self.hovered_point {
             self.redraw(cx);
         }
+
+        self.update_tooltip(cx, pos);
     }
 
-    fn handle_mouse_down(&mut self, _cx: &mut Cx, _pos: DVec2) {
-        let (dataset_idx, point_idx) = self.hovered_point;
-        if dataset_idx >= 0 && point_idx >= 0 {
-            if let Some(dataset) = self.data.datasets.get(dataset_idx as usize) {
-                if let Some(point) = dataset.data.get(point_idx as usize) {
-                    let x = point.x.unwrap_or(point_idx as f64);
-                    log!("Point clicked: ({}, {})", x, point.y);
-                }
-            }
+    fn update_tooltip(&mut self, cx: &mut Cx, pos: DVec2) {
+        let hits: Vec<HitRegion> = if self.coord.contains_pixel(pos.x, pos.y) {
+            let opts = &self.options.tooltip;
+            self.hit_tester
+                .query(pos, opts.mode, opts.intersect, self.options.interaction.axis)
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let data = &self.data;
+        let content = TooltipContent::from_hits(data, &hits, |ds, _| {
+            data.datasets[ds].background_color.unwrap_or_else(|| get_color(ds))
+        });
+
+        if self.tooltip.show(pos, content) {
+            self.redraw(cx);
         }
     }
+
+    /// Element under `pos` according to the interaction options
+    fn element_at(&self, pos: DVec2) -> Option<(usize, usize)> {
+        if !self.coord.contains_pixel(pos.x, pos.y) {
+            return None;
+        }
+        self.hit_tester.element_at(pos, &self.options.interaction)
+    }
+}
+
+impl DatasetToggle for ScatterChart {
+    fn toggled_data(&mut self) -> &mut ChartData {
+        &mut self.data
+    }
+
+    fn refresh_ranges(&mut self) {
+        self.setup_coord_from_data();
+        self.brush.refresh(&BrushTarget::new(&self.data, &self.coord, &self.options.scales, &[]));
+    }
 }
 
 impl ScatterChartRef {
@@ -403,4 +546,48 @@ impl ScatterChartRef {
             false
         }
     }
+
+    pub fn set_view(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_view(cx, x, y);
+        }
+    }
+
+    pub fn reset_zoom(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.reset_zoom(cx);
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        if let Some(inner) = self.borrow() {
+            inner.is_zoomed()
+        } else {
+            false
+        }
+    }
+
+    pub fn set_selection(&self, cx: &mut Cx, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_selection(cx, x, y);
+        }
+    }
+
+    pub fn clear_selection(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.clear_selection(cx);
+        }
+    }
+
+    pub fn selection(&self) -> Option<ChartSelection> {
+        self.borrow().and_then(|inner| inner.selection().cloned())
+    }
 }
+
+impl ChartActions for ScatterChartRef {
+    fn chart_uid(&self) -> WidgetUid {
+        self.widget_uid()
+    }
+}
+
+impl CartesianChartActions for ScatterChartRef {}
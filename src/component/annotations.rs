@@ -0,0 +1,272 @@
+use makepad_widgets::*;
+use crate::core::{Annotation, AnnotationDrawTime, AnnotationKind};
+use crate::coord::{AnnotationShape, CartesianCoord};
+use crate::interaction::{AnnotationHit, ChartAction};
+use crate::element::{draw_text_at, measure_text, set_text_style, DrawGridLine, DrawPoint};
+use super::tooltip::DrawTooltipBox;
+
+live_design! {
+    use link::theme::*;
+    use link::shaders::*;
+
+    pub DrawAnnotationShape = {{DrawAnnotationShape}} {
+        fn pixel(self) -> vec4 {
+            let p = self.pos * self.rect_size;
+            let half = self.rect_size * 0.5;
+
+            // Signed distance to the outline, negative inside
+            let d = 0.0;
+            if self.ellipse > 0.5 {
+                let q = (p - half) / max(half, vec2(0.001, 0.001));
+                d = (length(q) - 1.0) * min(half.x, half.y);
+            } else {
+                let q = abs(p - half) - half;
+                d = max(q.x, q.y);
+            }
+
+            let color = self.color;
+            if self.border_width > 0.0 {
+                color = mix(color, self.border_color, clamp(d + self.border_width + 0.5, 0.0, 1.0));
+            }
+            let coverage = clamp(0.5 - d, 0.0, 1.0);
+            return vec4(color.rgb * color.a, color.a) * coverage;
+        }
+    }
+
+    pub ChartAnnotations = {{ChartAnnotations}} {
+        draw_text: {
+            text_style: <THEME_FONT_REGULAR> {
+                font_size: 11.0
+            }
+        }
+    }
+}
+
+/// Space between an annotation label and its background edge
+const LABEL_PADDING: f64 = 3.0;
+
+/// Pixels around lines and points that still pick them
+const HIT_TOLERANCE: f64 = 4.0;
+
+/// Points of the outline of an ellipse annotation with a dashed border
+const ELLIPSE_SEGMENTS: usize = 48;
+
+/// Filled box or ellipse with an outline drawn inside its edge
+#[derive(Live, LiveHook, LiveRegister)]
+#[repr(C)]
+pub struct DrawAnnotationShape {
+    #[deref] pub draw_super: DrawQuad,
+    #[live] pub color: Vec4,
+    #[live] pub border_color: Vec4,
+    /// Outline width in pixels (0.0 = no outline)
+    #[live(0.0)] pub border_width: f32,
+    /// 1.0 draws an ellipse, 0.0 a box
+    #[live(0.0)] pub ellipse: f32,
+}
+
+/// An annotation drawn in the current frame
+#[derive(Clone, Debug)]
+struct DrawnAnnotation {
+    hit: AnnotationHit,
+    shape: AnnotationShape,
+    /// Label box, which also picks the annotation
+    label: Option<Rect>,
+    /// Pixels around the shape that pick it
+    tolerance: f64,
+}
+
+/// Lines, boxes, ellipses, points and labels placed in data coordinates on a
+/// cartesian chart. Annotations are drawn under or over the datasets per their
+/// draw time, and the pointer entering, leaving or clicking one sends an action.
+#[derive(Live, LiveHook, LiveRegister)]
+pub struct ChartAnnotations {
+    #[live]
+    draw_shape: DrawAnnotationShape,
+
+    #[live]
+    draw_line: DrawGridLine,
+
+    #[live]
+    draw_point: DrawPoint,
+
+    #[live]
+    draw_label_bg: DrawTooltipBox,
+
+    #[live]
+    draw_text: DrawText,
+
+    /// Annotations drawn in the last frame, in drawing order
+    #[rust]
+    drawn: Vec<DrawnAnnotation>,
+
+    /// Annotation under the pointer
+    #[rust]
+    hovered: Option<AnnotationHit>,
+}
+
+impl ChartAnnotations {
+    /// Annotation on top at `pos`, if any
+    pub fn annotation_at(&self, pos: DVec2) -> Option<&AnnotationHit> {
+        self.drawn
+            .iter()
+            .rev()
+            .find(|drawn| {
+                drawn.shape.contains(pos, drawn.tolerance)
+                    || drawn.label.is_some_and(|label| label.contains(pos))
+            })
+            .map(|drawn| &drawn.hit)
+    }
+
+    /// Send `ChartAction::AnnotationEntered`, `AnnotationLeft` and `AnnotationClicked`
+    /// for the annotations drawn in the last frame. Pointers outside the chart area of
+    /// `coord` are on no annotation.
+    pub fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope, uid: WidgetUid, coord: &CartesianCoord) {
+        let inside = |pos: DVec2| coord.contains_pixel(pos.x, pos.y);
+        match event {
+            Event::MouseMove(e) => {
+                let hit = if inside(e.abs) { self.annotation_at(e.abs).cloned() } else { None };
+                if hit == self.hovered {
+                    return;
+                }
+                if let Some(left) = self.hovered.take() {
+                    cx.widget_action(uid, &scope.path, ChartAction::AnnotationLeft(left));
+                }
+                if let Some(entered) = hit.clone() {
+                    cx.widget_action(uid, &scope.path, ChartAction::AnnotationEntered(entered));
+                }
+                self.hovered = hit;
+            }
+            Event::MouseDown(e) if inside(e.abs) => {
+                if let Some(hit) = self.annotation_at(e.abs).cloned() {
+                    cx.widget_action(uid, &scope.path, ChartAction::AnnotationClicked(hit));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Draw the annotations that go under the datasets. Call before drawing the
+    /// datasets, and `draw_above` after them.
+    pub fn draw_below(&mut self, cx: &mut Cx2d, coord: &CartesianCoord, annotations: &[Annotation]) {
+        self.drawn.clear();
+        self.draw_at(cx, coord, annotations, AnnotationDrawTime::BeforeDatasets);
+    }
+
+    /// Draw the annotations that go over the datasets
+    pub fn draw_above(&mut self, cx: &mut Cx2d, coord: &CartesianCoord, annotations: &[Annotation]) {
+        self.draw_at(cx, coord, annotations, AnnotationDrawTime::AfterDatasets);
+    }
+
+    fn draw_at(&mut self, cx: &mut Cx2d, coord: &CartesianCoord, annotations: &[Annotation], draw_time: AnnotationDrawTime) {
+        let area = coord.chart_area().to_rect();
+        for (index, annotation) in annotations.iter().enumerate() {
+            if annotation.draw_time != draw_time {
+                continue;
+            }
+            // Boxes reaching past the chart area stop at its edges, for drawing and picking
+            let shape = match AnnotationShape::of(annotation, coord) {
+                AnnotationShape::Box(rect) => AnnotationShape::Box(clamp_rect(rect, &area)),
+                shape => shape,
+            };
+            if !shape.overlaps(&area) {
+                continue;
+            }
+            self.draw_figure(cx, annotation, shape);
+            let label = annotation.label.as_deref().map(|text| self.draw_label(cx, annotation, shape, text));
+            self.drawn.push(DrawnAnnotation {
+                hit: AnnotationHit { index, id: annotation.id.clone() },
+                shape,
+                label,
+                tolerance: annotation.border_width / 2.0 + HIT_TOLERANCE,
+            });
+        }
+    }
+
+    fn draw_figure(&mut self, cx: &mut Cx2d, annotation: &Annotation, shape: AnnotationShape) {
+        let dashed = !annotation.border_dash.is_empty();
+        match shape {
+            AnnotationShape::Line { from, to } => {
+                if annotation.border_width > 0.0 {
+                    self.draw_line.color = annotation.border_color;
+                    self.draw_line.set_dash(&annotation.border_dash, 0.0);
+                    self.draw_line.draw_line(cx, from, to, annotation.border_width);
+                }
+            }
+            AnnotationShape::Box(rect) | AnnotationShape::Ellipse(rect) => {
+                let ellipse = matches!(shape, AnnotationShape::Ellipse(_));
+                if rect.size.x <= 0.0 || rect.size.y <= 0.0 {
+                    return;
+                }
+                self.draw_shape.color = annotation.color;
+                self.draw_shape.border_color = annotation.border_color;
+                self.draw_shape.border_width = if dashed { 0.0 } else { annotation.border_width as f32 };
+                self.draw_shape.ellipse = if ellipse { 1.0 } else { 0.0 };
+                self.draw_shape.draw_abs(cx, rect);
+
+                if dashed && annotation.border_width > 0.0 {
+                    let outline = if ellipse { ellipse_outline(rect) } else { box_outline(rect) };
+                    self.draw_line.color = annotation.border_color;
+                    self.draw_line.set_dash(&annotation.border_dash, 0.0);
+                    self.draw_line.draw_path(cx, &outline, annotation.border_width, true);
+                }
+            }
+            AnnotationShape::Point { center, radius } => {
+                let AnnotationKind::Point { style, .. } = annotation.kind else {
+                    return;
+                };
+                self.draw_point.color = annotation.color;
+                self.draw_point.disable_gradient();
+                self.draw_point.set_style(style);
+                self.draw_point.set_border(annotation.border_color, annotation.border_width);
+                self.draw_point.draw_point(cx, Rect {
+                    pos: dvec2(center.x - radius, center.y - radius),
+                    size: dvec2(radius * 2.0, radius * 2.0),
+                });
+            }
+            AnnotationShape::Label { .. } => {}
+        }
+    }
+
+    /// Draw the label of an annotation on its background and return its box
+    fn draw_label(&mut self, cx: &mut Cx2d, annotation: &Annotation, shape: AnnotationShape, text: &str) -> Rect {
+        set_text_style(&mut self.draw_text, annotation.label_font_size, annotation.label_color);
+        let size = measure_text(cx, &self.draw_text, text);
+        let rect = shape.label_rect(size + dvec2(LABEL_PADDING, LABEL_PADDING) * 2.0);
+        if annotation.label_background.w > 0.0 {
+            self.draw_label_bg.color = annotation.label_background;
+            self.draw_label_bg.border_width = 0.0;
+            self.draw_label_bg.radius = 3.0;
+            self.draw_label_bg.draw_abs(cx, rect);
+        }
+        draw_text_at(cx, &mut self.draw_text, rect.pos + dvec2(LABEL_PADDING, LABEL_PADDING), text);
+        rect
+    }
+}
+
+/// Part of `rect` inside `area`, empty when they do not overlap
+fn clamp_rect(rect: Rect, area: &Rect) -> Rect {
+    let left = rect.pos.x.max(area.pos.x);
+    let top = rect.pos.y.max(area.pos.y);
+    let right = (rect.pos.x + rect.size.x).min(area.pos.x + area.size.x);
+    let bottom = (rect.pos.y + rect.size.y).min(area.pos.y + area.size.y);
+    Rect {
+        pos: dvec2(left, top),
+        size: dvec2((right - left).max(0.0), (bottom - top).max(0.0)),
+    }
+}
+
+fn box_outline(rect: Rect) -> Vec<DVec2> {
+    let (min, max) = (rect.pos, rect.pos + rect.size);
+    vec![min, dvec2(max.x, min.y), max, dvec2(min.x, max.y)]
+}
+
+fn ellipse_outline(rect: Rect) -> Vec<DVec2> {
+    let half = rect.size * 0.5;
+    let center = rect.pos + half;
+    (0..ELLIPSE_SEGMENTS)
+        .map(|i| {
+            let angle = i as f64 / ELLIPSE_SEGMENTS as f64 * std::f64::consts::TAU;
+            dvec2(center.x + half.x * angle.cos(), center.y + half.y * angle.sin())
+        })
+        .collect()
+}
@@ -1,11 +1,293 @@
-/// Chart axis component for rendering axis lines and labels
+use makepad_widgets::*;
+use crate::core::{AxisOptions, ScalesOptions};
+use crate::coord::{AxisPosition, CartesianCoord};
+use crate::element::*;
+use super::ChartGrid;
+
+/// Minimum gap kept between the chart area and the widget edge
+const MIN_EDGE_PADDING: f64 = 10.0;
+
+/// Chart axis component for laying out and drawing tick labels and axis titles
 #[derive(Clone, Debug, Default)]
 pub struct ChartAxis {
-    // Placeholder - to be implemented
+    /// Rotation applied to X tick labels in degrees
+    x_rotation: f64,
+    /// Size of the largest X tick label (unrotated)
+    x_label_size: DVec2,
+    /// Size of the largest Y tick label
+    y_label_size: DVec2,
+    /// Width taken by each Y axis outside the chart area, in `CartesianCoord::y_axes` order
+    y_widths: Vec<f64>,
 }
 
 impl ChartAxis {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Current X tick label rotation in degrees
+    pub fn x_rotation(&self) -> f64 {
+        self.x_rotation
+    }
+
+    /// Reserve room for tick labels and axis titles, then fit the coordinate
+    /// system into `rect`
+    pub fn fit(
+        &mut self,
+        cx: &mut Cx2d,
+        draw_text: &mut DrawText,
+        coord: &mut CartesianCoord,
+        rect: Rect,
+        scales: &ScalesOptions,
+    ) {
+        // Y labels determine the left and right padding. The Y axes on each side
+        // are placed outwards from the chart area in order.
+        self.y_label_size = dvec2(0.0, 0.0);
+        self.y_widths.clear();
+        let mut left = 0.0f64;
+        let mut right_axes = 0.0f64;
+        for axis in coord.y_axes(scales) {
+            let options = &axis.options;
+            let mut width = ChartGrid::tick_mark_length(options);
+            if Self::labels_visible(options) {
+                set_text_style(draw_text, options.ticks.font_size, options.ticks.color);
+                let mut label_width = 0.0f64;
+                for tick in &axis.ticks {
+                    let size = measure_text(cx, draw_text, &tick.label);
+                    label_width = label_width.max(size.x);
+                    self.y_label_size = dvec2(self.y_label_size.x.max(size.x), self.y_label_size.y.max(size.y));
+                }
+                width += label_width + options.ticks.padding;
+            }
+            if let Some(size) = Self::measure_y_title(cx, draw_text, options) {
+                width += size.x + options.title.padding * 2.0;
+            }
+            let side = if axis.position == AxisPosition::Right { &mut right_axes } else { &mut left };
+            coord.set_y_axis_offset(axis.id.as_deref(), *side);
+            *side += width;
+            self.y_widths.push(width);
+        }
+        let left = left.max(MIN_EDGE_PADDING);
+        let top = (self.y_label_size.y / 2.0).max(MIN_EDGE_PADDING);
+
+        // X labels determine the bottom padding and may need rotation
+        self.x_label_size = dvec2(0.0, 0.0);
+        let mut last_label_width = 0.0;
+        let x_ticks = if Self::labels_visible(&scales.x) {
+            coord.build_x_ticks(&scales.x.ticks)
+        } else {
+            Vec::new()
+        };
+        if !x_ticks.is_empty() {
+            set_text_style(draw_text, scales.x.ticks.font_size, scales.x.ticks.color);
+            for tick in &x_ticks {
+                let size = measure_text(cx, draw_text, &tick.label);
+                self.x_label_size = dvec2(self.x_label_size.x.max(size.x), self.x_label_size.y.max(size.y));
+                last_label_width = size.x;
+            }
+        }
+
+        let right = (last_label_width / 2.0).max(MIN_EDGE_PADDING * 2.0).max(right_axes);
+
+        // Provisional fit to find the pixel spacing between X ticks
+        coord.set_padding(left, top, right, MIN_EDGE_PADDING);
+        coord.update(rect);
+
+        let spacing = x_ticks
+            .windows(2)
+            .map(|w| {
+                let a = coord.x_scale().get_pixel_for_value(w[0].value);
+                let b = coord.x_scale().get_pixel_for_value(w[1].value);
+                (b - a).abs()
+            })
+            .fold(f64::MAX, f64::min);
+        let spacing = if spacing == f64::MAX { coord.chart_area().width() } else { spacing };
+
+        self.x_rotation = fit_label_rotation(
+            self.x_label_size,
+            spacing,
+            scales.x.ticks.min_rotation,
+            scales.x.ticks.max_rotation,
+        );
+
+        let mut bottom = ChartGrid::tick_mark_length(&scales.x);
+        if !x_ticks.is_empty() {
+            bottom += scales.x.ticks.padding + rotated_label_height(self.x_label_size, self.x_rotation);
+        }
+        if let Some(size) = Self::measure_x_title(cx, draw_text, &scales.x) {
+            bottom += size.y + scales.x.title.padding * 2.0;
+        }
+        let bottom = bottom.max(MIN_EDGE_PADDING);
+        let right = if self.x_rotation > 0.0 { (MIN_EDGE_PADDING * 2.0).max(right_axes) } else { right };
+
+        coord.set_padding(left, top, right, bottom);
+        coord.update(rect);
+    }
+
+    /// Draw tick labels and axis titles around the chart area
+    pub fn draw(
+        &mut self,
+        cx: &mut Cx2d,
+        draw_text: &mut DrawText,
+        coord: &CartesianCoord,
+        scales: &ScalesOptions,
+    ) {
+        let area = coord.chart_area().clone();
+
+        // Y tick labels against their axis, right aligned on the left side and left
+        // aligned on the right, and the titles along the outer edge of each axis
+        for (axis, width) in coord.y_axes(scales).iter().zip(&self.y_widths) {
+            let options = &axis.options;
+            let line_x = axis.line_start.x;
+            let on_right = axis.position == AxisPosition::Right;
+            if Self::labels_visible(options) {
+                set_text_style(draw_text, options.ticks.font_size, options.ticks.color);
+                let distance = ChartGrid::tick_mark_length(options) + options.ticks.padding;
+                let (x, align) = if on_right {
+                    (line_x + distance, dvec2(0.0, 0.5))
+                } else {
+                    (line_x - distance, dvec2(1.0, 0.5))
+                };
+                let y_scale = coord.y_scale_for(axis.id.as_deref());
+                for tick in &axis.ticks {
+                    let y = y_scale.get_pixel_for_value(tick.value);
+                    if y < area.top - 1.0 || y > area.bottom + 1.0 {
+                        continue;
+                    }
+                    draw_text_aligned(cx, draw_text, dvec2(x, y), align, &tick.label);
+                }
+            }
+
+            if let Some(size) = Self::measure_y_title(cx, draw_text, options) {
+                let x = if on_right {
+                    line_x + width - options.title.padding - size.x
+                } else {
+                    line_x - width + options.title.padding
+                };
+                let y = (area.top + area.bottom) / 2.0 - size.y / 2.0;
+                draw_text_vertical(cx, draw_text, dvec2(x, y), &options.title.text);
+            }
+        }
+
+        // X tick labels, centered below the axis or rotated so they end at the tick
+        let label_top = area.bottom + ChartGrid::tick_mark_length(&scales.x) + scales.x.ticks.padding;
+        if Self::labels_visible(&scales.x) {
+            set_text_style(draw_text, scales.x.ticks.font_size, scales.x.ticks.color);
+            let rad = self.x_rotation.to_radians();
+            for tick in coord.build_x_ticks(&scales.x.ticks) {
+                let x = coord.x_scale().get_pixel_for_value(tick.value);
+                if x < area.left - 1.0 || x > area.right + 1.0 {
+                    continue;
+                }
+                if self.x_rotation > 0.0 {
+                    let width = measure_text(cx, draw_text, &tick.label).x;
+                    let start = dvec2(x - width * rad.cos(), label_top + width * rad.sin());
+                    draw_text_rotated(cx, draw_text, start, self.x_rotation, &tick.label);
+                } else {
+                    draw_text_aligned(cx, draw_text, dvec2(x, label_top), dvec2(0.5, 0.0), &tick.label);
+                }
+            }
+        }
+
+        // X axis title, centered below the tick labels
+        if let Some(size) = Self::measure_x_title(cx, draw_text, &scales.x) {
+            let mut y = label_top + scales.x.title.padding;
+            if Self::labels_visible(&scales.x) {
+                y += rotated_label_height(self.x_label_size, self.x_rotation);
+            }
+            let x = (area.left + area.right) / 2.0 - size.x / 2.0;
+            draw_text_at(cx, draw_text, dvec2(x, y), &scales.x.title.text);
+        }
+    }
+
+    /// Whether tick labels of `axis` are drawn
+    fn labels_visible(axis: &AxisOptions) -> bool {
+        axis.display && axis.ticks.display
+    }
+
+    /// Set the title style and measure the X axis title, if it is displayed
+    fn measure_x_title(cx: &mut Cx2d, draw_text: &mut DrawText, axis: &AxisOptions) -> Option<DVec2> {
+        if !axis.display || !axis.title.display || axis.title.text.is_empty() {
+            return None;
+        }
+        set_text_style(draw_text, axis.title.font_size, axis.title.color);
+        Some(measure_text(cx, draw_text, &axis.title.text))
+    }
+
+    /// Set the title style and measure the vertical Y axis title, if it is displayed
+    fn measure_y_title(cx: &mut Cx2d, draw_text: &mut DrawText, axis: &AxisOptions) -> Option<DVec2> {
+        if !axis.display || !axis.title.display || axis.title.text.is_empty() {
+            return None;
+        }
+        set_text_style(draw_text, axis.title.font_size, axis.title.color);
+        Some(measure_text_vertical(cx, draw_text, &axis.title.text))
+    }
+}
+
+/// Vertical space taken by a label of `size` rotated by `rotation` degrees
+pub fn rotated_label_height(size: DVec2, rotation: f64) -> f64 {
+    let rad = rotation.to_radians();
+    size.x * rad.sin().abs() + size.y
+}
+
+/// Pick the smallest label rotation (in degrees) within `min_rotation..=max_rotation`
+/// at which labels of `label_size` placed `spacing` pixels apart do not collide
+pub fn fit_label_rotation(label_size: DVec2, spacing: f64, min_rotation: f64, max_rotation: f64) -> f64 {
+    let min_rotation = min_rotation.clamp(0.0, 90.0);
+    let max_rotation = max_rotation.clamp(min_rotation, 90.0);
+
+    if spacing <= 0.0 || label_size.x <= 0.0 {
+        return min_rotation;
+    }
+
+    // Leave a small gap between neighbouring labels
+    let width = label_size.x + 4.0;
+    let height = label_size.y + 2.0;
+
+    if width <= spacing {
+        return min_rotation;
+    }
+
+    // Rotated labels stop overlapping once either their horizontal footprint
+    // fits the spacing, or the perpendicular distance between parallel
+    // baselines exceeds the label height
+    let by_width = (spacing / width).min(1.0).acos().to_degrees();
+    let by_height = (height / spacing).min(1.0).asin().to_degrees();
+
+    by_width.min(by_height).clamp(min_rotation, max_rotation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_rotation_when_labels_fit() {
+        let rotation = fit_label_rotation(dvec2(30.0, 12.0), 60.0, 0.0, 50.0);
+        assert_eq!(rotation, 0.0);
+    }
+
+    #[test]
+    fn test_rotation_when_labels_collide() {
+        let rotation = fit_label_rotation(dvec2(80.0, 12.0), 30.0, 0.0, 50.0);
+        assert!(rotation > 0.0);
+        assert!(rotation <= 50.0);
+    }
+
+    #[test]
+    fn test_rotation_respects_limits() {
+        // Very tight spacing wants more rotation than allowed
+        let rotation = fit_label_rotation(dvec2(200.0, 14.0), 10.0, 0.0, 45.0);
+        assert_eq!(rotation, 45.0);
+
+        // Min rotation is always applied
+        let rotation = fit_label_rotation(dvec2(10.0, 12.0), 100.0, 20.0, 50.0);
+        assert_eq!(rotation, 20.0);
+    }
+
+    #[test]
+    fn test_rotated_label_height() {
+        assert_eq!(rotated_label_height(dvec2(40.0, 12.0), 0.0), 12.0);
+        assert!((rotated_label_height(dvec2(40.0, 12.0), 90.0) - 52.0).abs() < 1e-9);
     }
 }
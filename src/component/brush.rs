@@ -0,0 +1,299 @@
+use makepad_widgets::*;
+use crate::core::{BrushOptions, ChartData, InteractionAxis, ScalesOptions};
+use crate::coord::CartesianCoord;
+use crate::interaction::{ChartAction, ChartSelection};
+use crate::element::{dash_pattern, DrawGridLine};
+use super::tooltip::DrawTooltipBox;
+
+live_design! {
+    use link::shaders::*;
+
+    pub ChartBrush = {{ChartBrush}} {}
+}
+
+/// Smallest dragged region that selects instead of counting as a click
+const MIN_DRAG_SIZE: f64 = 4.0;
+
+/// A brush drag in progress over the chart area
+#[derive(Clone, Copy, Debug)]
+struct BrushDrag {
+    start: DVec2,
+    last: DVec2,
+}
+
+/// What a brush selects from: the data of a chart and where its points are plotted
+#[derive(Clone, Copy)]
+pub struct BrushTarget<'a> {
+    pub data: &'a ChartData,
+    pub coord: &'a CartesianCoord,
+    /// Scales the datasets' Y axes are resolved through
+    pub scales: &'a ScalesOptions,
+    /// Stacked places of the points from `stack_values`, empty when not stacked
+    pub stacks: &'a [Vec<Option<(f64, f64)>>],
+}
+
+impl<'a> BrushTarget<'a> {
+    pub fn new(
+        data: &'a ChartData,
+        coord: &'a CartesianCoord,
+        scales: &'a ScalesOptions,
+        stacks: &'a [Vec<Option<(f64, f64)>>],
+    ) -> Self {
+        Self { data, coord, scales, stacks }
+    }
+
+    /// Points inside the ranges, comparing each at its plotted (stacked) value on its
+    /// own Y axis with `y_range` on the default one
+    fn points_in_rect(&self, x_range: (f64, f64), y_range: (f64, f64)) -> Vec<(usize, usize)> {
+        let coord = self.coord;
+        self.data.points_in_rect(x_range, y_range, |x, index| coord.x_value(x, index), |dataset_index, data_index, y| {
+            let plotted = match self.stacks.get(dataset_index).and_then(|stack| stack.get(data_index)) {
+                Some(Some((_, top))) => *top,
+                _ => y,
+            };
+            coord.y_on_default_axis(plotted, self.scales.y_axis_of(&self.data.datasets[dataset_index]))
+        })
+    }
+}
+
+/// Brush selection for cartesian charts: drag over the chart area to select the
+/// elements in an X range, a Y range or a rectangle per `BrushOptions`, click to
+/// clear. The region is kept in data space so it follows zooming and panning.
+/// A dashed border can march around the region for a "marching ants" outline.
+#[derive(Live, LiveHook, LiveRegister)]
+pub struct ChartBrush {
+    #[live]
+    draw_box: DrawTooltipBox,
+
+    #[live]
+    draw_border: DrawGridLine,
+
+    #[rust]
+    options: BrushOptions,
+
+    #[rust]
+    drag: Option<BrushDrag>,
+
+    #[rust]
+    selection: Option<ChartSelection>,
+
+    /// Distance the border dash pattern has moved
+    #[rust]
+    dash_offset: f64,
+
+    /// Time of the last frame moving the border dashes
+    #[rust]
+    dash_time: Option<f64>,
+}
+
+impl ChartBrush {
+    pub fn set_options(&mut self, options: BrushOptions) {
+        self.options = options;
+        if !self.options.enabled {
+            self.drag = None;
+            self.selection = None;
+        }
+    }
+
+    pub fn options(&self) -> &BrushOptions {
+        &self.options
+    }
+
+    pub fn selection(&self) -> Option<&ChartSelection> {
+        self.selection.as_ref()
+    }
+
+    /// Select the elements of `target` inside `x` and `y`, with `y` on the default Y axis;
+    /// `None` leaves an axis unbounded
+    pub fn select(
+        &mut self,
+        target: &BrushTarget,
+        x: Option<(f64, f64)>,
+        y: Option<(f64, f64)>,
+    ) -> &ChartSelection {
+        let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+        let (x_min, x_max) = x.map_or(unbounded, |(a, b)| (a.min(b), a.max(b)));
+        let (y_min, y_max) = y.map_or(unbounded, |(a, b)| (a.min(b), a.max(b)));
+        let indices = target.points_in_rect((x_min, x_max), (y_min, y_max));
+        self.selection.insert(ChartSelection { x_min, x_max, y_min, y_max, indices })
+    }
+
+    /// Select again inside the same region after the data or visible datasets changed
+    pub fn refresh(&mut self, target: &BrushTarget) {
+        if let Some(selection) = &mut self.selection {
+            let x_range = (selection.x_min, selection.x_max);
+            let y_range = (selection.y_min, selection.y_max);
+            selection.indices = target.points_in_rect(x_range, y_range);
+        }
+    }
+
+    /// Drop the selection. Returns true if there was one.
+    pub fn clear(&mut self) -> bool {
+        self.selection.take().is_some()
+    }
+
+    /// Whether the element at `(dataset_index, data_index)` is in the selection
+    pub fn is_selected(&self, dataset_index: usize, data_index: usize) -> bool {
+        self.selection
+            .as_ref()
+            .is_some_and(|selection| selection.contains(dataset_index, data_index))
+    }
+
+    /// Dim `color` when a selection exists and does not hold the element
+    pub fn highlight(&self, dataset_index: usize, data_index: usize, color: Vec4) -> Vec4 {
+        match &self.selection {
+            Some(selection) if !selection.contains(dataset_index, data_index) => {
+                vec4(color.x, color.y, color.z, color.w * self.options.dimmed_alpha)
+            }
+            _ => color,
+        }
+    }
+
+    /// Handle brush drags and clicks. Returns true when the chart needs a redraw;
+    /// `ChartAction::SelectionChanged` or `ChartAction::SelectionCleared` is sent when the selection changed.
+    pub fn handle_event(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        scope: &mut Scope,
+        uid: WidgetUid,
+        target: &BrushTarget,
+    ) -> bool {
+        if !self.options.enabled {
+            return false;
+        }
+        let coord = target.coord;
+
+        match event {
+            Event::NextFrame(_) if self.marching() => {
+                let time = cx.seconds_since_app_start();
+                // Frames stop while nothing is selected; do not jump when they start again
+                let elapsed = self.dash_time.map_or(0.0, |last| (time - last).min(0.1));
+                self.dash_time = Some(time);
+                self.dash_offset = (self.dash_offset - self.options.border_dash_speed * elapsed).rem_euclid(self.dash_period());
+                true
+            }
+            Event::MouseDown(e) if coord.contains_pixel(e.abs.x, e.abs.y) => {
+                self.drag = Some(BrushDrag { start: e.abs, last: e.abs });
+                false
+            }
+            Event::MouseMove(e) => {
+                let Some(drag) = self.drag.as_mut() else {
+                    return false;
+                };
+                drag.last = e.abs;
+                true
+            }
+            Event::MouseUp(e) => {
+                let Some(drag) = self.drag.take() else {
+                    return false;
+                };
+                let size = e.abs - drag.start;
+                let (select_x, select_y) = self.axes();
+                let big_enough = (!select_x || size.x.abs() >= MIN_DRAG_SIZE) && (!select_y || size.y.abs() >= MIN_DRAG_SIZE);
+                if !big_enough {
+                    // A click clears the selection
+                    if self.clear() {
+                        cx.widget_action(uid, &scope.path, ChartAction::SelectionCleared);
+                    }
+                    return true;
+                }
+
+                let area = coord.chart_area();
+                let (start, end) = (drag.start, e.abs);
+                let x = select_x.then(|| {
+                    let clamp = |x: f64| coord.x_for_pixel(x.clamp(area.left, area.right));
+                    (clamp(start.x), clamp(end.x))
+                });
+                let y = select_y.then(|| {
+                    let clamp = |y: f64| coord.y_for_pixel(y.clamp(area.top, area.bottom));
+                    (clamp(start.y), clamp(end.y))
+                });
+                let selection = self.select(target, x, y).clone();
+                cx.widget_action(uid, &scope.path, ChartAction::SelectionChanged(selection));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Length of the border dash pattern, zero for a solid border
+    fn dash_period(&self) -> f64 {
+        dash_pattern(&self.options.border_dash).iter().sum::<f32>() as f64
+    }
+
+    /// Whether a dashed border is moving and needs a frame after every draw
+    fn marching(&self) -> bool {
+        self.options.border_dash_speed != 0.0
+            && self.dash_period() > 0.0
+            && (self.drag.is_some() || self.selection.is_some())
+    }
+
+    /// Which of the X and Y axes the brush spans
+    fn axes(&self) -> (bool, bool) {
+        match self.options.mode {
+            InteractionAxis::X => (true, false),
+            InteractionAxis::Y => (false, true),
+            InteractionAxis::XY => (true, true),
+        }
+    }
+
+    /// Draw the region being dragged, or else the selected region, clipped to the chart area
+    pub fn draw(&mut self, cx: &mut Cx2d, coord: &CartesianCoord) {
+        if !self.options.enabled {
+            return;
+        }
+        let area = coord.chart_area().clone();
+        let (left, right, top, bottom) = if let Some(drag) = self.drag {
+            let (select_x, select_y) = self.axes();
+            let (left, right) = if select_x {
+                (drag.start.x.min(drag.last.x), drag.start.x.max(drag.last.x))
+            } else {
+                (area.left, area.right)
+            };
+            let (top, bottom) = if select_y {
+                (drag.start.y.min(drag.last.y), drag.start.y.max(drag.last.y))
+            } else {
+                (area.top, area.bottom)
+            };
+            (left, right, top, bottom)
+        } else if let Some(selection) = &self.selection {
+            // Pixels grow downwards on Y and either way on reversed axes
+            let a = coord.data_to_pixel(selection.x_min, selection.y_min);
+            let b = coord.data_to_pixel(selection.x_max, selection.y_max);
+            (a.x.min(b.x), a.x.max(b.x), a.y.min(b.y), a.y.max(b.y))
+        } else {
+            return;
+        };
+
+        let left = left.clamp(area.left, area.right);
+        let right = right.clamp(area.left, area.right);
+        let top = top.clamp(area.top, area.bottom);
+        let bottom = bottom.clamp(area.top, area.bottom);
+        if right <= left || bottom <= top {
+            return;
+        }
+
+        let dashed = !self.options.border_dash.is_empty();
+        self.draw_box.color = self.options.color;
+        self.draw_box.border_color = self.options.border_color;
+        self.draw_box.border_width = if dashed { 0.0 } else { 1.0 };
+        self.draw_box.radius = 0.0;
+        self.draw_box.draw_abs(cx, Rect {
+            pos: dvec2(left, top),
+            size: dvec2(right - left, bottom - top),
+        });
+
+        if dashed {
+            // One path around the region so the dashes run on past the corners
+            let corners = [dvec2(left, top), dvec2(right, top), dvec2(right, bottom), dvec2(left, bottom)];
+            self.draw_border.color = self.options.border_color;
+            self.draw_border.set_dash(&self.options.border_dash, self.dash_offset);
+            self.draw_border.draw_path(cx, &corners, 1.0, true);
+        }
+
+        if self.marching() {
+            cx.new_next_frame();
+        }
+    }
+}
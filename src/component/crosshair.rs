@@ -0,0 +1,133 @@
+use makepad_widgets::*;
+use crate::core::{CrosshairOptions, InteractionAxis, InteractionMode};
+use crate::coord::CartesianCoord;
+use crate::element::*;
+use crate::interaction::HitTester;
+use super::tooltip::DrawTooltipBox;
+
+live_design! {
+    use link::theme::*;
+    use link::shaders::*;
+
+    pub ChartCrosshair = {{ChartCrosshair}} {
+        draw_text: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
+    }
+}
+
+/// Space between a value label's text and its background edge
+const LABEL_PADDING: f64 = 3.0;
+
+/// Crosshair guide lines following the pointer over a cartesian chart, with the
+/// values under them shown on the axes
+#[derive(Live, LiveHook, LiveRegister)]
+pub struct ChartCrosshair {
+    #[live]
+    draw_label_bg: DrawTooltipBox,
+
+    #[live]
+    draw_text: DrawText,
+
+    #[rust]
+    options: CrosshairOptions,
+
+    /// Pointer position while it is over the chart area
+    #[rust]
+    cursor: Option<DVec2>,
+}
+
+impl ChartCrosshair {
+    pub fn set_options(&mut self, options: CrosshairOptions) {
+        self.options = options;
+    }
+
+    pub fn options(&self) -> &CrosshairOptions {
+        &self.options
+    }
+
+    /// Follow the pointer, or hide with `None`. Returns true when a redraw is needed.
+    pub fn set_cursor(&mut self, cursor: Option<DVec2>) -> bool {
+        if !self.options.enabled || cursor == self.cursor {
+            return false;
+        }
+        self.cursor = cursor;
+        true
+    }
+
+    /// Where the lines cross: the pointer, or when snapping the element nearest to it
+    /// along X, choosing between datasets at that index by distance along Y
+    pub fn position(&self, hit_tester: &HitTester) -> Option<DVec2> {
+        let cursor = self.cursor?;
+        if !self.options.snap {
+            return Some(cursor);
+        }
+        let regions = hit_tester.query(cursor, InteractionMode::Index, false, InteractionAxis::X);
+        let nearest = regions
+            .iter()
+            .min_by(|a, b| a.distance(cursor, InteractionAxis::Y).total_cmp(&b.distance(cursor, InteractionAxis::Y)))?;
+        let center = nearest.center();
+        Some(match self.options.mode {
+            // Only the vertical line snaps; a lone horizontal line follows the element's value
+            InteractionAxis::X | InteractionAxis::XY => center,
+            InteractionAxis::Y => dvec2(cursor.x, center.y),
+        })
+    }
+
+    /// Draw the guide lines with `draw_grid` and the value labels on the axes
+    pub fn draw(&mut self, cx: &mut Cx2d, draw_grid: &mut DrawGridLine, coord: &CartesianCoord, hit_tester: &HitTester) {
+        if !self.options.enabled {
+            return;
+        }
+        let Some(pos) = self.position(hit_tester) else {
+            return;
+        };
+        let area = coord.chart_area().clone();
+        if !area.contains(pos.x, pos.y) {
+            return;
+        }
+
+        let vertical = matches!(self.options.mode, InteractionAxis::X | InteractionAxis::XY);
+        let horizontal = matches!(self.options.mode, InteractionAxis::Y | InteractionAxis::XY);
+
+        draw_grid.color = self.options.color;
+        draw_grid.set_dash(&[], 0.0);
+        if vertical {
+            draw_grid.draw_line(cx, dvec2(pos.x, area.top), dvec2(pos.x, area.bottom), self.options.line_width);
+        }
+        if horizontal {
+            draw_grid.draw_line(cx, dvec2(area.left, pos.y), dvec2(area.right, pos.y), self.options.line_width);
+        }
+
+        if !self.options.show_labels {
+            return;
+        }
+        let value = coord.pixel_to_data(pos.x, pos.y);
+        if vertical {
+            let text = coord.x_scale().format_value(value.x);
+            // Centered under the line, just below the chart area
+            self.draw_label(cx, dvec2(pos.x, area.bottom + LABEL_PADDING), dvec2(0.5, 0.0), &text);
+        }
+        if horizontal {
+            let text = coord.y_scale().format_value(value.y);
+            // Left of the chart area, centered on the line
+            self.draw_label(cx, dvec2(area.left - LABEL_PADDING, pos.y), dvec2(1.0, 0.5), &text);
+        }
+    }
+
+    /// Draw `text` on a background with `align` placing it around `anchor` like `draw_text_aligned`
+    fn draw_label(&mut self, cx: &mut Cx2d, anchor: DVec2, align: DVec2, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        set_text_style(&mut self.draw_text, self.options.font_size, self.options.label_color);
+        let size = measure_text(cx, &self.draw_text, text) + dvec2(LABEL_PADDING * 2.0, LABEL_PADDING * 2.0);
+        let pos = dvec2(anchor.x - size.x * align.x, anchor.y - size.y * align.y);
+
+        self.draw_label_bg.color = self.options.label_background;
+        self.draw_label_bg.border_width = 0.0;
+        self.draw_label_bg.radius = 2.0;
+        self.draw_label_bg.draw_abs(cx, Rect { pos, size });
+        draw_text_at(cx, &mut self.draw_text, pos + dvec2(LABEL_PADDING, LABEL_PADDING), text);
+    }
+}
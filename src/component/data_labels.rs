@@ -0,0 +1,360 @@
+use makepad_widgets::*;
+use crate::core::{contrast_color, AxisPosition, ChartAlign, DataLabelContext, DataLabelOptions, LabelAlign, LabelContent, LabelOverlap};
+use crate::element::*;
+use crate::scale::{format_number, format_percent};
+
+/// Color of labels drawn off their element when the options set none
+const OUTSIDE_COLOR: Vec4 = vec4(0.4, 0.4, 0.4, 1.0);
+
+/// Gap kept between a nudged label and the one it moved away from
+const NUDGE_GAP: f64 = 2.0;
+
+/// Element a data label is placed on
+#[derive(Clone, Copy, Debug)]
+pub enum LabelTarget {
+    /// Bar growing from its `base` side
+    Bar { rect: Rect, base: AxisPosition },
+    /// Point of `radius` pixels at `center`
+    Point { center: DVec2, radius: f64 },
+    /// Pie or doughnut slice between two angles in radians, clockwise from the +X axis
+    Arc { center: DVec2, inner_radius: f64, outer_radius: f64, start_angle: f64, end_angle: f64 },
+}
+
+impl LabelTarget {
+    /// Anchor point at the base, center or end of the element, and the unit
+    /// direction the element grows in from its base
+    pub fn anchor(&self, anchor: ChartAlign) -> (DVec2, DVec2) {
+        let side = match anchor {
+            ChartAlign::Start => -1.0,
+            ChartAlign::Center => 0.0,
+            ChartAlign::End => 1.0,
+        };
+        match *self {
+            LabelTarget::Bar { rect, base } => {
+                let grow = match base {
+                    AxisPosition::Bottom => dvec2(0.0, -1.0),
+                    AxisPosition::Top => dvec2(0.0, 1.0),
+                    AxisPosition::Left => dvec2(1.0, 0.0),
+                    AxisPosition::Right => dvec2(-1.0, 0.0),
+                };
+                let center = rect.pos + rect.size * 0.5;
+                let half_length = (grow.x.abs() * rect.size.x + grow.y.abs() * rect.size.y) / 2.0;
+                (center + grow * (half_length * side), grow)
+            }
+            // Points grow upwards, so labels at their end sit above them
+            LabelTarget::Point { center, radius } => {
+                let grow = dvec2(0.0, -1.0);
+                (center + grow * (radius * side), grow)
+            }
+            LabelTarget::Arc { center, inner_radius, outer_radius, start_angle, end_angle } => {
+                let mid_angle = (start_angle + end_angle) / 2.0;
+                let grow = dvec2(mid_angle.cos(), mid_angle.sin());
+                let radius = match anchor {
+                    ChartAlign::Start => inner_radius,
+                    ChartAlign::Center => (inner_radius + outer_radius) / 2.0,
+                    ChartAlign::End => outer_radius,
+                };
+                (center + grow * radius, grow)
+            }
+        }
+    }
+
+    /// Whether `pos` lies on the element
+    pub fn contains(&self, pos: DVec2) -> bool {
+        match *self {
+            LabelTarget::Bar { rect, .. } => {
+                pos.x >= rect.pos.x && pos.x <= rect.pos.x + rect.size.x
+                    && pos.y >= rect.pos.y && pos.y <= rect.pos.y + rect.size.y
+            }
+            LabelTarget::Point { center, radius } => (pos - center).length() <= radius,
+            LabelTarget::Arc { center, inner_radius, outer_radius, start_angle, end_angle } => {
+                let offset = pos - center;
+                let distance = offset.length();
+                let angle = (offset.y.atan2(offset.x) - start_angle).rem_euclid(std::f64::consts::TAU);
+                distance >= inner_radius && distance <= outer_radius && angle <= end_angle - start_angle
+            }
+        }
+    }
+}
+
+/// Text of a data label: the formatter's, or the value, percentage or category
+/// label asked for by `content`
+pub fn label_text(options: &DataLabelOptions, context: &DataLabelContext) -> String {
+    if let Some(formatter) = options.formatter {
+        return formatter(context);
+    }
+    match (options.content, context.percentage) {
+        (LabelContent::Percentage, Some(percentage)) => format_percent(percentage),
+        (LabelContent::Label, _) => context.label.clone(),
+        _ => format_number(context.value),
+    }
+}
+
+/// Rect of a label of `size` placed on `target`: centered on the anchor, or moved
+/// `offset` pixels away from it in the aligned direction
+pub fn place_label(target: &LabelTarget, options: &DataLabelOptions, size: DVec2) -> Rect {
+    let (anchor, grow) = target.anchor(options.anchor);
+    let direction = match options.align {
+        LabelAlign::Center => return Rect { pos: anchor - size * 0.5, size },
+        LabelAlign::Start => grow * -1.0,
+        LabelAlign::End => grow,
+        LabelAlign::Left => dvec2(-1.0, 0.0),
+        LabelAlign::Top => dvec2(0.0, -1.0),
+        LabelAlign::Right => dvec2(1.0, 0.0),
+        LabelAlign::Bottom => dvec2(0.0, 1.0),
+    };
+    // Far enough along the direction for the label's near edge to be `offset` away
+    let reach = options.offset + (direction.x.abs() * size.x + direction.y.abs() * size.y) / 2.0;
+    let center = anchor + direction * reach;
+    Rect { pos: center - size * 0.5, size }
+}
+
+/// Size of the box around text of `size` rotated by `rotation` degrees
+pub fn rotated_size(size: DVec2, rotation: f64) -> DVec2 {
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    dvec2(
+        size.x * cos.abs() + size.y * sin.abs(),
+        size.x * sin.abs() + size.y * cos.abs(),
+    )
+}
+
+/// A data label laid out for drawing
+#[derive(Clone, Debug)]
+pub struct DataLabel {
+    pub text: String,
+    /// Box around the label, rotation included
+    pub rect: Rect,
+    /// Size of the unrotated text
+    pub text_size: DVec2,
+    /// Rotation in degrees, counter-clockwise
+    pub rotation: f64,
+    pub font_size: f64,
+    pub color: Vec4,
+    pub overlap: LabelOverlap,
+    /// Cleared for labels left out by `resolve_overlaps`
+    pub visible: bool,
+}
+
+/// Leave out or move labels overlapping the ones before them, in order.
+/// Nudged labels move next to a label they overlap, as little as possible,
+/// and must stay within `bounds`.
+pub fn resolve_overlaps(labels: &mut [DataLabel], bounds: Rect) {
+    let mut placed: Vec<Rect> = Vec::new();
+    for label in labels.iter_mut() {
+        let clear = |rect: &Rect| !placed.iter().any(|other| rects_overlap(rect, other));
+        if label.overlap == LabelOverlap::Show || clear(&label.rect) {
+            placed.push(label.rect);
+            continue;
+        }
+
+        let nudged = match label.overlap {
+            LabelOverlap::Nudge => {
+                let rect = label.rect;
+                placed
+                    .iter()
+                    .filter(|other| rects_overlap(&rect, other))
+                    .flat_map(|other| [
+                        dvec2(rect.pos.x, other.pos.y - rect.size.y - NUDGE_GAP),
+                        dvec2(rect.pos.x, other.pos.y + other.size.y + NUDGE_GAP),
+                        dvec2(other.pos.x - rect.size.x - NUDGE_GAP, rect.pos.y),
+                        dvec2(other.pos.x + other.size.x + NUDGE_GAP, rect.pos.y),
+                    ])
+                    .map(|pos| Rect { pos, size: rect.size })
+                    .filter(|candidate| rect_within(candidate, &bounds) && clear(candidate))
+                    .min_by(|a, b| (a.pos - rect.pos).length().total_cmp(&(b.pos - rect.pos).length()))
+            }
+            _ => None,
+        };
+        match nudged {
+            Some(rect) => {
+                label.rect = rect;
+                placed.push(rect);
+            }
+            None => label.visible = false,
+        }
+    }
+}
+
+fn rects_overlap(a: &Rect, b: &Rect) -> bool {
+    a.pos.x < b.pos.x + b.size.x && b.pos.x < a.pos.x + a.size.x
+        && a.pos.y < b.pos.y + b.size.y && b.pos.y < a.pos.y + a.size.y
+}
+
+fn rect_within(rect: &Rect, bounds: &Rect) -> bool {
+    rect.pos.x >= bounds.pos.x && rect.pos.y >= bounds.pos.y
+        && rect.pos.x + rect.size.x <= bounds.pos.x + bounds.size.x
+        && rect.pos.y + rect.size.y <= bounds.pos.y + bounds.size.y
+}
+
+/// Data labels of a chart, laid out while its elements are drawn and drawn on
+/// top of them once all are in place
+#[derive(Clone, Debug, Default)]
+pub struct ChartDataLabels {
+    labels: Vec<DataLabel>,
+}
+
+impl ChartDataLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the labels of the previous frame
+    pub fn clear(&mut self) {
+        self.labels.clear();
+    }
+
+    /// Lay out the label of an element filled with `fill`
+    pub fn push(
+        &mut self,
+        cx: &mut Cx2d,
+        draw_text: &mut DrawText,
+        options: &DataLabelOptions,
+        target: LabelTarget,
+        context: &DataLabelContext,
+        fill: Vec4,
+    ) {
+        let text = label_text(options, context);
+        if text.is_empty() {
+            return;
+        }
+        set_text_style(draw_text, options.font_size, OUTSIDE_COLOR);
+        let text_size = measure_text(cx, draw_text, &text);
+        let rect = place_label(&target, options, rotated_size(text_size, options.rotation));
+
+        // Labels on their element contrast with its fill
+        let color = options.color.unwrap_or_else(|| {
+            if target.contains(rect.pos + rect.size * 0.5) {
+                contrast_color(fill)
+            } else {
+                OUTSIDE_COLOR
+            }
+        });
+
+        self.labels.push(DataLabel {
+            text,
+            rect,
+            text_size,
+            rotation: options.rotation,
+            font_size: options.font_size,
+            color,
+            overlap: options.overlap,
+            visible: true,
+        });
+    }
+
+    /// Draw the labels laid out since the last `clear`, in the order they were
+    /// pushed, leaving out or nudging overlapping ones within `bounds`
+    pub fn draw(&mut self, cx: &mut Cx2d, draw_text: &mut DrawText, bounds: Rect) {
+        resolve_overlaps(&mut self.labels, bounds);
+        for label in self.labels.iter().filter(|label| label.visible) {
+            set_text_style(draw_text, label.font_size, label.color);
+
+            // Glyphs run along the rotated baseline through the center of the box
+            let center = label.rect.pos + label.rect.size * 0.5;
+            let (sin, cos) = label.rotation.to_radians().sin_cos();
+            let start = center - dvec2(cos, -sin) * (label.text_size.x / 2.0) - dvec2(0.0, label.text_size.y / 2.0);
+            draw_text_rotated(cx, draw_text, start, label.rotation, &label.text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(pos: DVec2, overlap: LabelOverlap) -> DataLabel {
+        DataLabel {
+            text: "10".into(),
+            rect: Rect { pos, size: dvec2(20.0, 10.0) },
+            text_size: dvec2(20.0, 10.0),
+            rotation: 0.0,
+            font_size: 11.0,
+            color: OUTSIDE_COLOR,
+            overlap,
+            visible: true,
+        }
+    }
+
+    #[test]
+    fn test_place_label_on_bars() {
+        let rect = Rect { pos: dvec2(100.0, 50.0), size: dvec2(20.0, 100.0) };
+        let bar = LabelTarget::Bar { rect, base: AxisPosition::Bottom };
+        let size = dvec2(16.0, 10.0);
+
+        let centered = place_label(&bar, &DataLabelOptions::default(), size);
+        assert_eq!(centered.pos, dvec2(102.0, 95.0));
+
+        // Above the top of the bar
+        let options = DataLabelOptions { anchor: ChartAlign::End, align: LabelAlign::End, ..Default::default() };
+        assert_eq!(place_label(&bar, &options, size).pos, dvec2(102.0, 36.0));
+
+        // Inside the end of a bar growing down from its top
+        let down = LabelTarget::Bar { rect, base: AxisPosition::Top };
+        let options = DataLabelOptions { anchor: ChartAlign::End, align: LabelAlign::Start, ..Default::default() };
+        let rect = place_label(&down, &options, size);
+        assert_eq!(rect.pos, dvec2(102.0, 136.0));
+        assert!(down.contains(rect.pos + rect.size * 0.5));
+    }
+
+    #[test]
+    fn test_place_label_on_slices() {
+        // Quarter slice to the bottom right of the center
+        let slice = LabelTarget::Arc {
+            center: dvec2(0.0, 0.0),
+            inner_radius: 0.0,
+            outer_radius: 100.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::FRAC_PI_2,
+        };
+        let rect = place_label(&slice, &DataLabelOptions::default(), dvec2(10.0, 10.0));
+        let center = rect.pos + rect.size * 0.5;
+        assert!((center.x - center.y).abs() < 1e-9 && (center.length() - 50.0).abs() < 1e-9);
+        assert!(slice.contains(center));
+        assert!(!slice.contains(dvec2(-10.0, 10.0)));
+        assert!(!slice.contains(dvec2(80.0, 80.0)));
+    }
+
+    #[test]
+    fn test_label_text() {
+        let context = DataLabelContext { value: 1234.0, percentage: Some(0.25), label: "Q1".into(), ..Default::default() };
+        assert_eq!(label_text(&DataLabelOptions::default(), &context), format_number(1234.0));
+
+        let percent = DataLabelOptions { content: LabelContent::Percentage, ..Default::default() };
+        assert_eq!(label_text(&percent, &context), "25.0%");
+        let no_share = DataLabelContext { percentage: None, ..context.clone() };
+        assert_eq!(label_text(&percent, &no_share), format_number(1234.0));
+
+        let formatted = DataLabelOptions { formatter: Some(|c| format!("{} #{}", c.label, c.data_index)), ..Default::default() };
+        assert_eq!(label_text(&formatted, &context), "Q1 #0");
+    }
+
+    #[test]
+    fn test_resolve_overlaps() {
+        let bounds = Rect { pos: dvec2(0.0, 0.0), size: dvec2(200.0, 200.0) };
+        let mut labels = vec![
+            label(dvec2(50.0, 50.0), LabelOverlap::Hide),
+            label(dvec2(60.0, 55.0), LabelOverlap::Hide),
+            label(dvec2(55.0, 52.0), LabelOverlap::Nudge),
+            label(dvec2(100.0, 100.0), LabelOverlap::Hide),
+            label(dvec2(105.0, 100.0), LabelOverlap::Show),
+        ];
+        resolve_overlaps(&mut labels, bounds);
+
+        assert!(labels[0].visible && !labels[1].visible && labels[3].visible && labels[4].visible);
+        // Moved just below the first label
+        assert!(labels[2].visible);
+        assert_eq!(labels[2].rect.pos, dvec2(55.0, 62.0));
+
+        // No room to move within the bounds
+        let mut labels = vec![label(dvec2(0.0, 0.0), LabelOverlap::Hide), label(dvec2(5.0, 0.0), LabelOverlap::Nudge)];
+        let tight = Rect { pos: dvec2(0.0, 0.0), size: dvec2(30.0, 10.0) };
+        resolve_overlaps(&mut labels, tight);
+        assert!(!labels[1].visible);
+    }
+
+    #[test]
+    fn test_rotated_size() {
+        let size = rotated_size(dvec2(20.0, 10.0), 90.0);
+        assert!((size.x - 10.0).abs() < 1e-9 && (size.y - 20.0).abs() < 1e-9);
+    }
+}
@@ -1,11 +1,148 @@
-/// Chart grid component for rendering grid lines
+use makepad_widgets::*;
+use crate::core::{AxisOptions, ScalesOptions};
+use crate::coord::{AxisPosition, CartesianCoord};
+use crate::element::grid::DrawGridLine;
+
+/// Chart grid component for drawing grid lines, tick marks and axis lines
+/// of a cartesian chart from its `ScalesOptions`
 #[derive(Clone, Debug, Default)]
-pub struct ChartGrid {
-    // Placeholder - to be implemented
-}
+pub struct ChartGrid {}
 
 impl ChartGrid {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// Whether grid lines or tick marks of `axis` are drawn at all
+    fn grid_visible(axis: &AxisOptions) -> bool {
+        axis.display && axis.grid.display
+    }
+
+    /// Length of the tick marks drawn outside the chart area for `axis`
+    pub fn tick_mark_length(axis: &AxisOptions) -> f64 {
+        if Self::grid_visible(axis) && axis.grid.draw_ticks {
+            axis.grid.tick_length.max(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Draw vertical grid lines across the chart area at each X tick
+    pub fn draw_x_lines(&self, cx: &mut Cx2d, draw_grid: &mut DrawGridLine, coord: &CartesianCoord, axis: &AxisOptions) {
+        if !Self::grid_visible(axis) || !axis.grid.draw_on_chart_area {
+            return;
+        }
+        draw_grid.color = axis.grid.color;
+        draw_grid.set_dash(&axis.grid.dash, axis.grid.dash_offset);
+        let area = coord.chart_area();
+        for tick in coord.build_x_ticks(&axis.ticks) {
+            let (p1, p2) = coord.get_vertical_grid_line(tick.value);
+            if p1.x < area.left - 1.0 || p1.x > area.right + 1.0 {
+                continue;
+            }
+            draw_grid.draw_line(cx, p1, p2, axis.grid.line_width);
+        }
+    }
+
+    /// Draw horizontal grid lines across the chart area at the ticks of each Y axis
+    pub fn draw_y_lines(&self, cx: &mut Cx2d, draw_grid: &mut DrawGridLine, coord: &CartesianCoord, scales: &ScalesOptions) {
+        let area = coord.chart_area();
+        for axis in coord.y_axes(scales) {
+            let options = &axis.options;
+            if !Self::grid_visible(options) || !options.grid.draw_on_chart_area {
+                continue;
+            }
+            draw_grid.color = options.grid.color;
+            draw_grid.set_dash(&options.grid.dash, options.grid.dash_offset);
+            let y_scale = coord.y_scale_for(axis.id.as_deref());
+            for tick in &axis.ticks {
+                let y = y_scale.get_pixel_for_value(tick.value);
+                if y < area.top - 1.0 || y > area.bottom + 1.0 {
+                    continue;
+                }
+                draw_grid.draw_line(cx, dvec2(area.left, y), dvec2(area.right, y), options.grid.line_width);
+            }
+        }
+    }
+
+    /// Draw tick marks below the chart area for X ticks and outside each Y axis line for Y ticks
+    pub fn draw_tick_marks(&self, cx: &mut Cx2d, draw_grid: &mut DrawGridLine, coord: &CartesianCoord, scales: &ScalesOptions) {
+        let area = coord.chart_area().clone();
+        draw_grid.set_dash(&[], 0.0);
+
+        let length = Self::tick_mark_length(&scales.x);
+        if length > 0.0 {
+            draw_grid.color = scales.x.grid.color;
+            for tick in coord.build_x_ticks(&scales.x.ticks) {
+                let x = coord.x_scale().get_pixel_for_value(tick.value);
+                if x < area.left - 1.0 || x > area.right + 1.0 {
+                    continue;
+                }
+                draw_grid.draw_line(cx, dvec2(x, area.bottom), dvec2(x, area.bottom + length), scales.x.grid.line_width);
+            }
+        }
+
+        for axis in coord.y_axes(scales) {
+            let length = Self::tick_mark_length(&axis.options);
+            if length <= 0.0 {
+                continue;
+            }
+            let x = axis.line_start.x;
+            let outer = if axis.position == AxisPosition::Right { x + length } else { x - length };
+            draw_grid.color = axis.options.grid.color;
+            let y_scale = coord.y_scale_for(axis.id.as_deref());
+            for tick in &axis.ticks {
+                let y = y_scale.get_pixel_for_value(tick.value);
+                if y < area.top - 1.0 || y > area.bottom + 1.0 {
+                    continue;
+                }
+                draw_grid.draw_line(cx, dvec2(outer, y), dvec2(x, y), axis.options.grid.line_width);
+            }
+        }
+    }
+
+    /// Draw the X and Y axis lines.
+    /// With `x_at_zero` the X axis line follows y = 0 when it is inside the Y range,
+    /// and with `y_at_zero` the default Y axis line follows x = 0 when it is inside the
+    /// X range; otherwise they sit on the bottom edge of the chart area and the Y axes
+    /// on their sides of it.
+    pub fn draw_borders(
+        &self,
+        cx: &mut Cx2d,
+        draw_grid: &mut DrawGridLine,
+        coord: &CartesianCoord,
+        scales: &ScalesOptions,
+        x_at_zero: bool,
+        y_at_zero: bool,
+    ) {
+        let area = coord.chart_area().clone();
+
+        if scales.x.display && scales.x.grid.draw_border {
+            let (y_min, y_max) = coord.y_scale().get_data_bounds();
+            let y = if x_at_zero && y_min <= 0.0 && y_max >= 0.0 {
+                coord.y_scale().get_pixel_for_value(0.0)
+            } else {
+                area.bottom
+            };
+            draw_grid.color = scales.x.grid.border_color;
+            draw_grid.set_dash(&scales.x.grid.border_dash, scales.x.grid.border_dash_offset);
+            draw_grid.draw_line(cx, dvec2(area.left, y), dvec2(area.right, y), scales.x.grid.line_width);
+        }
+
+        for axis in coord.y_axes(scales) {
+            let options = &axis.options;
+            if !options.display || !options.grid.draw_border {
+                continue;
+            }
+            let (x_min, x_max) = coord.x_scale().get_data_bounds();
+            let x = if y_at_zero && axis.id.is_none() && x_min <= 0.0 && x_max >= 0.0 {
+                coord.x_scale().get_pixel_for_value(0.0)
+            } else {
+                axis.line_start.x
+            };
+            draw_grid.color = options.grid.border_color;
+            draw_grid.set_dash(&options.grid.border_dash, options.grid.border_dash_offset);
+            draw_grid.draw_line(cx, dvec2(x, area.top), dvec2(x, area.bottom), options.grid.line_width);
+        }
+    }
 }
@@ -1,11 +1,16 @@
 use makepad_widgets::*;
-use crate::core::get_color;
+use std::ops::Range;
+use crate::core::{ChartData, LegendOptions, LegendPosition, PointStyle, get_color};
+use crate::element::*;
+use super::title::aligned_x;
 
 live_design! {
     use link::theme::*;
     use link::shaders::*;
     use link::widgets::*;
 
+    use crate::element::point::DrawPoint;
+
     pub DrawLegendBox = {{DrawLegendBox}} {
         fn pixel(self) -> vec4 {
             let sdf = Sdf2d::viewport(self.pos * self.rect_size);
@@ -26,14 +31,22 @@ live_design! {
 
     pub ChartLegend = {{ChartLegend}} {
         width: Fill,
-        height: Fit,
-        flow: Right,
-        spacing: 16,
-        padding: {top: 8, bottom: 8},
-        align: {x: 0.5, y: 0.5},
+        height: Fill,
+        draw_text: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
     }
 }
 
+/// Gap between an item's marker and its label
+const MARKER_GAP: f64 = 6.0;
+
+/// Vertical gap between rows, and between items stacked in a column
+const ROW_GAP: f64 = 6.0;
+
+/// Alpha applied to hidden items and to series dimmed by a hovered item
+const DIMMED_ALPHA: f32 = 0.3;
+
 #[derive(Live, LiveHook, LiveRegister)]
 #[repr(C)]
 pub struct DrawLegendBox {
@@ -47,83 +60,134 @@ impl DrawLegendBox {
     }
 }
 
+/// A single legend entry
+#[derive(Clone, Debug)]
+pub struct LegendItemData {
+    pub label: String,
+    pub color: Vec4,
+    pub hidden: bool,
+    /// Dataset index, or data index for charts whose legend lists labels
+    pub index: usize,
+    /// Marker shape used when `use_point_style` is set
+    pub point_style: PointStyle,
+}
+
+impl LegendItemData {
+    /// One item per dataset, colored by `color_for(dataset_index)`
+    pub fn from_datasets<F>(data: &ChartData, color_for: F) -> Vec<Self>
+    where
+        F: Fn(usize) -> Vec4,
+    {
+        data.datasets.iter().enumerate().map(|(i, dataset)| Self {
+            label: dataset.label.clone(),
+            color: color_for(i),
+            hidden: dataset.hidden,
+            index: i,
+            point_style: dataset.point_style,
+        }).collect()
+    }
+
+    /// One item per category label, colored by `color_for(data_index)`
+    pub fn from_labels<F>(data: &ChartData, color_for: F) -> Vec<Self>
+    where
+        F: Fn(usize) -> Vec4,
+    {
+        data.labels.iter().enumerate().map(|(i, label)| Self {
+            label: label.clone(),
+            color: color_for(i),
+            hidden: !data.is_data_visible(i),
+            index: i,
+            point_style: PointStyle::Circle,
+        }).collect()
+    }
+}
+
+/// Where a legend item was laid out
+#[derive(Clone, Debug, Default)]
+struct LegendSlot {
+    /// Index into the legend items
+    item: usize,
+    /// Whole item, used for hit testing
+    rect: Rect,
+    /// Color box or point marker
+    marker: Rect,
+    /// Top-left of the label
+    text_pos: DVec2,
+}
+
+/// Chart legend that lays itself out on one side of the chart, toggles
+/// datasets on click and highlights the hovered series
 #[derive(Live, LiveHook, Widget)]
 pub struct ChartLegend {
+    #[redraw]
     #[live]
-    #[deref]
-    view: View,
+    draw_box: DrawLegendBox,
 
     #[live]
-    draw_box: DrawLegendBox,
+    draw_point: DrawPoint,
+
+    #[live]
+    draw_text: DrawText,
+
+    #[walk]
+    walk: Walk,
+
+    #[rust]
+    options: LegendOptions,
 
     #[rust]
     items: Vec<LegendItemData>,
-}
 
-#[derive(Clone, Debug)]
-pub struct LegendItemData {
-    pub label: String,
-    pub color: Vec4,
-    pub hidden: bool,
+    #[rust]
+    slots: Vec<LegendSlot>,
+
+    /// Index (dataset or data index) of the hovered item
+    #[rust]
+    hovered: Option<usize>,
 }
 
 impl Widget for ChartLegend {
-    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
-        self.view.handle_event(cx, event, scope);
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, _scope: &mut Scope) {
+        if let Event::MouseMove(e) = event {
+            if self.set_hovered_at(e.abs) {
+                self.redraw(cx);
+            }
+        }
     }
 
     fn draw_walk(&mut self, cx: &mut Cx2d, _scope: &mut Scope, walk: Walk) -> DrawStep {
-        cx.begin_turtle(walk, Layout {
-            flow: Flow::right(),
-            spacing: 16.0,
-            padding: Padding { left: 8.0, top: 4.0, right: 8.0, bottom: 4.0 },
-            align: Align { x: 0.5, y: 0.5 },
-            ..Default::default()
-        });
-
-        let box_size = 12.0;
-        let spacing = 6.0;
-
-        // Clone items to avoid borrow conflict
-        let items_clone: Vec<_> = self.items.clone();
-
-        for item in &items_clone {
-            // Get current position
-            let pos = cx.turtle().pos();
-
-            // Draw colored box
-            self.draw_box.color = item.color;
-            let box_rect = Rect {
-                pos: dvec2(pos.x, pos.y + 2.0),
-                size: dvec2(box_size, box_size),
-            };
-            self.draw_box.draw_box(cx, box_rect);
-
-            // Move turtle past the box and label
-            // Estimate label width (rough approximation)
-            let label_width = item.label.len() as f64 * 7.0;
-            cx.turtle_mut().move_to(dvec2(
-                pos.x + box_size + spacing + label_width + 16.0,
-                pos.y,
-            ));
-        }
-
-        cx.end_turtle();
+        let rect = cx.walk_turtle(walk);
+        self.fit(cx, rect);
+        self.draw_legend(cx);
         DrawStep::done()
     }
 }
 
 impl ChartLegend {
+    pub fn set_options(&mut self, options: LegendOptions) {
+        self.options = options;
+    }
+
+    pub fn options(&self) -> &LegendOptions {
+        &self.options
+    }
+
     pub fn set_items(&mut self, items: Vec<LegendItemData>) {
         self.items = items;
     }
 
+    pub fn items(&self) -> &[LegendItemData] {
+        &self.items
+    }
+
     pub fn set_items_from_labels(&mut self, labels: &[String]) {
         self.items = labels.iter().enumerate().map(|(i, label)| {
             LegendItemData {
                 label: label.clone(),
                 color: get_color(i),
                 hidden: false,
+                index: i,
+                point_style: PointStyle::Circle,
             }
         }).collect();
     }
@@ -134,12 +198,212 @@ impl ChartLegend {
                 label: label.clone(),
                 color: colors.get(i).and_then(|c| *c).unwrap_or_else(|| get_color(i)),
                 hidden: false,
+                index: i,
+                point_style: PointStyle::Circle,
             }
         }).collect();
     }
+
+    /// Index of the hovered item, if any
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Index of the item under `pos`, if any
+    pub fn item_at(&self, pos: DVec2) -> Option<usize> {
+        self.slots
+            .iter()
+            .find(|slot| slot.rect.contains(pos))
+            .map(|slot| self.items[slot.item].index)
+    }
+
+    /// Update the hovered item from the cursor. Returns true when it changed.
+    pub fn set_hovered_at(&mut self, pos: DVec2) -> bool {
+        let hovered = self.item_at(pos);
+        let changed = hovered != self.hovered;
+        self.hovered = hovered;
+        changed
+    }
+
+    /// Dim `color` when another item is hovered, so the hovered series stands out
+    pub fn highlight(&self, index: usize, color: Vec4) -> Vec4 {
+        match self.hovered {
+            Some(hovered) if hovered != index => vec4(color.x, color.y, color.z, color.w * DIMMED_ALPHA),
+            _ => color,
+        }
+    }
+
+    /// Lay the items out along the side of `rect` picked by the legend position
+    /// and return what is left for the plotting area
+    pub fn fit(&mut self, cx: &mut Cx2d, rect: Rect) -> Rect {
+        self.slots.clear();
+        if !self.options.display || self.items.is_empty() {
+            return rect;
+        }
+
+        let labels = self.options.labels.clone();
+        let padding = labels.padding;
+        let marker = if labels.use_point_style {
+            dvec2(labels.box_height, labels.box_height)
+        } else {
+            dvec2(labels.box_width, labels.box_height)
+        };
+
+        set_text_style(&mut self.draw_text, labels.font_size, labels.color);
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        if self.options.reverse {
+            order.reverse();
+        }
+        let text_sizes: Vec<DVec2> = order
+            .iter()
+            .map(|&i| measure_text(cx, &self.draw_text, &self.items[i].label))
+            .collect();
+        let line_height = text_sizes
+            .iter()
+            .fold(marker.y.max(labels.font_size), |h, size| h.max(size.y));
+        let widths: Vec<f64> = text_sizes.iter().map(|size| marker.x + MARKER_GAP + size.x).collect();
+
+        let left = rect.pos.x;
+        let top = rect.pos.y;
+        let right = rect.pos.x + rect.size.x;
+        let bottom = rect.pos.y + rect.size.y;
+
+        let place = |item: usize, pos: DVec2, text_size: DVec2| {
+            let width = marker.x + MARKER_GAP + text_size.x;
+            LegendSlot {
+                item,
+                rect: Rect { pos, size: dvec2(width, line_height) },
+                marker: Rect {
+                    pos: dvec2(pos.x, pos.y + (line_height - marker.y) / 2.0),
+                    size: marker,
+                },
+                text_pos: dvec2(pos.x + marker.x + MARKER_GAP, pos.y + (line_height - text_size.y) / 2.0),
+            }
+        };
+
+        match self.options.position {
+            LegendPosition::Top | LegendPosition::Bottom => {
+                let rows = wrap_items(&widths, rect.size.x - padding * 2.0, padding);
+                let height = rows.len() as f64 * (line_height + ROW_GAP) - ROW_GAP + padding * 2.0;
+                let height = height.min(rect.size.y);
+                let mut y = if self.options.position == LegendPosition::Top {
+                    top + padding
+                } else {
+                    bottom - height + padding
+                };
+
+                for row in rows {
+                    let row_width = widths[row.clone()].iter().sum::<f64>() + padding * (row.len() - 1) as f64;
+                    let mut x = aligned_x(self.options.align, left + padding, right - padding, row_width);
+                    for i in row {
+                        self.slots.push(place(order[i], dvec2(x, y), text_sizes[i]));
+                        x += widths[i] + padding;
+                    }
+                    y += line_height + ROW_GAP;
+                }
+
+                if self.options.position == LegendPosition::Top {
+                    Rect { pos: dvec2(left, top + height), size: dvec2(rect.size.x, rect.size.y - height) }
+                } else {
+                    Rect { pos: rect.pos, size: dvec2(rect.size.x, rect.size.y - height) }
+                }
+            }
+            LegendPosition::Left | LegendPosition::Right => {
+                let heights = vec![line_height; order.len()];
+                let columns = wrap_items(&heights, rect.size.y - padding * 2.0, ROW_GAP);
+                let column_widths: Vec<f64> = columns
+                    .iter()
+                    .map(|column| widths[column.clone()].iter().fold(0.0f64, |w, &x| w.max(x)))
+                    .collect();
+                let width = column_widths.iter().sum::<f64>() + padding * (columns.len() + 1) as f64;
+                let width = width.min(rect.size.x);
+                let mut x = if self.options.position == LegendPosition::Left {
+                    left + padding
+                } else {
+                    right - width + padding
+                };
+
+                for (column, column_width) in columns.into_iter().zip(column_widths) {
+                    let column_height = column.len() as f64 * (line_height + ROW_GAP) - ROW_GAP;
+                    let mut y = aligned_x(self.options.align, top + padding, bottom - padding, column_height);
+                    for i in column {
+                        self.slots.push(place(order[i], dvec2(x, y), text_sizes[i]));
+                        y += line_height + ROW_GAP;
+                    }
+                    x += column_width + padding;
+                }
+
+                if self.options.position == LegendPosition::Left {
+                    Rect { pos: dvec2(left + width, top), size: dvec2(rect.size.x - width, rect.size.y) }
+                } else {
+                    Rect { pos: rect.pos, size: dvec2(rect.size.x - width, rect.size.y) }
+                }
+            }
+        }
+    }
+
+    /// Draw the items laid out by the last `fit`. Hidden items are faded.
+    pub fn draw_legend(&mut self, cx: &mut Cx2d) {
+        if self.slots.is_empty() {
+            return;
+        }
+
+        let labels = self.options.labels.clone();
+        let slots = std::mem::take(&mut self.slots);
+        for slot in &slots {
+            let item = &self.items[slot.item];
+            let alpha = if item.hidden { DIMMED_ALPHA } else { 1.0 };
+            let color = vec4(item.color.x, item.color.y, item.color.z, item.color.w * alpha);
+
+            if labels.use_point_style {
+                self.draw_point.color = color;
+                self.draw_point.set_style(match item.point_style {
+                    // Images are tied to the dataset texture, use a plain marker instead
+                    PointStyle::Image => PointStyle::Circle,
+                    style => style,
+                });
+                self.draw_point.draw_point(cx, slot.marker);
+            } else {
+                self.draw_box.color = color;
+                self.draw_box.draw_box(cx, slot.marker);
+            }
+
+            let text_color = vec4(labels.color.x, labels.color.y, labels.color.z, labels.color.w * alpha);
+            set_text_style(&mut self.draw_text, labels.font_size, text_color);
+            draw_text_at(cx, &mut self.draw_text, slot.text_pos, &item.label);
+        }
+        self.slots = slots;
+    }
+}
+
+/// Split items of the given sizes into consecutive runs that fit within `max`,
+/// with `gap` between neighbours. Every run holds at least one item.
+pub fn wrap_items(sizes: &[f64], max: f64, gap: f64) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut used = 0.0;
+
+    for (i, &size) in sizes.iter().enumerate() {
+        if i > start && used + gap + size > max {
+            runs.push(start..i);
+            start = i;
+            used = 0.0;
+        }
+        used += if i > start { gap + size } else { size };
+    }
+    if start < sizes.len() {
+        runs.push(start..sizes.len());
+    }
+    runs
 }
 
 impl ChartLegendRef {
+    pub fn set_options(&self, options: LegendOptions) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_options(options);
+        }
+    }
+
     pub fn set_items(&self, items: Vec<LegendItemData>) {
         if let Some(mut inner) = self.borrow_mut() {
             inner.set_items(items);
@@ -151,4 +415,31 @@ impl ChartLegendRef {
             inner.set_items_from_labels(labels);
         }
     }
+
+    /// Index of the item under `pos`, using the last layout
+    pub fn item_at(&self, pos: DVec2) -> Option<usize> {
+        self.borrow().and_then(|inner| inner.item_at(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_items_into_rows() {
+        let rows = wrap_items(&[40.0, 40.0, 40.0, 40.0], 100.0, 10.0);
+        assert_eq!(rows, vec![0..2, 2..4]);
+    }
+
+    #[test]
+    fn test_wrap_items_keeps_oversized_item() {
+        let rows = wrap_items(&[150.0, 20.0], 100.0, 10.0);
+        assert_eq!(rows, vec![0..1, 1..2]);
+    }
+
+    #[test]
+    fn test_wrap_items_empty() {
+        assert!(wrap_items(&[], 100.0, 10.0).is_empty());
+    }
 }
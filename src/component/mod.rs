@@ -1,19 +1,34 @@
 // Component module - shared chart components
 
+mod annotations;
 mod axis;
+mod brush;
+mod crosshair;
+mod data_labels;
 mod grid;
 pub mod legend;
 mod tooltip;
 mod title;
+mod zoom;
 
+pub use annotations::*;
 pub use axis::*;
+pub use brush::*;
+pub use crosshair::*;
+pub use data_labels::*;
 pub use grid::*;
 pub use legend::*;
 pub use tooltip::*;
 pub use title::*;
+pub use zoom::*;
 
 use makepad_widgets::*;
 
 pub fn live_design(cx: &mut Cx) {
     legend::live_design(cx);
+    tooltip::live_design(cx);
+    zoom::live_design(cx);
+    crosshair::live_design(cx);
+    brush::live_design(cx);
+    annotations::live_design(cx);
 }
@@ -1,11 +1,104 @@
-/// Chart title component
+use makepad_widgets::*;
+use crate::core::{ChartAlign, ChartOptions, TitleOptions};
+use crate::element::*;
+
+/// Chart title component for laying out and drawing the title and subtitle
+/// above the plotting area
 #[derive(Clone, Debug, Default)]
 pub struct ChartTitle {
-    // Placeholder - to be implemented
+    /// Rect the title and subtitle were fitted into
+    rect: Rect,
+    /// Height taken by the title block, padding included
+    title_height: f64,
+    /// Height taken by the subtitle block, padding included
+    subtitle_height: f64,
 }
 
 impl ChartTitle {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Total height reserved above the plotting area
+    pub fn height(&self) -> f64 {
+        self.title_height + self.subtitle_height
+    }
+
+    /// Measure the title and subtitle and return what is left of `rect`
+    /// for the plotting area
+    pub fn fit(&mut self, cx: &mut Cx2d, draw_text: &mut DrawText, options: &ChartOptions, rect: Rect) -> Rect {
+        self.rect = rect;
+        self.title_height = Self::measure_block(cx, draw_text, &options.title);
+        self.subtitle_height = Self::measure_block(cx, draw_text, &options.subtitle);
+
+        let height = self.height().min(rect.size.y);
+        Rect {
+            pos: dvec2(rect.pos.x, rect.pos.y + height),
+            size: dvec2(rect.size.x, rect.size.y - height),
+        }
+    }
+
+    /// Draw the title followed by the subtitle at the top of the fitted rect
+    pub fn draw(&self, cx: &mut Cx2d, draw_text: &mut DrawText, options: &ChartOptions) {
+        let top = self.rect.pos.y;
+        self.draw_block(cx, draw_text, &options.title, top);
+        self.draw_block(cx, draw_text, &options.subtitle, top + self.title_height);
+    }
+
+    /// Height of one title block, or 0.0 if it is hidden
+    fn measure_block(cx: &mut Cx2d, draw_text: &mut DrawText, title: &TitleOptions) -> f64 {
+        if !title.display || title.text.is_empty() {
+            return 0.0;
+        }
+        set_text_style(draw_text, title.font_size, title.color);
+        let lines: f64 = title
+            .text
+            .lines()
+            .map(|line| line_height(measure_text(cx, draw_text, line).y, title.font_size))
+            .sum();
+        lines + title.padding * 2.0
+    }
+
+    fn draw_block(&self, cx: &mut Cx2d, draw_text: &mut DrawText, title: &TitleOptions, top: f64) {
+        if !title.display || title.text.is_empty() {
+            return;
+        }
+        set_text_style(draw_text, title.font_size, title.color);
+
+        let left = self.rect.pos.x + title.padding;
+        let right = self.rect.pos.x + self.rect.size.x - title.padding;
+        let mut y = top + title.padding;
+        for line in title.text.lines() {
+            let size = measure_text(cx, draw_text, line);
+            let x = aligned_x(title.align, left, right, size.x);
+            draw_text_at(cx, draw_text, dvec2(x, y), line);
+            y += line_height(size.y, title.font_size);
+        }
+    }
+}
+
+/// Height of a text line, falling back to the font size for empty lines
+fn line_height(measured: f64, font_size: f64) -> f64 {
+    measured.max(font_size)
+}
+
+/// Left edge of a line of `width` aligned between `left` and `right`
+pub fn aligned_x(align: ChartAlign, left: f64, right: f64, width: f64) -> f64 {
+    match align {
+        ChartAlign::Start => left,
+        ChartAlign::Center => (left + right - width) / 2.0,
+        ChartAlign::End => right - width,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aligned_x() {
+        assert_eq!(aligned_x(ChartAlign::Start, 10.0, 110.0, 20.0), 10.0);
+        assert_eq!(aligned_x(ChartAlign::Center, 10.0, 110.0, 20.0), 50.0);
+        assert_eq!(aligned_x(ChartAlign::End, 10.0, 110.0, 20.0), 90.0);
     }
 }
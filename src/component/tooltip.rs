@@ -1,11 +1,436 @@
-/// Chart tooltip component
-#[derive(Clone, Debug, Default)]
+use makepad_widgets::*;
+use crate::core::{ChartData, DataPoint, StackMode, TooltipOptions};
+use crate::element::*;
+use crate::interaction::HitRegion;
+use crate::scale::format_number;
+use super::legend::DrawLegendBox;
+
+live_design! {
+    use link::theme::*;
+    use link::shaders::*;
+    use link::widgets::*;
+
+    pub DrawTooltipBox = {{DrawTooltipBox}} {
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            let inset = self.border_width * 0.5;
+
+            sdf.box(
+                inset,
+                inset,
+                self.rect_size.x - self.border_width,
+                self.rect_size.y - self.border_width,
+                self.radius
+            );
+
+            sdf.fill_keep(self.color);
+            if self.border_width > 0.0 {
+                sdf.stroke(self.border_color, self.border_width);
+            }
+
+            return sdf.result;
+        }
+    }
+
+    pub ChartTooltip = {{ChartTooltip}} {
+        width: Fill,
+        height: Fill,
+        draw_text: {
+            text_style: <THEME_FONT_REGULAR> {}
+        }
+    }
+}
+
+/// Distance between the cursor and the tooltip box
+const CURSOR_OFFSET: f64 = 12.0;
+
+/// Vertical gap between tooltip lines
+const LINE_SPACING: f64 = 4.0;
+
+/// Gap between a color swatch and its text
+const SWATCH_GAP: f64 = 6.0;
+
+#[derive(Live, LiveHook, LiveRegister)]
+#[repr(C)]
+pub struct DrawTooltipBox {
+    #[deref] pub draw_super: DrawQuad,
+    #[live] pub color: Vec4,
+    #[live] pub border_color: Vec4,
+    #[live] pub border_width: f32,
+    #[live] pub radius: f32,
+}
+
+/// A single line in the tooltip body
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TooltipItem {
+    pub text: String,
+    pub color: Vec4,
+}
+
+/// Title and body lines shown by a tooltip
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TooltipContent {
+    pub title: String,
+    pub items: Vec<TooltipItem>,
+}
+
+impl TooltipContent {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn with_item(mut self, text: impl Into<String>, color: Vec4) -> Self {
+        self.items.push(TooltipItem { text: text.into(), color });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty() && self.items.is_empty()
+    }
+
+    /// Build tooltip content for the elements selected by a hit query
+    ///
+    /// The title is the category label of the first element; each element adds
+    /// a `dataset: value` line colored by `color_for(dataset_index, data_index)`.
+    pub fn from_hits<F>(data: &ChartData, hits: &[HitRegion], color_for: F) -> Self
+    where
+        F: Fn(usize, usize) -> Vec4,
+    {
+        let elements: Vec<(usize, usize)> = hits
+            .iter()
+            .filter_map(|hit| hit.data.indices())
+            .collect();
+        Self::from_elements(data, &elements, color_for)
+    }
+
+    /// Build tooltip content for `(dataset_index, data_index)` pairs
+    pub fn from_elements<F>(data: &ChartData, elements: &[(usize, usize)], color_for: F) -> Self
+    where
+        F: Fn(usize, usize) -> Vec4,
+    {
+        let show_x = data.labels.is_empty();
+        Self::from_elements_formatted(data, elements, color_for, |_, _, point| format_point_value(point, show_x))
+    }
+
+    /// Like `from_elements` with each value written by `format(dataset_index, data_index, point)`
+    pub fn from_elements_formatted<F, V>(data: &ChartData, elements: &[(usize, usize)], color_for: F, format: V) -> Self
+    where
+        F: Fn(usize, usize) -> Vec4,
+        V: Fn(usize, usize, &DataPoint) -> String,
+    {
+        let mut content = Self::default();
+
+        for &(dataset_index, data_index) in elements {
+            let Some(dataset) = data.datasets.get(dataset_index) else {
+                continue;
+            };
+            let Some(point) = dataset.data.get(data_index) else {
+                continue;
+            };
+
+            if content.title.is_empty() {
+                content.title = point.label.clone()
+                    .or_else(|| data.labels.get(data_index).cloned())
+                    .unwrap_or_default();
+            }
+
+            let value = format(dataset_index, data_index, point);
+            let text = if dataset.label.is_empty() {
+                value
+            } else {
+                format!("{}: {}", dataset.label, value)
+            };
+            content.items.push(TooltipItem {
+                text,
+                color: color_for(dataset_index, data_index),
+            });
+        }
+
+        content
+    }
+}
+
+/// Format a data point for a tooltip line
+///
+/// Points on a numeric x axis show their coordinates, floating bars show
+/// their range, everything else shows the y value.
+pub fn format_point_value(point: &DataPoint, show_x: bool) -> String {
+    if let Some(y_min) = point.y_min {
+        return format!("{} – {}", format_number(y_min), format_number(point.y));
+    }
+    match (point.x, show_x) {
+        (Some(x), true) => match point.r {
+            Some(r) => format!("({}, {}, {})", format_number(x), format_number(point.y), format_number(r)),
+            None => format!("({}, {})", format_number(x), format_number(point.y)),
+        },
+        _ => format_number(point.y),
+    }
+}
+
+/// Format a stacked value for a tooltip line: the value itself and the stack
+/// total up to and including it, `top`, which is in percent for `StackMode::Percent`
+pub fn format_stacked_value(point: &DataPoint, top: f64, mode: StackMode) -> String {
+    match mode {
+        StackMode::Percent => format!("{} (total {}%)", format_number(point.y), format_number(top)),
+        _ => format!("{} (total {})", format_number(point.y), format_number(top)),
+    }
+}
+
+/// Top-left corner for a tooltip of `size` next to `cursor`, flipped to the
+/// other side of the cursor when it would leave `bounds`
+pub fn place_tooltip(cursor: DVec2, size: DVec2, bounds: Rect) -> DVec2 {
+    let mut x = cursor.x + CURSOR_OFFSET;
+    if x + size.x > bounds.pos.x + bounds.size.x {
+        x = cursor.x - CURSOR_OFFSET - size.x;
+    }
+    let mut y = cursor.y + CURSOR_OFFSET;
+    if y + size.y > bounds.pos.y + bounds.size.y {
+        y = cursor.y - CURSOR_OFFSET - size.y;
+    }
+
+    // Keep it inside when the bounds are smaller than the tooltip
+    let x = x.min(bounds.pos.x + bounds.size.x - size.x).max(bounds.pos.x);
+    let y = y.min(bounds.pos.y + bounds.size.y - size.y).max(bounds.pos.y);
+    dvec2(x, y)
+}
+
+/// Chart tooltip that follows the cursor and lists the hovered elements
+#[derive(Live, LiveHook, Widget)]
 pub struct ChartTooltip {
-    // Placeholder - to be implemented
+    #[redraw]
+    #[live]
+    draw_bg: DrawTooltipBox,
+
+    #[live]
+    draw_swatch: DrawLegendBox,
+
+    #[live]
+    draw_text: DrawText,
+
+    #[walk]
+    walk: Walk,
+
+    #[rust]
+    options: TooltipOptions,
+
+    #[rust]
+    content: TooltipContent,
+
+    #[rust]
+    cursor: DVec2,
+
+    #[rust]
+    bounds: Rect,
+
+    /// Box drawn last, where the tooltip sits on screen
+    #[rust]
+    placed: Rect,
+
+    #[rust]
+    visible: bool,
+}
+
+impl Widget for ChartTooltip {
+    fn handle_event(&mut self, _cx: &mut Cx, _event: &Event, _scope: &mut Scope) {}
+
+    fn draw_walk(&mut self, cx: &mut Cx2d, _scope: &mut Scope, walk: Walk) -> DrawStep {
+        let rect = cx.walk_turtle(walk);
+        self.draw_tooltip(cx, rect);
+        DrawStep::done()
+    }
 }
 
 impl ChartTooltip {
-    pub fn new() -> Self {
-        Self {}
+    pub fn set_options(&mut self, options: TooltipOptions) {
+        self.options = options;
+    }
+
+    pub fn options(&self) -> &TooltipOptions {
+        &self.options
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn content(&self) -> &TooltipContent {
+        &self.content
+    }
+
+    /// Area the tooltip was last drawn in, usually the whole chart
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Show `content` next to the cursor. Returns true when a redraw is needed: the
+    /// tooltip was hidden, its content changed or it moves with the cursor.
+    pub fn show(&mut self, cursor: DVec2, content: TooltipContent) -> bool {
+        if !self.options.enabled || content.is_empty() {
+            return self.hide();
+        }
+        let pos = place_tooltip(cursor, self.placed.size, self.bounds);
+        let changed = !self.visible || content != self.content || pos != self.placed.pos;
+        self.cursor = cursor;
+        self.content = content;
+        self.visible = true;
+        changed
+    }
+
+    /// Hide the tooltip. Returns true if it was visible.
+    pub fn hide(&mut self) -> bool {
+        let was_visible = self.visible;
+        self.visible = false;
+        self.content = TooltipContent::default();
+        was_visible
+    }
+
+    /// Draw the tooltip on top of the chart, kept within `bounds`
+    pub fn draw_tooltip(&mut self, cx: &mut Cx2d, bounds: Rect) {
+        self.bounds = bounds;
+        if !self.visible || !self.options.enabled || self.content.is_empty() {
+            return;
+        }
+
+        let opts = self.options.clone();
+        let padding = opts.padding;
+        let swatch = opts.body_font_size;
+
+        // Measure every line to size the box
+        let mut title_size = dvec2(0.0, 0.0);
+        if !self.content.title.is_empty() {
+            set_text_style(&mut self.draw_text, opts.title_font_size, opts.title_color);
+            title_size = measure_text(cx, &self.draw_text, &self.content.title);
+        }
+
+        set_text_style(&mut self.draw_text, opts.body_font_size, opts.body_color);
+        let item_sizes: Vec<DVec2> = self.content.items
+            .iter()
+            .map(|item| measure_text(cx, &self.draw_text, &item.text))
+            .collect();
+
+        let mut width = title_size.x;
+        let mut height = title_size.y;
+        for size in &item_sizes {
+            width = width.max(swatch + SWATCH_GAP + size.x);
+            height += size.y.max(swatch);
+        }
+        let lines = item_sizes.len() + usize::from(title_size.y > 0.0);
+        height += LINE_SPACING * lines.saturating_sub(1) as f64;
+
+        let size = dvec2(width + padding * 2.0, height + padding * 2.0);
+        let pos = place_tooltip(self.cursor, size, bounds);
+        self.placed = Rect { pos, size };
+
+        self.draw_bg.color = opts.background_color;
+        self.draw_bg.border_color = opts.border_color;
+        self.draw_bg.border_width = opts.border_width as f32;
+        self.draw_bg.radius = opts.corner_radius as f32;
+        self.draw_bg.draw_abs(cx, Rect { pos, size });
+
+        let mut y = pos.y + padding;
+        let x = pos.x + padding;
+
+        if title_size.y > 0.0 {
+            set_text_style(&mut self.draw_text, opts.title_font_size, opts.title_color);
+            draw_text_at(cx, &mut self.draw_text, dvec2(x, y), &self.content.title);
+            y += title_size.y + LINE_SPACING;
+        }
+
+        set_text_style(&mut self.draw_text, opts.body_font_size, opts.body_color);
+        let items = self.content.items.clone();
+        for (item, text_size) in items.iter().zip(item_sizes) {
+            let line_height = text_size.y.max(swatch);
+
+            self.draw_swatch.color = item.color;
+            self.draw_swatch.draw_box(cx, Rect {
+                pos: dvec2(x, y + (line_height - swatch) / 2.0),
+                size: dvec2(swatch, swatch),
+            });
+
+            let text_pos = dvec2(x + swatch + SWATCH_GAP, y + (line_height - text_size.y) / 2.0);
+            draw_text_at(cx, &mut self.draw_text, text_pos, &item.text);
+            y += line_height + LINE_SPACING;
+        }
+    }
+}
+
+impl ChartTooltipRef {
+    pub fn set_options(&self, options: TooltipOptions) {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.set_options(options);
+        }
+    }
+
+    pub fn show(&self, cx: &mut Cx, cursor: DVec2, content: TooltipContent) {
+        if let Some(mut inner) = self.borrow_mut() {
+            if inner.show(cursor, content) {
+                inner.redraw(cx);
+            }
+        }
+    }
+
+    pub fn hide(&self, cx: &mut Cx) {
+        if let Some(mut inner) = self.borrow_mut() {
+            if inner.hide() {
+                inner.redraw(cx);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Dataset;
+    use crate::interaction::HitTester;
+    use crate::interaction::HitData;
+
+    fn bounds() -> Rect {
+        Rect { pos: dvec2(0.0, 0.0), size: dvec2(200.0, 100.0) }
+    }
+
+    #[test]
+    fn test_place_tooltip_follows_cursor() {
+        let pos = place_tooltip(dvec2(20.0, 20.0), dvec2(50.0, 30.0), bounds());
+        assert_eq!(pos, dvec2(32.0, 32.0));
+    }
+
+    #[test]
+    fn test_place_tooltip_flips_at_edges() {
+        let pos = place_tooltip(dvec2(180.0, 90.0), dvec2(50.0, 30.0), bounds());
+        assert_eq!(pos, dvec2(118.0, 48.0));
+    }
+
+    #[test]
+    fn test_content_from_hits() {
+        let data = ChartData::new()
+            .with_labels(vec!["Jan", "Feb"])
+            .add_dataset(Dataset::new("Sales").with_data(vec![10.0, 20.0]))
+            .add_dataset(Dataset::new("Costs").with_data(vec![5.0, 7.5]));
+
+        let mut tester = HitTester::new();
+        let rect = Rect { pos: dvec2(0.0, 0.0), size: dvec2(10.0, 10.0) };
+        tester.register(rect, HitData::Bar { dataset_index: 0, data_index: 1 });
+        tester.register(rect, HitData::Bar { dataset_index: 1, data_index: 1 });
+        let hits: Vec<HitRegion> = tester.hit_test_all(dvec2(5.0, 5.0)).into_iter().cloned().collect();
+
+        let content = TooltipContent::from_hits(&data, &hits, |ds, _| vec4(ds as f32, 0.0, 0.0, 1.0));
+        assert_eq!(content.title, "Feb");
+        assert_eq!(content.items.len(), 2);
+        assert_eq!(content.items[0].text, "Sales: 20");
+        assert_eq!(content.items[1].text, "Costs: 7.5");
+        assert_eq!(content.items[1].color.x, 1.0);
+    }
+
+    #[test]
+    fn test_format_point_value() {
+        assert_eq!(format_point_value(&DataPoint::new(2.0, 3.5), true), "(2, 3.5)");
+        assert_eq!(format_point_value(&DataPoint::new(2.0, 3.5), false), "3.5");
+        assert_eq!(format_point_value(&DataPoint::floating(1.0, 4.0), false), "1 – 4");
+        assert_eq!(format_stacked_value(&DataPoint::from_y(20.0), 45.0, StackMode::Absolute), "20 (total 45)");
+        assert_eq!(format_stacked_value(&DataPoint::from_y(20.0), 62.5, StackMode::Percent), "20 (total 62.5%)");
     }
 }
@@ -0,0 +1,335 @@
+use makepad_widgets::*;
+use crate::core::{InteractionAxis, ZoomOptions};
+use crate::coord::CartesianCoord;
+use crate::interaction::{ChartAction, VisibleRange};
+use super::tooltip::DrawTooltipBox;
+
+live_design! {
+    use link::shaders::*;
+
+    pub ChartZoom = {{ChartZoom}} {}
+}
+
+/// Longest gap between two presses that counts as a double click, in seconds
+const DOUBLE_CLICK_TIME: f64 = 0.35;
+
+/// Largest distance between two presses that counts as a double click
+const DOUBLE_CLICK_DISTANCE: f64 = 6.0;
+
+/// Smallest zoom rectangle side that zooms instead of being ignored as a click
+const MIN_DRAG_SIZE: f64 = 4.0;
+
+/// A drag in progress over the chart area
+#[derive(Clone, Copy, Debug)]
+struct ZoomDrag {
+    start: DVec2,
+    last: DVec2,
+    /// Drawing a zoom rectangle rather than panning
+    select: bool,
+}
+
+/// Zoom and pan for cartesian charts: mouse wheel, drag to pan, drag a rectangle
+/// to zoom into, two finger pinch and double click to reset, per `ZoomOptions`.
+/// The zoomed ranges are kept here and applied to the chart's coordinate system
+/// on every draw, so they survive new data.
+#[derive(Live, LiveHook, LiveRegister)]
+pub struct ChartZoom {
+    #[live]
+    draw_box: DrawTooltipBox,
+
+    #[rust]
+    options: ZoomOptions,
+
+    #[rust]
+    x_view: Option<(f64, f64)>,
+
+    #[rust]
+    y_view: Option<(f64, f64)>,
+
+    #[rust]
+    drag: Option<ZoomDrag>,
+
+    /// Time and position of the last press, to detect double clicks
+    #[rust]
+    last_press: Option<(f64, DVec2)>,
+
+    /// Distance between the fingers of a pinch in progress
+    #[rust]
+    pinch_distance: Option<f64>,
+
+    /// Whether drawing is currently clipped to the chart area
+    #[rust]
+    clipping: bool,
+}
+
+impl ChartZoom {
+    pub fn set_options(&mut self, options: ZoomOptions) {
+        self.options = options;
+        if !self.options.enabled {
+            self.x_view = None;
+            self.y_view = None;
+            self.drag = None;
+        }
+    }
+
+    pub fn options(&self) -> &ZoomOptions {
+        &self.options
+    }
+
+    /// Whether any axis is zoomed or panned
+    pub fn is_zoomed(&self) -> bool {
+        self.x_view.is_some() || self.y_view.is_some()
+    }
+
+    /// Show `x` and `y` instead of the data ranges; `None` keeps an axis on its data range
+    pub fn set_view(&mut self, x: Option<(f64, f64)>, y: Option<(f64, f64)>) {
+        self.x_view = x;
+        self.y_view = y;
+    }
+
+    /// Go back to the data ranges. Returns true if the chart was zoomed.
+    pub fn reset(&mut self) -> bool {
+        let was_zoomed = self.is_zoomed();
+        self.x_view = None;
+        self.y_view = None;
+        was_zoomed
+    }
+
+    /// Apply the zoomed ranges to `coord`, before fitting it to the chart rect
+    pub fn apply(&self, coord: &mut CartesianCoord) {
+        coord.set_x_view(self.x_view);
+        coord.set_y_view(self.y_view);
+    }
+
+    /// Range currently shown by `coord`
+    pub fn visible_range(&self, coord: &CartesianCoord) -> VisibleRange {
+        let (x_min, x_max) = coord.x_scale().get_data_bounds();
+        let (y_min, y_max) = coord.y_scale().get_data_bounds();
+        VisibleRange { x_min, x_max, y_min, y_max, zoomed: self.is_zoomed() }
+    }
+
+    /// Handle wheel, drag, pinch and double click input. Returns true when the chart
+    /// needs a redraw; `ChartAction::RangeChanged` is sent when the visible range changed.
+    pub fn handle_event(
+        &mut self,
+        cx: &mut Cx,
+        event: &Event,
+        scope: &mut Scope,
+        uid: WidgetUid,
+        coord: &mut CartesianCoord,
+    ) -> bool {
+        if !self.options.enabled {
+            return false;
+        }
+
+        let changed = match event {
+            Event::Scroll(e) if self.options.wheel && coord.contains_pixel(e.abs.x, e.abs.y) => {
+                if e.scroll.y == 0.0 {
+                    return false;
+                }
+                // Scrolling up zooms in
+                let speed = self.options.wheel_speed.clamp(0.01, 0.9);
+                let factor = if e.scroll.y > 0.0 { 1.0 + speed } else { 1.0 / (1.0 + speed) };
+                self.zoom_at(coord, e.abs, factor)
+            }
+            Event::MouseDown(e) if coord.contains_pixel(e.abs.x, e.abs.y) => {
+                let double_click = self.last_press.is_some_and(|(time, pos)| {
+                    e.time - time < DOUBLE_CLICK_TIME && (e.abs - pos).length() < DOUBLE_CLICK_DISTANCE
+                });
+                if double_click && self.options.double_click_reset {
+                    self.last_press = None;
+                    self.drag = None;
+                    self.reset()
+                } else {
+                    self.last_press = Some((e.time, e.abs));
+                    let select = self.options.drag && (!self.options.pan || e.modifiers.shift);
+                    if select || self.options.pan {
+                        self.drag = Some(ZoomDrag { start: e.abs, last: e.abs, select });
+                    }
+                    false
+                }
+            }
+            Event::MouseMove(e) => {
+                let Some(drag) = self.drag.as_mut() else {
+                    return false;
+                };
+                let delta = e.abs - drag.last;
+                drag.last = e.abs;
+                if drag.select {
+                    return true;
+                }
+                self.pan_by(coord, delta)
+            }
+            Event::MouseUp(e) => {
+                let Some(drag) = self.drag.take() else {
+                    return false;
+                };
+                if !drag.select {
+                    return false;
+                }
+                let size = e.abs - drag.start;
+                let (zoom_x, zoom_y) = self.axes();
+                let big_enough = (!zoom_x || size.x.abs() >= MIN_DRAG_SIZE) && (!zoom_y || size.y.abs() >= MIN_DRAG_SIZE);
+                if !big_enough {
+                    // Only the rectangle goes away
+                    return true;
+                }
+                self.zoom_to(coord, drag.start, e.abs)
+            }
+            Event::TouchUpdate(e) if self.options.pinch => {
+                if e.touches.len() != 2 {
+                    self.pinch_distance = None;
+                    return false;
+                }
+                let (a, b) = (e.touches[0].abs, e.touches[1].abs);
+                let distance = (a - b).length();
+                let center = (a + b) * 0.5;
+                // A pinch replaces any drag started by the first finger
+                self.drag = None;
+                let changed = match self.pinch_distance {
+                    Some(previous) if distance > 0.0 && coord.contains_pixel(center.x, center.y) => {
+                        self.zoom_at(coord, center, previous / distance)
+                    }
+                    _ => false,
+                };
+                self.pinch_distance = Some(distance);
+                changed
+            }
+            _ => false,
+        };
+
+        if changed {
+            self.apply(coord);
+            cx.widget_action(uid, &scope.path, ChartAction::RangeChanged(self.visible_range(coord)));
+        }
+        changed
+    }
+
+    /// Which of the X and Y axes zoom and pan
+    fn axes(&self) -> (bool, bool) {
+        match self.options.mode {
+            InteractionAxis::X => (true, false),
+            InteractionAxis::Y => (false, true),
+            InteractionAxis::XY => (true, true),
+        }
+    }
+
+    /// Zoom around `center`, `factor` below 1.0 zooms in
+    fn zoom_at(&mut self, coord: &CartesianCoord, center: DVec2, factor: f64) -> bool {
+        let area = coord.chart_area().clone();
+        let (zoom_x, zoom_y) = self.axes();
+        let mut changed = false;
+        if zoom_x {
+            let left = center.x - (center.x - area.left) * factor;
+            let right = center.x + (area.right - center.x) * factor;
+            changed |= self.set_x_pixels(coord, left, right);
+        }
+        if zoom_y {
+            let top = center.y - (center.y - area.top) * factor;
+            let bottom = center.y + (area.bottom - center.y) * factor;
+            changed |= self.set_y_pixels(coord, top, bottom);
+        }
+        changed
+    }
+
+    /// Move the view so the data under the pointer follows a drag of `delta` pixels
+    fn pan_by(&mut self, coord: &CartesianCoord, delta: DVec2) -> bool {
+        let area = coord.chart_area().clone();
+        let (pan_x, pan_y) = self.axes();
+        let mut changed = false;
+        if pan_x && delta.x != 0.0 {
+            changed |= self.set_x_pixels(coord, area.left - delta.x, area.right - delta.x);
+        }
+        if pan_y && delta.y != 0.0 {
+            changed |= self.set_y_pixels(coord, area.top - delta.y, area.bottom - delta.y);
+        }
+        changed
+    }
+
+    /// Zoom into the rectangle between two pixel positions
+    fn zoom_to(&mut self, coord: &CartesianCoord, a: DVec2, b: DVec2) -> bool {
+        let (zoom_x, zoom_y) = self.axes();
+        let mut changed = false;
+        if zoom_x {
+            changed |= self.set_x_pixels(coord, a.x.min(b.x), a.x.max(b.x));
+        }
+        if zoom_y {
+            changed |= self.set_y_pixels(coord, a.y.min(b.y), a.y.max(b.y));
+        }
+        changed
+    }
+
+    /// Show the X values between two pixel positions of the current view
+    fn set_x_pixels(&mut self, coord: &CartesianCoord, start: f64, end: f64) -> bool {
+        let view = Self::view_between(coord.x_for_pixel(start), coord.x_for_pixel(end));
+        let view = view.map(|(min, max)| self.options.x_limits.clamp(min, max));
+        let changed = view.is_some() && view != self.x_view;
+        if changed {
+            self.x_view = view;
+        }
+        changed
+    }
+
+    /// Show the Y values between two pixel positions of the current view
+    fn set_y_pixels(&mut self, coord: &CartesianCoord, start: f64, end: f64) -> bool {
+        let view = Self::view_between(coord.y_for_pixel(start), coord.y_for_pixel(end));
+        let view = view.map(|(min, max)| self.options.y_limits.clamp(min, max));
+        let changed = view.is_some() && view != self.y_view;
+        if changed {
+            self.y_view = view;
+        }
+        changed
+    }
+
+    fn view_between(a: f64, b: f64) -> Option<(f64, f64)> {
+        let (min, max) = (a.min(b), a.max(b));
+        (min.is_finite() && max.is_finite() && max > min).then_some((min, max))
+    }
+
+    /// Clip the data drawn until `end_clip` to the chart area while zoomed,
+    /// so elements scrolled out of view do not spill over the axes
+    pub fn begin_clip(&mut self, cx: &mut Cx2d, coord: &CartesianCoord) {
+        self.clipping = self.is_zoomed();
+        if self.clipping {
+            cx.begin_turtle(Walk::abs_rect(coord.chart_area().to_rect()), Layout::default());
+        }
+    }
+
+    pub fn end_clip(&mut self, cx: &mut Cx2d) {
+        if self.clipping {
+            cx.end_turtle();
+            self.clipping = false;
+        }
+    }
+
+    /// Draw the zoom rectangle being dragged, spanning the whole chart area
+    /// along an axis that does not zoom
+    pub fn draw_drag_box(&mut self, cx: &mut Cx2d, coord: &CartesianCoord) {
+        let Some(drag) = self.drag.filter(|drag| drag.select) else {
+            return;
+        };
+        let area = coord.chart_area();
+        let (zoom_x, zoom_y) = self.axes();
+        let clamp_x = |x: f64| x.clamp(area.left, area.right);
+        let clamp_y = |y: f64| y.clamp(area.top, area.bottom);
+        let (left, right) = if zoom_x {
+            (clamp_x(drag.start.x.min(drag.last.x)), clamp_x(drag.start.x.max(drag.last.x)))
+        } else {
+            (area.left, area.right)
+        };
+        let (top, bottom) = if zoom_y {
+            (clamp_y(drag.start.y.min(drag.last.y)), clamp_y(drag.start.y.max(drag.last.y)))
+        } else {
+            (area.top, area.bottom)
+        };
+
+        self.draw_box.color = self.options.drag_color;
+        self.draw_box.border_color = self.options.drag_border_color;
+        self.draw_box.border_width = 1.0;
+        self.draw_box.radius = 0.0;
+        self.draw_box.draw_abs(cx, Rect {
+            pos: dvec2(left, top),
+            size: dvec2(right - left, bottom - top),
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use makepad_widgets::*;
+use crate::core::{Annotation, AnnotationKind};
+use super::CartesianCoord;
+
+/// Gap between a point annotation and its label
+const POINT_LABEL_GAP: f64 = 4.0;
+
+/// An annotation placed in pixels on a cartesian chart
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnnotationShape {
+    Line { from: DVec2, to: DVec2 },
+    Box(Rect),
+    Ellipse(Rect),
+    Point { center: DVec2, radius: f64 },
+    Label { center: DVec2 },
+}
+
+impl AnnotationShape {
+    /// Place `annotation` on `coord`, with missing bounds at the edges of the chart area
+    pub fn of(annotation: &Annotation, coord: &CartesianCoord) -> Self {
+        let area = coord.chart_area();
+        let y_axis_id = annotation.y_axis_id.as_deref();
+        let corner = |x: Option<f64>, y: Option<f64>, edge: DVec2| {
+            let pixel = coord.data_to_pixel_for(x.unwrap_or(0.0), y.unwrap_or(0.0), y_axis_id);
+            dvec2(
+                if x.is_some() { pixel.x } else { edge.x },
+                if y.is_some() { pixel.y } else { edge.y },
+            )
+        };
+        let span = |x_min, x_max, y_min, y_max| {
+            (
+                corner(x_min, y_min, dvec2(area.left, area.bottom)),
+                corner(x_max, y_max, dvec2(area.right, area.top)),
+            )
+        };
+        let bounds = |(a, b): (DVec2, DVec2)| Rect {
+            pos: dvec2(a.x.min(b.x), a.y.min(b.y)),
+            size: dvec2((a.x - b.x).abs(), (a.y - b.y).abs()),
+        };
+
+        match annotation.kind {
+            AnnotationKind::Line { x_min, x_max, y_min, y_max } => {
+                let (from, to) = span(x_min, x_max, y_min, y_max);
+                AnnotationShape::Line { from, to }
+            }
+            AnnotationKind::Box { x_min, x_max, y_min, y_max } => AnnotationShape::Box(bounds(span(x_min, x_max, y_min, y_max))),
+            AnnotationKind::Ellipse { x_min, x_max, y_min, y_max } => AnnotationShape::Ellipse(bounds(span(x_min, x_max, y_min, y_max))),
+            AnnotationKind::Point { x, y, radius, .. } => AnnotationShape::Point {
+                center: coord.data_to_pixel_for(x, y, y_axis_id),
+                radius,
+            },
+            AnnotationKind::Label { x, y } => AnnotationShape::Label { center: coord.data_to_pixel_for(x, y, y_axis_id) },
+        }
+    }
+
+    /// Box around the shape
+    pub fn bounds(&self) -> Rect {
+        match *self {
+            AnnotationShape::Line { from, to } => Rect {
+                pos: dvec2(from.x.min(to.x), from.y.min(to.y)),
+                size: dvec2((from.x - to.x).abs(), (from.y - to.y).abs()),
+            },
+            AnnotationShape::Box(rect) | AnnotationShape::Ellipse(rect) => rect,
+            AnnotationShape::Point { center, radius } => Rect {
+                pos: dvec2(center.x - radius, center.y - radius),
+                size: dvec2(radius * 2.0, radius * 2.0),
+            },
+            AnnotationShape::Label { center } => Rect { pos: center, size: dvec2(0.0, 0.0) },
+        }
+    }
+
+    /// Whether any of the shape lies within `area`
+    pub fn overlaps(&self, area: &Rect) -> bool {
+        let bounds = self.bounds();
+        bounds.pos.x <= area.pos.x + area.size.x && bounds.pos.x + bounds.size.x >= area.pos.x
+            && bounds.pos.y <= area.pos.y + area.size.y && bounds.pos.y + bounds.size.y >= area.pos.y
+    }
+
+    /// Whether `pos` is on the shape, or within `tolerance` pixels of a line or point
+    pub fn contains(&self, pos: DVec2, tolerance: f64) -> bool {
+        match *self {
+            AnnotationShape::Line { from, to } => {
+                let line = to - from;
+                let length_sq = line.x * line.x + line.y * line.y;
+                let t = if length_sq > 0.0 {
+                    (((pos.x - from.x) * line.x + (pos.y - from.y) * line.y) / length_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (pos - (from + line * t)).length() <= tolerance
+            }
+            AnnotationShape::Box(rect) => {
+                pos.x >= rect.pos.x && pos.x <= rect.pos.x + rect.size.x
+                    && pos.y >= rect.pos.y && pos.y <= rect.pos.y + rect.size.y
+            }
+            AnnotationShape::Ellipse(rect) => {
+                let half = rect.size * 0.5;
+                if half.x <= 0.0 || half.y <= 0.0 {
+                    return false;
+                }
+                let offset = pos - (rect.pos + half);
+                (offset.x / half.x).powi(2) + (offset.y / half.y).powi(2) <= 1.0
+            }
+            AnnotationShape::Point { center, radius } => (pos - center).length() <= radius + tolerance,
+            AnnotationShape::Label { .. } => false,
+        }
+    }
+
+    /// Rect of a label of `size` centered on the shape, or above a point
+    pub fn label_rect(&self, size: DVec2) -> Rect {
+        let center = match *self {
+            AnnotationShape::Line { from, to } => (from + to) * 0.5,
+            AnnotationShape::Box(rect) | AnnotationShape::Ellipse(rect) => rect.pos + rect.size * 0.5,
+            AnnotationShape::Point { center, radius } => center - dvec2(0.0, radius + POINT_LABEL_GAP + size.y / 2.0),
+            AnnotationShape::Label { center } => center,
+        };
+        Rect { pos: center - size * 0.5, size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::ScaleType;
+    use crate::scale::LinearScale;
+
+    fn coord() -> CartesianCoord {
+        let mut coord = CartesianCoord::new()
+            .with_x_scale(ScaleType::Linear(LinearScale::new().with_nice(false)))
+            .with_y_scale(ScaleType::Linear(LinearScale::new().with_nice(false)));
+        coord.update(Rect {
+            pos: dvec2(0.0, 0.0),
+            size: dvec2(400.0, 300.0),
+        });
+        coord.set_x_data_range(0.0, 10.0);
+        coord.set_y_data_range(0.0, 100.0);
+        coord
+    }
+
+    #[test]
+    fn test_shapes_in_pixels() {
+        let coord = coord();
+        let area = coord.chart_area().clone();
+
+        // A target line spans the chart area at its Y value
+        let target = AnnotationShape::of(&Annotation::horizontal_line(50.0), &coord);
+        let y = coord.data_to_pixel(0.0, 50.0).y;
+        assert_eq!(target, AnnotationShape::Line { from: dvec2(area.left, y), to: dvec2(area.right, y) });
+
+        // A window between two X values covers the whole height
+        let AnnotationShape::Box(window) = AnnotationShape::of(&Annotation::x_range(2.0, 4.0), &coord) else {
+            panic!("expected a box");
+        };
+        assert_eq!(window.pos, dvec2(coord.data_to_pixel(2.0, 0.0).x, area.top));
+        assert_eq!(window.size.y, area.height());
+        assert!(window.size.x > 0.0);
+
+        let point = AnnotationShape::of(&Annotation::point(5.0, 20.0, 6.0), &coord);
+        assert_eq!(point, AnnotationShape::Point { center: coord.data_to_pixel(5.0, 20.0), radius: 6.0 });
+        let outside = AnnotationShape::of(&Annotation::point(50.0, 20.0, 6.0), &coord);
+        assert!(point.overlaps(&area.to_rect()) && !outside.overlaps(&area.to_rect()));
+    }
+
+    #[test]
+    fn test_shape_contains() {
+        let line = AnnotationShape::Line { from: dvec2(0.0, 0.0), to: dvec2(100.0, 0.0) };
+        assert!(line.contains(dvec2(50.0, 3.0), 4.0));
+        assert!(!line.contains(dvec2(50.0, 5.0), 4.0));
+        assert!(!line.contains(dvec2(106.0, 0.0), 4.0));
+
+        let rect = Rect { pos: dvec2(0.0, 0.0), size: dvec2(100.0, 50.0) };
+        assert!(AnnotationShape::Box(rect).contains(dvec2(95.0, 45.0), 0.0));
+        let ellipse = AnnotationShape::Ellipse(rect);
+        assert!(ellipse.contains(dvec2(50.0, 25.0), 0.0));
+        assert!(!ellipse.contains(dvec2(95.0, 45.0), 0.0));
+
+        // Labels of points sit above them
+        let point = AnnotationShape::Point { center: dvec2(50.0, 50.0), radius: 6.0 };
+        assert_eq!(point.label_rect(dvec2(20.0, 10.0)).pos, dvec2(40.0, 30.0));
+    }
+}
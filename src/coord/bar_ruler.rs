@@ -0,0 +1,153 @@
+use crate::core::{BarThickness, Dataset, UNSET_PERCENTAGE};
+
+/// Share of the category the bars of bar charts take when their datasets do not set one
+pub const DEFAULT_CATEGORY_PERCENTAGE: f64 = 0.8;
+
+/// Bar options of a dataset that size its bars along the category axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BarSizing {
+    pub thickness: BarThickness,
+    pub max_thickness: Option<f64>,
+    pub bar_percentage: f64,
+    pub category_percentage: f64,
+}
+
+impl BarSizing {
+    /// Bar options of `dataset`, taking `category_percentage` of the category when
+    /// the dataset sets no share of its own. Bars fill their whole part of it unless
+    /// the dataset sets a `bar_percentage`.
+    pub fn of(dataset: &Dataset, category_percentage: f64) -> Self {
+        Self {
+            thickness: dataset.bar_thickness,
+            max_thickness: dataset.max_bar_thickness,
+            bar_percentage: set_or(dataset.bar_percentage, 1.0),
+            category_percentage: set_or(dataset.category_percentage, category_percentage),
+        }
+    }
+}
+
+/// `percentage` unless it is `UNSET_PERCENTAGE`, `default` otherwise
+fn set_or(percentage: f64, default: f64) -> f64 {
+    if percentage > UNSET_PERCENTAGE { percentage } else { default }
+}
+
+impl From<&Dataset> for BarSizing {
+    fn from(dataset: &Dataset) -> Self {
+        Self::of(dataset, DEFAULT_CATEGORY_PERCENTAGE)
+    }
+}
+
+/// Pixels of the data indices along the category axis of a bar chart, from which
+/// bars are placed side by side in slots the way Chart.js bar charts do
+#[derive(Clone, Debug, Default)]
+pub struct BarRuler {
+    /// Pixel of each data index
+    pixels: Vec<f64>,
+    /// Smallest distance between neighbouring indices
+    min_spacing: f64,
+    /// Length of the axis in pixels
+    length: f64,
+}
+
+impl BarRuler {
+    /// Ruler for data indices at `pixels` along an axis `length` pixels long
+    pub fn new(pixels: Vec<f64>, length: f64) -> Self {
+        let mut sorted = pixels.clone();
+        sorted.sort_by(f64::total_cmp);
+        let min_spacing = sorted
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|spacing| *spacing > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let length = length.abs();
+        Self {
+            pixels,
+            min_spacing: if min_spacing.is_finite() { min_spacing } else { length },
+            length,
+        }
+    }
+
+    /// Start pixel and thickness of the bar at `index` in `slot` of the `slots`
+    /// sharing the category, or `None` past the indices of the ruler
+    pub fn bar(&self, index: usize, slot: usize, slots: usize, sizing: &BarSizing) -> Option<(f64, f64)> {
+        let center = *self.pixels.get(index)?;
+        let slots = slots.max(1) as f64;
+        let percent = sizing.category_percentage;
+
+        // Room for all slots, where it starts, and the share of a slot the bar fills
+        let (start, size, ratio) = match sizing.thickness {
+            BarThickness::Auto => {
+                let size = self.min_spacing * percent;
+                (center - size / 2.0, size, sizing.bar_percentage)
+            }
+            BarThickness::Fixed(thickness) => {
+                let size = thickness * slots;
+                (center - size / 2.0, size, 1.0)
+            }
+            BarThickness::Flex => {
+                let prev = index.checked_sub(1).and_then(|i| self.pixels.get(i)).copied();
+                let next = self.pixels.get(index + 1).copied();
+                let prev = prev.unwrap_or_else(|| center - next.map_or(self.length, |next| next - center));
+                let next = next.unwrap_or(center + center - prev);
+                let start = center - (center - prev.min(next)) / 2.0 * percent;
+                (start, (next - prev).abs() / 2.0 * percent, sizing.bar_percentage)
+            }
+        };
+
+        let chunk = size / slots;
+        let thickness = (chunk * ratio).min(sizing.max_thickness.unwrap_or(f64::INFINITY));
+        let bar_center = start + chunk * (slot as f64 + 0.5);
+        Some((bar_center - thickness / 2.0, thickness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sizing(thickness: BarThickness) -> BarSizing {
+        BarSizing::from(&Dataset::new("A").with_bar_thickness(thickness).with_bar_percentage(0.9))
+    }
+
+    #[test]
+    fn test_bar_auto_and_fixed() {
+        let ruler = BarRuler::new(vec![50.0, 150.0, 250.0], 300.0);
+
+        // Without sizing options the bars fill 80% of the category between them
+        let plain = BarSizing::from(&Dataset::new("A"));
+        assert_eq!(ruler.bar(1, 0, 2, &plain), Some((110.0, 40.0)));
+        assert_eq!(ruler.bar(1, 0, 1, &BarSizing::of(&Dataset::new("A"), 0.6)), Some((120.0, 60.0)));
+
+        // 80% of the category split in two slots, each bar 90% of its slot
+        let (start, size) = ruler.bar(1, 0, 2, &sizing(BarThickness::Auto)).unwrap();
+        assert!((start - 112.0).abs() < 1e-9 && (size - 36.0).abs() < 1e-9);
+        let (start, _) = ruler.bar(1, 1, 2, &sizing(BarThickness::Auto)).unwrap();
+        assert!((start - 152.0).abs() < 1e-9);
+
+        assert_eq!(ruler.bar(0, 1, 2, &sizing(BarThickness::Fixed(10.0))), Some((50.0, 10.0)));
+        assert_eq!(ruler.bar(3, 0, 2, &sizing(BarThickness::Auto)), None);
+
+        let mut limited = sizing(BarThickness::Auto);
+        limited.max_thickness = Some(20.0);
+        assert_eq!(ruler.bar(1, 0, 1, &limited), Some((140.0, 20.0)));
+    }
+
+    #[test]
+    fn test_bar_flex_follows_neighbours() {
+        let mut flex = sizing(BarThickness::Flex);
+        flex.category_percentage = 1.0;
+        flex.bar_percentage = 1.0;
+        let ruler = BarRuler::new(vec![0.0, 20.0, 100.0], 200.0);
+
+        // Half way to each neighbour
+        assert_eq!(ruler.bar(1, 0, 1, &flex), Some((10.0, 50.0)));
+        // The first and last bars mirror their one neighbour
+        assert_eq!(ruler.bar(0, 0, 1, &flex), Some((-10.0, 20.0)));
+        assert_eq!(ruler.bar(2, 0, 1, &flex), Some((60.0, 80.0)));
+        // Auto sizes every bar by the smallest gap
+        assert_eq!(ruler.bar(2, 0, 1, &BarSizing { thickness: BarThickness::Auto, ..flex }), Some((90.0, 20.0)));
+
+        let single = BarRuler::new(vec![100.0], 200.0);
+        assert_eq!(single.bar(0, 0, 1, &flex), Some((0.0, 200.0)));
+    }
+}
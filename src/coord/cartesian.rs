@@ -1,6 +1,8 @@
 use makepad_widgets::*;
-use crate::scale::{Scale, LinearScale, CategoryScale, Tick};
-use crate::core::{AxisOptions, TickOptions};
+use crate::scale::{Scale, LinearScale, LogarithmicScale, CategoryScale, TimeScale, Tick, format_number};
+use crate::core::{AxisOptions, AxisType, ChartData, ScalesOptions, TickOptions};
+use super::BarRuler;
+pub use crate::core::AxisPosition;
 
 /// Represents the chart area boundaries
 #[derive(Clone, Debug, Default)]
@@ -71,20 +73,59 @@ pub struct CartesianCoord {
     right_padding: f64,
     /// Padding for top (title, etc.)
     top_padding: f64,
+    /// X axis runs right to left
+    reverse_x: bool,
+    /// Y axis runs top to bottom
+    reverse_y: bool,
+    /// Data ranges fitted to the data, before any zoom
+    x_fit: Option<(f64, f64)>,
+    y_fit: Option<(f64, f64)>,
+    /// Zoomed or panned ranges shown instead of the fitted ones
+    x_view: Option<(f64, f64)>,
+    y_view: Option<(f64, f64)>,
+    /// Distance of the default Y axis line outwards from its edge of the chart area
+    y_offset: f64,
+    /// Y axes besides the default one
+    y_axes: Vec<ExtraYAxis>,
+}
+
+/// A Y axis besides the default one, with its own scale
+struct ExtraYAxis {
+    id: String,
+    scale: ScaleType,
+    reverse: bool,
+    /// Data range fitted to the datasets drawn against the axis, before any zoom
+    fit: Option<(f64, f64)>,
+    /// Distance of the axis line outwards from its edge of the chart area
+    offset: f64,
 }
 
 /// Enum to hold different scale types
+// Scales live inside the coordinate system, so the size of the time scale is not worth boxing
+#[allow(clippy::large_enum_variant)]
 pub enum ScaleType {
     Linear(LinearScale),
     Category(CategoryScale),
+    Time(TimeScale),
+    Logarithmic(LogarithmicScale),
 }
 
 impl ScaleType {
+    /// Scale for a value axis: logarithmic when the axis asks for it, `linear` otherwise
+    pub fn value_scale(axis: &AxisOptions, linear: LinearScale) -> Self {
+        match axis.scale_type {
+            AxisType::Logarithmic => ScaleType::Logarithmic(LogarithmicScale::new().with_base(axis.log_base)),
+            _ => ScaleType::Linear(linear),
+        }
+    }
+
     /// Get pixel for value
     pub fn get_pixel_for_value(&self, value: f64) -> f64 {
         match self {
             ScaleType::Linear(s) => s.get_pixel_for_value(value),
             ScaleType::Category(s) => s.get_pixel_for_value(value),
+            ScaleType::Time(s) => s.get_pixel_for_value(value),
+            ScaleType::Logarithmic(s) => s.get_pixel_for_value(value),
         }
     }
 
@@ -93,6 +134,8 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.get_value_for_pixel(pixel),
             ScaleType::Category(s) => s.get_value_for_pixel(pixel),
+            ScaleType::Time(s) => s.get_value_for_pixel(pixel),
+            ScaleType::Logarithmic(s) => s.get_value_for_pixel(pixel),
         }
     }
 
@@ -101,6 +144,8 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.set_pixel_range(start, end),
             ScaleType::Category(s) => s.set_pixel_range(start, end),
+            ScaleType::Time(s) => s.set_pixel_range(start, end),
+            ScaleType::Logarithmic(s) => s.set_pixel_range(start, end),
         }
     }
 
@@ -109,6 +154,8 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.set_data_range(min, max),
             ScaleType::Category(s) => s.set_data_range(min, max),
+            ScaleType::Time(s) => s.set_data_range(min, max),
+            ScaleType::Logarithmic(s) => s.set_data_range(min, max),
         }
     }
 
@@ -117,6 +164,8 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.build_ticks(options),
             ScaleType::Category(s) => s.build_ticks(options),
+            ScaleType::Time(s) => s.build_ticks(options),
+            ScaleType::Logarithmic(s) => s.build_ticks(options),
         }
     }
 
@@ -125,6 +174,26 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.get_data_bounds(),
             ScaleType::Category(s) => s.get_data_bounds(),
+            ScaleType::Time(s) => s.get_data_bounds(),
+            ScaleType::Logarithmic(s) => s.get_data_bounds(),
+        }
+    }
+
+    /// Label for `value` as the axis would show it: the category label, the time
+    /// in the tick unit, or the formatted number
+    pub fn format_value(&self, value: f64) -> String {
+        match self {
+            ScaleType::Category(s) => s.get_label(value.round().max(0.0) as usize).unwrap_or_default().to_string(),
+            ScaleType::Time(s) => s.format(value, s.tick_unit(&TickOptions::default()).0),
+            ScaleType::Linear(_) | ScaleType::Logarithmic(_) => format_number(value),
+        }
+    }
+
+    /// Start logarithmic scales at `value`, the smallest positive data value, when
+    /// the data range reaches zero or below; other scales ignore it
+    pub fn set_min_positive(&mut self, value: Option<f64>) {
+        if let ScaleType::Logarithmic(s) = self {
+            s.set_min_positive(value);
         }
     }
 
@@ -133,6 +202,8 @@ impl ScaleType {
         match self {
             ScaleType::Linear(s) => s.is_inverted(),
             ScaleType::Category(s) => s.is_inverted(),
+            ScaleType::Time(s) => s.is_inverted(),
+            ScaleType::Logarithmic(s) => s.is_inverted(),
         }
     }
 
@@ -160,6 +231,30 @@ impl ScaleType {
         }
     }
 
+    /// Get as time scale reference
+    pub fn as_time(&self) -> Option<&TimeScale> {
+        match self {
+            ScaleType::Time(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get as mutable time scale
+    pub fn as_time_mut(&mut self) -> Option<&mut TimeScale> {
+        match self {
+            ScaleType::Time(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get as logarithmic scale reference
+    pub fn as_logarithmic(&self) -> Option<&LogarithmicScale> {
+        match self {
+            ScaleType::Logarithmic(s) => Some(s),
+            _ => None,
+        }
+    }
+
     /// Get as mutable category scale
     pub fn as_category_mut(&mut self) -> Option<&mut CategoryScale> {
         match self {
@@ -181,6 +276,14 @@ impl CartesianCoord {
             bottom_padding: 30.0,
             right_padding: 20.0,
             top_padding: 20.0,
+            reverse_x: false,
+            reverse_y: false,
+            x_fit: None,
+            y_fit: None,
+            x_view: None,
+            y_view: None,
+            y_offset: 0.0,
+            y_axes: Vec::new(),
         }
     }
 
@@ -196,6 +299,21 @@ impl CartesianCoord {
         self
     }
 
+    /// Add the extra Y axes of `scales`, each with a value scale built from its options
+    pub fn with_y_axes(mut self, scales: &ScalesOptions) -> Self {
+        self.y_axes = scales.y_axes.iter().map(|(id, axis)| ExtraYAxis {
+            id: id.clone(),
+            scale: ScaleType::value_scale(
+                axis,
+                LinearScale::new().with_begin_at_zero(axis.begin_at_zero).with_nice(true),
+            ),
+            reverse: false,
+            fit: None,
+            offset: 0.0,
+        }).collect();
+        self
+    }
+
     /// Set axis padding values
     pub fn with_padding(mut self, left: f64, top: f64, right: f64, bottom: f64) -> Self {
         self.left_padding = left;
@@ -205,6 +323,33 @@ impl CartesianCoord {
         self
     }
 
+    /// Apply `reverse`, the scale bounds (`min`, `max`, `suggested_*`, `begin_at_zero`)
+    /// and time options from the axis options. Call before setting data ranges.
+    pub fn apply_axis_options(&mut self, scales: &ScalesOptions) {
+        self.reverse_x = scales.x.reverse;
+        self.reverse_y = scales.y.reverse;
+        for extra in &mut self.y_axes {
+            extra.reverse = scales.y_axis(Some(&extra.id)).reverse;
+        }
+        let extras = self.y_axes.iter_mut().map(|extra| (&mut extra.scale, scales.y_axis(Some(&extra.id))));
+        for (scale, axis) in [(&mut self.x_scale, &scales.x), (&mut self.y_scale, &scales.y)].into_iter().chain(extras) {
+            match scale {
+                ScaleType::Linear(s) => s.apply_axis_options(axis),
+                ScaleType::Time(s) => s.apply_axis_options(axis),
+                ScaleType::Logarithmic(s) => s.apply_axis_options(axis),
+                ScaleType::Category(_) => {}
+            }
+        }
+    }
+
+    /// Change axis padding values in place
+    pub fn set_padding(&mut self, left: f64, top: f64, right: f64, bottom: f64) {
+        self.left_padding = left;
+        self.top_padding = top;
+        self.right_padding = right;
+        self.bottom_padding = bottom;
+    }
+
     /// Update the coordinate system with new bounds
     pub fn update(&mut self, rect: Rect) {
         self.total_rect = rect;
@@ -218,26 +363,162 @@ impl CartesianCoord {
         );
 
         // Update scale pixel ranges
-        // X axis goes left to right
-        self.x_scale.set_pixel_range(self.chart_area.left, self.chart_area.right);
-        // Y axis goes bottom to top (inverted pixel coordinates)
-        self.y_scale.set_pixel_range(self.chart_area.bottom, self.chart_area.top);
+        // X axis goes left to right unless reversed
+        if self.reverse_x {
+            self.x_scale.set_pixel_range(self.chart_area.right, self.chart_area.left);
+        } else {
+            self.x_scale.set_pixel_range(self.chart_area.left, self.chart_area.right);
+        }
+        // Y axes go bottom to top (inverted pixel coordinates) unless reversed
+        let (top, bottom) = (self.chart_area.top, self.chart_area.bottom);
+        let extras = self.y_axes.iter_mut().map(|extra| (&mut extra.scale, extra.reverse));
+        for (scale, reverse) in std::iter::once((&mut self.y_scale, self.reverse_y)).chain(extras) {
+            if reverse {
+                scale.set_pixel_range(top, bottom);
+            } else {
+                scale.set_pixel_range(bottom, top);
+            }
+        }
     }
 
     /// Set the data ranges for both axes
     pub fn set_data_ranges(&mut self, x_min: f64, x_max: f64, y_min: f64, y_max: f64) {
-        self.x_scale.set_data_range(x_min, x_max);
-        self.y_scale.set_data_range(y_min, y_max);
+        self.set_x_data_range(x_min, x_max);
+        self.set_y_data_range(y_min, y_max);
     }
 
     /// Set X data range only
     pub fn set_x_data_range(&mut self, min: f64, max: f64) {
         self.x_scale.set_data_range(min, max);
+        self.x_fit = Some(self.x_scale.get_data_bounds());
+        Self::apply_view(&mut self.x_scale, self.x_view, self.x_fit);
     }
 
     /// Set Y data range only
     pub fn set_y_data_range(&mut self, min: f64, max: f64) {
         self.y_scale.set_data_range(min, max);
+        self.y_fit = Some(self.y_scale.get_data_bounds());
+        Self::apply_view(&mut self.y_scale, self.y_view, self.y_fit);
+        self.apply_extra_views();
+    }
+
+    /// Smallest positive X value, where a logarithmic X axis starts when the data
+    /// reaches zero or below. Set before the data range.
+    pub fn set_x_min_positive(&mut self, value: Option<f64>) {
+        self.x_scale.set_min_positive(value);
+    }
+
+    /// Smallest positive value on the Y axis `id`, where a logarithmic axis starts
+    /// when the data reaches zero or below. Set before the data range.
+    pub fn set_y_axis_min_positive(&mut self, id: Option<&str>, value: Option<f64>) {
+        match id.and_then(|id| self.y_axes.iter_mut().find(|extra| extra.id == id)) {
+            Some(extra) => extra.scale.set_min_positive(value),
+            None => self.y_scale.set_min_positive(value),
+        }
+    }
+
+    /// Set the data range of the Y axis `id`, the default Y axis for `None` and unknown ids
+    pub fn set_y_axis_data_range(&mut self, id: Option<&str>, min: f64, max: f64) {
+        let Some(extra) = id.and_then(|id| self.y_axes.iter_mut().find(|extra| extra.id == id)) else {
+            self.set_y_data_range(min, max);
+            return;
+        };
+        extra.scale.set_data_range(min, max);
+        extra.fit = Some(extra.scale.get_data_bounds());
+        self.apply_extra_views();
+    }
+
+    /// Show exactly `min..max` on the X axis instead of the fitted data range,
+    /// or go back to the fitted range with `None`
+    pub fn set_x_view(&mut self, view: Option<(f64, f64)>) {
+        self.x_view = view;
+        Self::apply_view(&mut self.x_scale, self.x_view, self.x_fit);
+    }
+
+    /// Show exactly `min..max` on the Y axis instead of the fitted data range,
+    /// or go back to the fitted range with `None`
+    pub fn set_y_view(&mut self, view: Option<(f64, f64)>) {
+        self.y_view = view;
+        Self::apply_view(&mut self.y_scale, self.y_view, self.y_fit);
+        self.apply_extra_views();
+    }
+
+    /// Zoomed X range, if any
+    pub fn x_view(&self) -> Option<(f64, f64)> {
+        self.x_view
+    }
+
+    /// Zoomed Y range, if any
+    pub fn y_view(&self) -> Option<(f64, f64)> {
+        self.y_view
+    }
+
+    fn apply_view(scale: &mut ScaleType, view: Option<(f64, f64)>, fit: Option<(f64, f64)>) {
+        if let ScaleType::Category(s) = scale {
+            s.set_view(view);
+            return;
+        }
+        let Some((min, max)) = view.or(fit) else {
+            return;
+        };
+        match scale {
+            ScaleType::Linear(s) => s.set_view_range(min, max),
+            ScaleType::Time(s) => s.set_view_range(min, max),
+            ScaleType::Logarithmic(s) => s.set_view_range(min, max),
+            ScaleType::Category(_) => {}
+        }
+    }
+
+    /// Zoom the extra Y axes along with the default one, showing the same part of
+    /// their fitted ranges
+    fn apply_extra_views(&mut self) {
+        let fraction = match (self.y_view, self.y_fit) {
+            (Some((min, max)), Some((fit_min, fit_max))) if fit_max != fit_min => {
+                let span = fit_max - fit_min;
+                Some(((min - fit_min) / span, (max - fit_min) / span))
+            }
+            _ => None,
+        };
+        for extra in &mut self.y_axes {
+            let view = fraction.zip(extra.fit).map(|((from, to), (min, max))| {
+                (min + from * (max - min), min + to * (max - min))
+            });
+            Self::apply_view(&mut extra.scale, view, extra.fit);
+        }
+    }
+
+    /// X value under a pixel; a fractional position on category scales
+    pub fn x_for_pixel(&self, pixel: f64) -> f64 {
+        match &self.x_scale {
+            ScaleType::Category(s) => s.get_position_for_pixel(pixel),
+            s => s.get_value_for_pixel(pixel),
+        }
+    }
+
+    /// Y value under a pixel; a fractional position on category scales
+    pub fn y_for_pixel(&self, pixel: f64) -> f64 {
+        match &self.y_scale {
+            ScaleType::Category(s) => s.get_position_for_pixel(pixel),
+            s => s.get_value_for_pixel(pixel),
+        }
+    }
+
+    /// Value on the default Y axis at the pixel `y` is drawn at on the Y axis
+    /// `y_axis_id`, to compare values plotted against different Y axes
+    pub fn y_on_default_axis(&self, y: f64, y_axis_id: Option<&str>) -> f64 {
+        match y_axis_id {
+            None => y,
+            id => self.y_for_pixel(self.y_scale_for(id).get_pixel_for_value(y)),
+        }
+    }
+
+    /// X value of a data point: its index on category scales, `x` (falling back
+    /// to the index) on linear and time scales
+    pub fn x_value(&self, x: Option<f64>, index: usize) -> f64 {
+        match self.x_scale {
+            ScaleType::Category(_) => index as f64,
+            _ => x.unwrap_or(index as f64),
+        }
     }
 
     /// Convert data coordinates to pixel coordinates
@@ -248,6 +529,14 @@ impl CartesianCoord {
         )
     }
 
+    /// Like `data_to_pixel` with `y` on the Y axis `y_axis_id`
+    pub fn data_to_pixel_for(&self, x: f64, y: f64, y_axis_id: Option<&str>) -> DVec2 {
+        match y_axis_id {
+            None => self.data_to_pixel(x, y),
+            id => dvec2(self.x_scale.get_pixel_for_value(x), self.y_scale_for(id).get_pixel_for_value(y)),
+        }
+    }
+
     /// Convert pixel coordinates to data coordinates
     pub fn pixel_to_data(&self, x: f64, y: f64) -> DVec2 {
         dvec2(
@@ -276,6 +565,42 @@ impl CartesianCoord {
         &self.y_scale
     }
 
+    /// Scale of the Y axis `id`, the default Y scale for `None` and unknown ids
+    pub fn y_scale_for(&self, id: Option<&str>) -> &ScaleType {
+        id.and_then(|id| self.y_axes.iter().find(|extra| extra.id == id))
+            .map_or(&self.y_scale, |extra| &extra.scale)
+    }
+
+    /// Move the line of the Y axis `id` `offset` pixels outwards from its edge of
+    /// the chart area, to make room for the axes closer to the chart area
+    pub fn set_y_axis_offset(&mut self, id: Option<&str>, offset: f64) {
+        match id.and_then(|id| self.y_axes.iter_mut().find(|extra| extra.id == id)) {
+            Some(extra) => extra.offset = offset,
+            None => self.y_offset = offset,
+        }
+    }
+
+    /// Every Y axis, the default one first, with its options from `scales`, its
+    /// ticks and where its line is drawn
+    pub fn y_axes(&self, scales: &ScalesOptions) -> Vec<AxisInfo> {
+        let extras = self.y_axes.iter().map(|extra| (Some(extra.id.as_str()), extra.offset));
+        std::iter::once((None, self.y_offset)).chain(extras).map(|(id, offset)| {
+            let options = scales.y_axis(id).clone();
+            let (position, x) = match options.position {
+                Some(AxisPosition::Right) => (AxisPosition::Right, self.chart_area.right + offset),
+                _ => (AxisPosition::Left, self.chart_area.left - offset),
+            };
+            AxisInfo {
+                id: id.map(String::from),
+                position,
+                ticks: self.y_scale_for(id).build_ticks(&options.ticks),
+                line_start: dvec2(x, self.chart_area.bottom),
+                line_end: dvec2(x, self.chart_area.top),
+                options,
+            }
+        }).collect()
+    }
+
     /// Get mutable X scale
     pub fn x_scale_mut(&mut self) -> &mut ScaleType {
         &mut self.x_scale
@@ -336,6 +661,8 @@ impl CartesianCoord {
     }
 
     /// Get the bar width for category scales
+    #[deprecated(note = "use x_bar_ruler, which sizes bars per dataset")]
+    #[allow(deprecated)]
     pub fn get_bar_width(&self, bar_percent: f64) -> f64 {
         if let Some(cat_scale) = self.x_scale.as_category() {
             cat_scale.get_bar_width(bar_percent)
@@ -347,6 +674,27 @@ impl CartesianCoord {
             band_width * bar_percent.clamp(0.1, 1.0)
         }
     }
+
+    /// Ruler placing the bars of `data` along the X axis, at each index's X value
+    pub fn x_bar_ruler(&self, data: &ChartData) -> BarRuler {
+        let pixels = (0..Self::index_count(data)).map(|index| {
+            let x = data.datasets.iter().find_map(|dataset| dataset.data.get(index).and_then(|point| point.x));
+            self.x_scale.get_pixel_for_value(self.x_value(x, index))
+        }).collect();
+        BarRuler::new(pixels, self.chart_area.width())
+    }
+
+    /// Ruler placing the bars of `data` along a category Y axis, for horizontal bars
+    pub fn y_bar_ruler(&self, data: &ChartData) -> BarRuler {
+        let pixels = (0..Self::index_count(data))
+            .map(|index| self.y_scale.get_pixel_for_value(index as f64))
+            .collect();
+        BarRuler::new(pixels, self.chart_area.height())
+    }
+
+    fn index_count(data: &ChartData) -> usize {
+        data.datasets.iter().map(|dataset| dataset.data.len()).fold(data.labels.len(), usize::max)
+    }
 }
 
 impl Default for CartesianCoord {
@@ -355,17 +703,10 @@ impl Default for CartesianCoord {
     }
 }
 
-/// Axis position for rendering
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum AxisPosition {
-    Left,
-    Right,
-    Top,
-    Bottom,
-}
-
 /// Information about an axis for rendering
 pub struct AxisInfo {
+    /// Id of an extra Y axis, `None` for the default axes
+    pub id: Option<String>,
     pub position: AxisPosition,
     pub ticks: Vec<Tick>,
     pub line_start: DVec2,
@@ -376,6 +717,8 @@ pub struct AxisInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coord::BarSizing;
+    use crate::core::{stack_values, BarThickness, ChartOptions, Dataset, StackMode};
 
     #[test]
     fn test_cartesian_coord_basic() {
@@ -425,4 +768,174 @@ mod tests {
         assert!(!area.contains(0.0, 0.0));
         assert!(!area.contains(400.0, 300.0));
     }
+
+    #[test]
+    fn test_reversed_axes() {
+        let mut scales = ScalesOptions::default();
+        scales.x.reverse = true;
+        scales.y.reverse = true;
+
+        let mut coord = CartesianCoord::new()
+            .with_x_scale(ScaleType::Linear(LinearScale::new().with_nice(false)))
+            .with_y_scale(ScaleType::Linear(LinearScale::new().with_nice(false)));
+        coord.apply_axis_options(&scales);
+        coord.update(Rect {
+            pos: dvec2(0.0, 0.0),
+            size: dvec2(400.0, 300.0),
+        });
+        coord.set_data_ranges(0.0, 100.0, 0.0, 100.0);
+
+        let area = coord.chart_area().clone();
+        let origin = coord.data_to_pixel(0.0, 0.0);
+        assert_eq!(origin, dvec2(area.right, area.top));
+    }
+
+    #[test]
+    fn test_x_value_by_scale() {
+        let coord = CartesianCoord::new();
+        assert_eq!(coord.x_value(Some(1_700_000_000.0), 3), 3.0);
+
+        let coord = CartesianCoord::new().with_x_scale(ScaleType::Time(TimeScale::new()));
+        assert_eq!(coord.x_value(Some(1_700_000_000.0), 3), 1_700_000_000.0);
+        assert_eq!(coord.x_value(None, 3), 3.0);
+    }
+
+    #[test]
+    fn test_x_bar_ruler_follows_x_values() {
+        let data = ChartData::new()
+            .add_dataset(Dataset::new("A").with_xy_data(vec![(0.0, 1.0), (1.0, 2.0), (4.0, 3.0)]));
+        let mut coord = CartesianCoord::new()
+            .with_x_scale(ScaleType::Linear(LinearScale::new().with_nice(false)));
+        coord.update(Rect {
+            pos: dvec2(0.0, 0.0),
+            size: dvec2(400.0, 300.0),
+        });
+        coord.set_x_data_range(0.0, 4.0);
+
+        // Flex bars fill the gaps around them, so the one at 1 is wider than at 0
+        let sizing = BarSizing::from(&Dataset::new("A").with_bar_thickness(BarThickness::Flex));
+        let ruler = coord.x_bar_ruler(&data);
+        let (_, first) = ruler.bar(0, 0, 1, &sizing).unwrap();
+        let (_, second) = ruler.bar(1, 0, 1, &sizing).unwrap();
+        assert!((second / first - 2.0).abs() < 1e-9);
+        assert!(ruler.bar(3, 0, 1, &sizing).is_none());
+    }
+
+    #[test]
+    fn test_value_scale_from_axis_options() {
+        let mut axis = AxisOptions::default();
+        assert!(ScaleType::value_scale(&axis, LinearScale::new()).as_linear().is_some());
+
+        axis.scale_type = AxisType::Logarithmic;
+        axis.log_base = 2.0;
+        let scale = ScaleType::value_scale(&axis, LinearScale::new());
+        assert_eq!(scale.as_logarithmic().map(|s| s.base()), Some(2.0));
+    }
+
+    #[test]
+    fn test_view_ranges() {
+        let mut coord = CartesianCoord::new()
+            .with_x_scale(ScaleType::Linear(LinearScale::new()))
+            .with_y_scale(ScaleType::Linear(LinearScale::new()));
+        coord.set_data_ranges(0.0, 97.0, 0.0, 10.0);
+        assert_eq!(coord.x_scale().get_data_bounds(), (0.0, 100.0));
+
+        // Views skip nice rounding and survive new data ranges
+        coord.set_x_view(Some((12.5, 37.5)));
+        coord.set_x_data_range(0.0, 150.0);
+        assert_eq!(coord.x_scale().get_data_bounds(), (12.5, 37.5));
+
+        coord.set_x_view(None);
+        assert_eq!(coord.x_scale().get_data_bounds(), (0.0, 200.0));
+    }
+
+    #[test]
+    fn test_extra_y_axes() {
+        let mut scales = ScalesOptions::default();
+        let mut right = AxisOptions {
+            position: Some(AxisPosition::Right),
+            ..Default::default()
+        };
+        right.reverse = true;
+        scales.y_axes.push(("rate".into(), right));
+
+        let mut coord = CartesianCoord::new()
+            .with_y_scale(ScaleType::Linear(LinearScale::new()))
+            .with_y_axes(&scales);
+        coord.apply_axis_options(&scales);
+        coord.update(Rect {
+            pos: dvec2(0.0, 0.0),
+            size: dvec2(400.0, 300.0),
+        });
+        coord.set_y_data_range(0.0, 1000.0);
+        coord.set_y_axis_data_range(Some("rate"), 0.0, 10.0);
+        assert_eq!(coord.y_scale_for(Some("rate")).get_data_bounds(), (0.0, 10.0));
+        assert_eq!(coord.y_scale_for(Some("other")).get_data_bounds(), (0.0, 1000.0));
+
+        // Reversed on its own
+        let area = coord.chart_area().clone();
+        assert_eq!(coord.y_scale_for(Some("rate")).get_pixel_for_value(0.0), area.top);
+        assert_eq!(coord.y_scale_for(None).get_pixel_for_value(0.0), area.bottom);
+
+        // Zooming the default axis shows the same part of the others
+        coord.set_y_view(Some((250.0, 500.0)));
+        assert_eq!(coord.y_scale_for(Some("rate")).get_data_bounds(), (2.5, 5.0));
+        coord.set_y_view(None);
+        assert_eq!(coord.y_scale_for(Some("rate")).get_data_bounds(), (0.0, 10.0));
+
+        coord.set_y_axis_offset(Some("rate"), 30.0);
+        let axes = coord.y_axes(&scales);
+        assert_eq!(axes.len(), 2);
+        assert_eq!((axes[0].position, axes[0].line_start.x), (AxisPosition::Left, area.left));
+        assert_eq!(axes[1].id.as_deref(), Some("rate"));
+        assert_eq!((axes[1].position, axes[1].line_start.x), (AxisPosition::Right, area.right + 30.0));
+    }
+
+    #[test]
+    fn test_points_in_rect_on_extra_y_axis() {
+        let scales = ChartOptions::new().with_y_axis("rate", AxisPosition::Right, "Rate").scales;
+        let mut coord = CartesianCoord::new()
+            .with_y_scale(ScaleType::Linear(LinearScale::new()))
+            .with_y_axes(&scales);
+        coord.apply_axis_options(&scales);
+        coord.update(Rect {
+            pos: dvec2(0.0, 0.0),
+            size: dvec2(400.0, 300.0),
+        });
+        coord.set_y_data_range(0.0, 1000.0);
+        coord.set_y_axis_data_range(Some("rate"), 0.0, 10.0);
+        assert_eq!(coord.y_on_default_axis(5.0, Some("rate")), 500.0);
+        assert_eq!(coord.y_on_default_axis(5.0, None), 5.0);
+
+        let data = ChartData::new()
+            .add_dataset(Dataset::new("Total").with_data(vec![5.0, 450.0]))
+            .add_dataset(Dataset::new("Rate").with_data(vec![5.0, 1.0]).with_y_axis_id("rate"))
+            .add_dataset(Dataset::new("More").with_data(vec![1.0, 2.0]).with_y_axis_id("rate"));
+        let stacks = stack_values(&data, &scales, StackMode::Absolute);
+        let select = |stacks: &[Vec<Option<(f64, f64)>>], y_range| {
+            data.points_in_rect((f64::NEG_INFINITY, f64::INFINITY), y_range, |_, i| i as f64, |d, i, y| {
+                let plotted = match stacks.get(d).and_then(|stack| stack.get(i)) {
+                    Some(Some((_, top))) => *top,
+                    _ => y,
+                };
+                coord.y_on_default_axis(plotted, scales.y_axis_of(&data.datasets[d]))
+            })
+        };
+
+        // A rate of 5 is drawn where the default axis shows 500, and 1 where it shows 100
+        assert_eq!(select(&[], (400.0, 600.0)), vec![(0, 1), (1, 0)]);
+        assert_eq!(select(&[], (0.0, 150.0)), vec![(0, 0), (1, 1), (2, 0)]);
+        // Stacked on the rate axis, "More" is drawn at 6 and 3
+        assert_eq!(select(&stacks, (250.0, 650.0)), vec![(0, 1), (1, 0), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_format_value() {
+        let category = ScaleType::Category(CategoryScale::new().with_labels(vec!["Jan".into(), "Feb".into()]));
+        assert_eq!(category.format_value(0.8), "Feb");
+        assert_eq!(category.format_value(5.0), "");
+
+        let linear = ScaleType::Linear(LinearScale::new());
+        assert_eq!(linear.format_value(42.0), format_number(42.0));
+    }
 }
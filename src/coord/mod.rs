@@ -1,5 +1,9 @@
+mod annotation;
+mod bar_ruler;
 mod cartesian;
 mod polar;
 
+pub use annotation::*;
+pub use bar_ruler::*;
 pub use cartesian::*;
 pub use polar::*;
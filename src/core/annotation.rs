@@ -0,0 +1,254 @@
+use makepad_widgets::*;
+use super::types::PointStyle;
+
+/// Whether an annotation is drawn under or over the datasets
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AnnotationDrawTime {
+    BeforeDatasets,
+    #[default]
+    AfterDatasets,
+}
+
+/// Shape of an annotation in data coordinates. X values are category indices on
+/// category axes; a missing bound reaches the edge of the chart area.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnnotationKind {
+    /// Line from `(x_min, y_min)` to `(x_max, y_max)`
+    Line { x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64> },
+    /// Rectangle between the bounds
+    Box { x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64> },
+    /// Ellipse inside the bounds
+    Ellipse { x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64> },
+    /// Marker of `radius` pixels at a point
+    Point { x: f64, y: f64, radius: f64, style: PointStyle },
+    /// The annotation's label alone, centered on a point
+    Label { x: f64, y: f64 },
+}
+
+/// A line, box, ellipse, point or label drawn on a cartesian chart, mirroring
+/// the Chart.js annotation plugin
+#[derive(Clone, Debug)]
+pub struct Annotation {
+    /// Id reported by annotation actions
+    pub id: String,
+    pub kind: AnnotationKind,
+    /// Y axis the Y values are on (None = the default axis)
+    pub y_axis_id: Option<String>,
+    /// Fill of boxes, ellipses and points
+    pub color: Vec4,
+    /// Color of lines and of the outline of boxes, ellipses and points
+    pub border_color: Vec4,
+    /// Width of lines and outlines (0.0 = no outline)
+    pub border_width: f64,
+    /// Dash and gap lengths of lines and outlines in pixels (empty = solid)
+    pub border_dash: Vec<f64>,
+    /// Text drawn at the center of the annotation, above points
+    pub label: Option<String>,
+    pub label_color: Vec4,
+    /// Fill behind the label text
+    pub label_background: Vec4,
+    pub label_font_size: f64,
+    pub draw_time: AnnotationDrawTime,
+    /// Widen the Y axis to show the annotation's Y values
+    pub adjust_scale_range: bool,
+}
+
+impl Annotation {
+    pub fn new(kind: AnnotationKind) -> Self {
+        Self {
+            id: String::new(),
+            kind,
+            y_axis_id: None,
+            color: vec4(0.4, 0.6, 0.9, 0.15),
+            border_color: vec4(0.4, 0.6, 0.9, 0.8),
+            border_width: 0.0,
+            border_dash: Vec::new(),
+            label: None,
+            label_color: vec4(0.2, 0.2, 0.2, 1.0),
+            label_background: vec4(0.0, 0.0, 0.0, 0.0),
+            label_font_size: 11.0,
+            draw_time: AnnotationDrawTime::AfterDatasets,
+            adjust_scale_range: false,
+        }
+    }
+
+    /// Line across the chart area at `y`, such as a target
+    pub fn horizontal_line(y: f64) -> Self {
+        Self::line_between(None, None, Some(y), Some(y))
+    }
+
+    /// Line across the chart area at `x`, such as an event marker
+    pub fn vertical_line(x: f64) -> Self {
+        Self::line_between(Some(x), Some(x), None, None)
+    }
+
+    /// Line between two points
+    pub fn line(from: (f64, f64), to: (f64, f64)) -> Self {
+        Self::line_between(Some(from.0), Some(to.0), Some(from.1), Some(to.1))
+    }
+
+    fn line_between(x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64>) -> Self {
+        Self {
+            border_width: 2.0,
+            ..Self::new(AnnotationKind::Line { x_min, x_max, y_min, y_max })
+        }
+    }
+
+    /// Box between the bounds; `None` reaches the edge of the chart area
+    pub fn rect(x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64>) -> Self {
+        Self::new(AnnotationKind::Box { x_min, x_max, y_min, y_max })
+    }
+
+    /// Box over the whole height of the chart between two X values, such as a time window
+    pub fn x_range(x_min: f64, x_max: f64) -> Self {
+        Self::rect(Some(x_min), Some(x_max), None, None)
+    }
+
+    /// Box over the whole width of the chart between two Y values, such as a band
+    pub fn y_range(y_min: f64, y_max: f64) -> Self {
+        Self::rect(None, None, Some(y_min), Some(y_max))
+    }
+
+    /// Ellipse inside the bounds; `None` reaches the edge of the chart area
+    pub fn ellipse(x_min: Option<f64>, x_max: Option<f64>, y_min: Option<f64>, y_max: Option<f64>) -> Self {
+        Self::new(AnnotationKind::Ellipse { x_min, x_max, y_min, y_max })
+    }
+
+    /// Circle marker of `radius` pixels at a point
+    pub fn point(x: f64, y: f64, radius: f64) -> Self {
+        Self {
+            color: vec4(0.4, 0.6, 0.9, 0.8),
+            border_color: vec4(1.0, 1.0, 1.0, 1.0),
+            border_width: 1.0,
+            ..Self::new(AnnotationKind::Point { x, y, radius, style: PointStyle::Circle })
+        }
+    }
+
+    /// Text centered on a point
+    pub fn label(x: f64, y: f64, text: impl Into<String>) -> Self {
+        Self {
+            label: Some(text.into()),
+            ..Self::new(AnnotationKind::Label { x, y })
+        }
+    }
+
+    /// Set the id reported by annotation actions
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Put the Y values on the Y axis `id`
+    pub fn with_y_axis_id(mut self, id: impl Into<String>) -> Self {
+        self.y_axis_id = Some(id.into());
+        self
+    }
+
+    /// Set the fill of boxes, ellipses and points
+    pub fn with_color(mut self, color: Vec4) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the color and width of lines and outlines
+    pub fn with_border(mut self, color: Vec4, width: f64) -> Self {
+        self.border_color = color;
+        self.border_width = width;
+        self
+    }
+
+    /// Dash lines and outlines with alternating dash and gap lengths in pixels
+    pub fn with_border_dash(mut self, dash: Vec<f64>) -> Self {
+        self.border_dash = dash;
+        self
+    }
+
+    /// Draw `text` at the center of the annotation
+    pub fn with_label(mut self, text: impl Into<String>) -> Self {
+        self.label = Some(text.into());
+        self
+    }
+
+    /// Set the label text color and the fill behind it
+    pub fn with_label_colors(mut self, color: Vec4, background: Vec4) -> Self {
+        self.label_color = color;
+        self.label_background = background;
+        self
+    }
+
+    pub fn with_draw_time(mut self, draw_time: AnnotationDrawTime) -> Self {
+        self.draw_time = draw_time;
+        self
+    }
+
+    /// Widen the Y axis to show the annotation
+    pub fn with_adjust_scale_range(mut self, adjust: bool) -> Self {
+        self.adjust_scale_range = adjust;
+        self
+    }
+
+    /// Y values the annotation is placed at
+    pub fn y_values(&self) -> Vec<f64> {
+        match self.kind {
+            AnnotationKind::Line { y_min, y_max, .. }
+            | AnnotationKind::Box { y_min, y_max, .. }
+            | AnnotationKind::Ellipse { y_min, y_max, .. } => y_min.into_iter().chain(y_max).collect(),
+            AnnotationKind::Point { y, .. } | AnnotationKind::Label { y, .. } => vec![y],
+        }
+    }
+}
+
+/// Range of the Y values of the annotations on the Y axis `y_axis_id` that adjust the
+/// scale range, `None` when there are none; `axis_of` maps an annotation's
+/// `y_axis_id` to the axis it is drawn on
+pub fn annotation_y_extent(
+    annotations: &[Annotation],
+    y_axis_id: Option<&str>,
+    axis_of: impl Fn(Option<&str>) -> Option<&str>,
+) -> Option<(f64, f64)> {
+    annotations
+        .iter()
+        .filter(|annotation| annotation.adjust_scale_range && axis_of(annotation.y_axis_id.as_deref()) == y_axis_id)
+        .flat_map(|annotation| annotation.y_values())
+        .filter(|y| y.is_finite())
+        .fold(None, |extent, y| Some(extent.map_or((y, y), |(min, max): (f64, f64)| (min.min(y), max.max(y)))))
+}
+
+/// Smallest range covering both extents
+pub fn merge_extents(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some((a_min, a_max)), Some((b_min, b_max))) => Some((a_min.min(b_min), a_max.max(b_max))),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotation_y_extent() {
+        let annotations = vec![
+            Annotation::horizontal_line(120.0).with_adjust_scale_range(true),
+            Annotation::y_range(-5.0, 10.0).with_adjust_scale_range(true),
+            Annotation::point(1.0, 500.0, 4.0),
+            Annotation::label(0.0, 7.0, "rate").with_y_axis_id("rate").with_adjust_scale_range(true),
+            Annotation::vertical_line(3.0).with_adjust_scale_range(true),
+        ];
+        fn known(id: Option<&str>) -> Option<&str> {
+            id.filter(|id| *id == "rate")
+        }
+        assert_eq!(annotation_y_extent(&annotations, None, known), Some((-5.0, 120.0)));
+        assert_eq!(annotation_y_extent(&annotations, Some("rate"), known), Some((7.0, 7.0)));
+        // Annotations on unknown axes are drawn on the default one
+        assert_eq!(annotation_y_extent(&annotations, None, |_| None), Some((-5.0, 120.0)));
+        assert_eq!(annotation_y_extent(&annotations[2..3], None, known), None);
+    }
+
+    #[test]
+    fn test_merge_extents() {
+        assert_eq!(merge_extents(Some((0.0, 10.0)), Some((-2.0, 5.0))), Some((-2.0, 10.0)));
+        assert_eq!(merge_extents(None, Some((1.0, 2.0))), Some((1.0, 2.0)));
+        assert_eq!(merge_extents(None, None), None);
+    }
+}
@@ -116,6 +116,18 @@ pub fn lerp_color(from: Vec4, to: Vec4, t: f32) -> Vec4 {
     )
 }
 
+/// Dark or white text, whichever reads better on `background`; translucent
+/// backgrounds are taken over white
+pub fn contrast_color(background: Vec4) -> Vec4 {
+    let over_white = |c: f32| c * background.w + 1.0 - background.w;
+    let luminance = 0.2126 * over_white(background.x) + 0.7152 * over_white(background.y) + 0.0722 * over_white(background.z);
+    if luminance > 0.5 {
+        vec4(0.1, 0.1, 0.1, 1.0)
+    } else {
+        vec4(1.0, 1.0, 1.0, 1.0)
+    }
+}
+
 /// Create a color gradient from a list of colors
 pub struct ColorGradient {
     colors: Vec<Vec4>,
@@ -204,6 +216,15 @@ mod tests {
         assert!((black.x - 0.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_contrast_color() {
+        let white = vec4(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(contrast_color(vec4(0.1, 0.2, 0.6, 1.0)), white);
+        assert_ne!(contrast_color(vec4(1.0, 0.9, 0.3, 1.0)), white);
+        // Barely visible dark fill over white
+        assert_ne!(contrast_color(vec4(0.0, 0.0, 0.0, 0.1)), white);
+    }
+
     #[test]
     fn test_color_gradient() {
         let gradient = ColorGradient::new(vec![
@@ -1,23 +1,21 @@
 use makepad_widgets::*;
 use super::colors::get_color;
+use super::options::DataLabelOptions;
+use super::types::{BarThickness, BorderSkipped, CornerRadius, FillTarget, LineCap, LineJoin, PointStyle, SpanGaps};
 
-/// Point styles for scatter/line charts
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub enum DataPointStyle {
-    #[default]
-    Circle,
-    Square,
-    Triangle,
-    Cross,
-    Diamond,
-}
+/// Former name of [`PointStyle`], kept for source compatibility
+#[deprecated(note = "use PointStyle")]
+pub type DataPointStyle = PointStyle;
+
+/// `bar_percentage` or `category_percentage` of a dataset that leaves it to the chart
+pub const UNSET_PERCENTAGE: f64 = 0.0;
 
 /// Single data point with x, y coordinates and optional metadata
 #[derive(Clone, Debug, Default)]
 pub struct DataPoint {
     /// X coordinate value (None means use index)
     pub x: Option<f64>,
-    /// Y coordinate value (top of bar for floating bars)
+    /// Y coordinate value (top of bar for floating bars), NaN for a missing value
     pub y: f64,
     /// Y minimum value for floating bars (base of bar, None means use 0)
     pub y_min: Option<f64>,
@@ -54,6 +52,17 @@ impl DataPoint {
         }
     }
 
+    /// Create a missing value, e.g. a sensor dropout. Lines break or bridge it
+    /// per `Dataset::span_gaps` and it is left out of scales, tooltips and hit testing.
+    pub fn missing() -> Self {
+        Self::from_y(f64::NAN)
+    }
+
+    /// Whether the Y value is missing
+    pub fn is_missing(&self) -> bool {
+        self.y.is_nan()
+    }
+
     /// Create a floating bar data point with min and max values
     pub fn floating(y_min: f64, y_max: f64) -> Self {
         Self {
@@ -106,40 +115,76 @@ pub struct Dataset {
     pub border_width: f64,
     /// Whether this dataset is hidden
     pub hidden: bool,
+    /// Id of the Y axis in `ScalesOptions::y_axes` the dataset is drawn against,
+    /// the default Y axis when `None`
+    pub y_axis_id: Option<String>,
 
     // Line chart specific
-    /// Fill area under line
-    pub fill: bool,
+    /// What the area under the line is filled to
+    pub fill: FillTarget,
+    /// Fill color where the line is above its fill target (the line color when unset)
+    pub fill_above_color: Option<Vec4>,
+    /// Fill color where the line is below its fill target (the line color when unset)
+    pub fill_below_color: Option<Vec4>,
     /// Bezier curve tension (0 = straight lines, 1 = smooth)
     pub tension: f64,
+    /// How line segments meet
+    pub border_join_style: LineJoin,
+    /// How the ends of the line finish
+    pub border_cap_style: LineCap,
+    /// Alternating dash and gap lengths in pixels, empty for a solid line
+    pub border_dash: Vec<f64>,
+    /// Distance in pixels into the dash pattern where the line starts
+    pub border_dash_offset: f64,
+    /// Whether the line continues across missing values
+    pub span_gaps: SpanGaps,
 
     // Point options
     /// Point radius in pixels
     pub point_radius: f64,
     /// Point shape style
-    pub point_style: DataPointStyle,
+    pub point_style: PointStyle,
+    /// Point rotation in degrees
+    pub point_rotation: f64,
+    /// Image drawn for `PointStyle::Image` points
+    pub point_image: Option<Texture>,
     /// Point background color (defaults to dataset color)
     pub point_background_color: Option<Vec4>,
     /// Point border color
     pub point_border_color: Option<Vec4>,
-    /// Point border width
+    /// Point border width (0.0 = no border)
     pub point_border_width: f64,
     /// Hover point radius
     pub point_hover_radius: f64,
 
     // Bar chart specific
-    /// Fixed bar thickness (None = auto)
-    pub bar_thickness: Option<f64>,
-    /// Bar width as percentage of category (0-1)
+    /// Bar thickness along the category axis
+    pub bar_thickness: BarThickness,
+    /// Largest bar thickness in pixels, whatever `bar_thickness` asks for
+    pub max_bar_thickness: Option<f64>,
+    /// Bar width as percentage of its part of the category (0-1), the whole part when `UNSET_PERCENTAGE`
     pub bar_percentage: f64,
-    /// Category width as percentage of available space (0-1)
+    /// Category width as percentage of available space (0-1), the chart's default when `UNSET_PERCENTAGE`
     pub category_percentage: f64,
-    /// Bar border radius
-    pub bar_border_radius: f64,
+    /// Radii of the bar corners; corners on the skipped side stay square
+    pub bar_border_radius: CornerRadius,
+    /// Side of the bars left without a border (bars get a border when `border_color` is set)
+    pub border_skipped: BorderSkipped,
+    /// Stack the bars are stacked in; datasets without one share the default stack
+    pub stack: Option<String>,
 
     // Pie chart specific
     /// Offset when hovered
     pub hover_offset: f64,
+
+    /// Labels printed on the elements of the dataset (None = no labels)
+    pub data_labels: Option<DataLabelOptions>,
+
+    // Streaming
+    /// Maximum number of points kept when streaming; the oldest are dropped first
+    pub max_length: Option<usize>,
+    /// Span of X values kept when streaming, measured back from the newest point
+    pub time_window: Option<f64>,
 }
 
 impl Default for Dataset {
@@ -151,19 +196,35 @@ impl Default for Dataset {
             border_color: None,
             border_width: 1.0,
             hidden: false,
-            fill: false,
+            y_axis_id: None,
+            fill: FillTarget::None,
+            fill_above_color: None,
+            fill_below_color: None,
             tension: 0.0,
+            border_join_style: LineJoin::Miter,
+            border_cap_style: LineCap::Butt,
+            border_dash: Vec::new(),
+            border_dash_offset: 0.0,
+            span_gaps: SpanGaps::Never,
             point_radius: 3.0,
-            point_style: DataPointStyle::Circle,
+            point_style: PointStyle::Circle,
+            point_rotation: 0.0,
+            point_image: None,
             point_background_color: None,
             point_border_color: None,
             point_border_width: 1.0,
             point_hover_radius: 5.0,
-            bar_thickness: None,
-            bar_percentage: 0.9,
-            category_percentage: 0.8,
-            bar_border_radius: 0.0,
+            bar_thickness: BarThickness::Auto,
+            max_bar_thickness: None,
+            bar_percentage: UNSET_PERCENTAGE,
+            category_percentage: UNSET_PERCENTAGE,
+            bar_border_radius: CornerRadius::default(),
+            border_skipped: BorderSkipped::Start,
+            stack: None,
             hover_offset: 10.0,
+            data_labels: None,
+            max_length: None,
+            time_window: None,
         }
     }
 }
@@ -186,6 +247,15 @@ impl Dataset {
         self
     }
 
+    /// Set data from y values where `None` is a missing value (x = index)
+    pub fn with_optional_data(mut self, data: Vec<Option<f64>>) -> Self {
+        self.data = data
+            .into_iter()
+            .map(|y| y.map_or_else(DataPoint::missing, DataPoint::from_y))
+            .collect();
+        self
+    }
+
     /// Set data from (x, y) tuples
     pub fn with_xy_data(mut self, data: Vec<(f64, f64)>) -> Self {
         self.data = data
@@ -253,9 +323,23 @@ impl Dataset {
         self
     }
 
-    /// Enable area fill (for line charts)
-    pub fn with_fill(mut self, fill: bool) -> Self {
-        self.fill = fill;
+    /// Draw the dataset against the Y axis `id` from `ScalesOptions::y_axes`
+    pub fn with_y_axis_id(mut self, id: impl Into<String>) -> Self {
+        self.y_axis_id = Some(id.into());
+        self
+    }
+
+    /// Fill the area under the line (for line charts): `true` fills to the origin,
+    /// or pass a `FillTarget` such as `FillTarget::Relative(-1)` for a band to the previous dataset
+    pub fn with_fill(mut self, fill: impl Into<FillTarget>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Set the fill colors where the line is above and below its fill target
+    pub fn with_fill_colors(mut self, above: Vec4, below: Vec4) -> Self {
+        self.fill_above_color = Some(above);
+        self.fill_below_color = Some(below);
         self
     }
 
@@ -265,6 +349,36 @@ impl Dataset {
         self
     }
 
+    /// Set how line segments meet
+    pub fn with_border_join_style(mut self, join: LineJoin) -> Self {
+        self.border_join_style = join;
+        self
+    }
+
+    /// Set how the ends of the line finish
+    pub fn with_border_cap_style(mut self, cap: LineCap) -> Self {
+        self.border_cap_style = cap;
+        self
+    }
+
+    /// Dash the line with alternating dash and gap lengths in pixels, e.g. `vec![6.0, 4.0]`
+    pub fn with_border_dash(mut self, dash: Vec<f64>) -> Self {
+        self.border_dash = dash;
+        self
+    }
+
+    /// Start the dash pattern `offset` pixels in
+    pub fn with_border_dash_offset(mut self, offset: f64) -> Self {
+        self.border_dash_offset = offset;
+        self
+    }
+
+    /// Set whether the line continues across missing values
+    pub fn with_span_gaps(mut self, span_gaps: SpanGaps) -> Self {
+        self.span_gaps = span_gaps;
+        self
+    }
+
     /// Set point radius
     pub fn with_point_radius(mut self, radius: f64) -> Self {
         self.point_radius = radius;
@@ -272,23 +386,209 @@ impl Dataset {
     }
 
     /// Set point style
-    pub fn with_point_style(mut self, style: DataPointStyle) -> Self {
+    pub fn with_point_style(mut self, style: PointStyle) -> Self {
         self.point_style = style;
         self
     }
 
-    /// Set bar thickness
-    pub fn with_bar_thickness(mut self, thickness: f64) -> Self {
-        self.bar_thickness = Some(thickness);
+    /// Set point rotation in degrees
+    pub fn with_point_rotation(mut self, degrees: f64) -> Self {
+        self.point_rotation = degrees;
+        self
+    }
+
+    /// Set point background color
+    pub fn with_point_background_color(mut self, color: Vec4) -> Self {
+        self.point_background_color = Some(color);
+        self
+    }
+
+    /// Set point border color and width
+    pub fn with_point_border(mut self, color: Vec4, width: f64) -> Self {
+        self.point_border_color = Some(color);
+        self.point_border_width = width;
+        self
+    }
+
+    /// Draw points as `image`, switching the point style to `PointStyle::Image`
+    pub fn with_point_image(mut self, image: Texture) -> Self {
+        self.point_style = PointStyle::Image;
+        self.point_image = Some(image);
+        self
+    }
+
+    /// Set bar thickness, in pixels or a `BarThickness` such as `BarThickness::Flex`
+    pub fn with_bar_thickness(mut self, thickness: impl Into<BarThickness>) -> Self {
+        self.bar_thickness = thickness.into();
+        self
+    }
+
+    /// Limit the bar thickness to `thickness` pixels
+    pub fn with_max_bar_thickness(mut self, thickness: f64) -> Self {
+        self.max_bar_thickness = Some(thickness);
+        self
+    }
+
+    /// Set the bar width as a share of its part of the category (0-1)
+    pub fn with_bar_percentage(mut self, percentage: f64) -> Self {
+        self.bar_percentage = percentage;
+        self
+    }
+
+    /// Set the share of the category taken by the bars at it (0-1)
+    pub fn with_category_percentage(mut self, percentage: f64) -> Self {
+        self.category_percentage = percentage;
+        self
+    }
+
+    /// Set bar border radius, the same for every corner or a `CornerRadius`
+    pub fn with_bar_border_radius(mut self, radius: impl Into<CornerRadius>) -> Self {
+        self.bar_border_radius = radius.into();
+        self
+    }
+
+    /// Set which side of the bars has no border and square corners
+    pub fn with_border_skipped(mut self, skipped: BorderSkipped) -> Self {
+        self.border_skipped = skipped;
+        self
+    }
+
+    /// Put the bars in the stack `stack`; bars in different stacks sit side by side
+    pub fn with_stack(mut self, stack: impl Into<String>) -> Self {
+        self.stack = Some(stack.into());
+        self
+    }
+
+    /// Print labels on the elements of the dataset
+    pub fn with_data_labels(mut self, options: DataLabelOptions) -> Self {
+        self.data_labels = Some(options);
+        self
+    }
+
+    /// Keep at most `max_length` points when streaming
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
         self
     }
 
-    /// Set bar border radius
-    pub fn with_bar_border_radius(mut self, radius: f64) -> Self {
-        self.bar_border_radius = radius;
+    /// Keep only points whose `x` lies within `window` of the newest point when streaming
+    pub fn with_time_window(mut self, window: f64) -> Self {
+        self.time_window = Some(window);
         self
     }
 
+    /// Whether the line from the point at `from` to the point at `to` continues across
+    /// the missing values between them, with `x_value` giving a point's X value from
+    /// its `x` and index. Each run of missing values is bridged on its own, so a
+    /// `MaxDistance` is measured between the points right around it.
+    pub fn spans<F>(&self, from: usize, to: usize, x_value: F) -> bool
+    where
+        F: Fn(Option<f64>, usize) -> f64,
+    {
+        let mut gap_start = None;
+        for index in from + 1..=to {
+            let Some(point) = self.data.get(index) else {
+                break;
+            };
+            match (point.is_missing(), gap_start) {
+                (true, None) => gap_start = Some(index),
+                (false, Some(start)) => {
+                    gap_start = None;
+                    let bridged = match self.span_gaps {
+                        SpanGaps::Never => false,
+                        SpanGaps::Always => true,
+                        SpanGaps::MaxDistance(distance) => {
+                            let before = start - 1;
+                            (x_value(point.x, index) - x_value(self.data[before].x, before)).abs() <= distance
+                        }
+                    };
+                    if !bridged {
+                        return false;
+                    }
+                }
+                _ => {}
+            }
+        }
+        true
+    }
+
+    /// Split `indices`, the data indices of drawn points in order, into the runs
+    /// joined by one line each. Missing values are dropped and break the line
+    /// where `spans` says so; indices past the data are kept.
+    pub fn line_runs<F>(&self, indices: &[usize], x_value: F) -> Vec<Vec<usize>>
+    where
+        F: Fn(Option<f64>, usize) -> f64,
+    {
+        let mut runs: Vec<Vec<usize>> = Vec::new();
+        let mut previous: Option<usize> = None;
+        for &index in indices {
+            if self.data.get(index).is_some_and(|point| point.is_missing()) {
+                continue;
+            }
+            match (previous, runs.last_mut()) {
+                (Some(from), Some(run)) if self.spans(from, index, &x_value) => run.push(index),
+                _ => runs.push(vec![index]),
+            }
+            previous = Some(index);
+        }
+        runs
+    }
+
+    /// Runs of the closed line through indices `0..count`, such as a radar outline, with
+    /// the index as X. Returns one closed run when nothing breaks the line, otherwise open
+    /// runs where the run crossing from the last index back to the first is joined up.
+    pub fn closed_line_runs(&self, count: usize) -> Vec<(Vec<usize>, bool)> {
+        let indices: Vec<usize> = (0..count).collect();
+        let mut runs = self.line_runs(&indices, |_, index| index as f64);
+        let (Some(first), Some(last)) = (
+            runs.first().and_then(|run| run.first()).copied(),
+            runs.last().and_then(|run| run.last()).copied(),
+        ) else {
+            return Vec::new();
+        };
+
+        // Across the wrap the gap is whatever is missing after the last value and before the first
+        let wraps = first + count - last == 1 || match self.span_gaps {
+            SpanGaps::Never => false,
+            SpanGaps::Always => true,
+            SpanGaps::MaxDistance(distance) => (first + count - last) as f64 <= distance,
+        };
+        if wraps && runs.len() == 1 {
+            return runs.into_iter().map(|run| (run, true)).collect();
+        }
+        if wraps {
+            let tail = runs.pop().unwrap_or_default();
+            runs[0].splice(0..0, tail);
+        }
+        runs.into_iter().map(|run| (run, false)).collect()
+    }
+
+    /// Append a point and drop the oldest points that no longer fit the max length
+    /// or time window. Returns the number of dropped points.
+    pub fn push_point(&mut self, point: DataPoint) -> usize {
+        self.data.push(point);
+        self.trim()
+    }
+
+    /// Drop the oldest points beyond the max length or time window, moving the kept
+    /// points to the front in one pass. Returns the number of dropped points.
+    pub fn trim(&mut self) -> usize {
+        let mut dropped = 0;
+        if let Some(max_length) = self.max_length {
+            dropped = self.data.len().saturating_sub(max_length);
+        }
+        if let (Some(window), Some(newest)) = (self.time_window, self.data.last().and_then(|p| p.x)) {
+            let start = newest - window;
+            let outside = self.data[dropped..]
+                .iter()
+                .take_while(|p| p.x.is_some_and(|x| x < start))
+                .count();
+            dropped += outside;
+        }
+        self.data.drain(..dropped);
+        dropped
+    }
+
     /// Get the effective background color (with fallback)
     pub fn get_background_color(&self, index: usize) -> Vec4 {
         self.background_color.unwrap_or_else(|| get_color(index))
@@ -302,6 +602,16 @@ impl Dataset {
             vec4(bg.x * 0.8, bg.y * 0.8, bg.z * 0.8, bg.w)
         })
     }
+
+    /// Get the effective point fill color (with fallback to the dataset color)
+    pub fn get_point_background_color(&self, index: usize) -> Vec4 {
+        self.point_background_color.unwrap_or_else(|| self.get_background_color(index))
+    }
+
+    /// Get the effective point border color (with fallback to the dataset border)
+    pub fn get_point_border_color(&self, index: usize) -> Vec4 {
+        self.point_border_color.unwrap_or_else(|| self.get_border_color(index))
+    }
 }
 
 /// Complete chart data container
@@ -311,6 +621,8 @@ pub struct ChartData {
     pub labels: Vec<String>,
     /// Datasets to render
     pub datasets: Vec<Dataset>,
+    /// Data indices hidden through the legend of charts that list labels (pie, polar area)
+    pub hidden_indices: Vec<usize>,
 }
 
 impl ChartData {
@@ -339,14 +651,20 @@ impl ChartData {
 
     /// Get min/max Y values across all visible datasets
     pub fn get_y_extent(&self) -> Option<(f64, f64)> {
+        self.get_y_extent_where(|_, _| true)
+    }
+
+    /// Get min/max Y values across the visible datasets for which `include`,
+    /// called with the dataset index, returns true
+    pub fn get_y_extent_where(&self, include: impl Fn(usize, &Dataset) -> bool) -> Option<(f64, f64)> {
         let mut min = f64::MAX;
         let mut max = f64::MIN;
 
-        for dataset in &self.datasets {
-            if dataset.hidden {
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            if dataset.hidden || !include(index, dataset) {
                 continue;
             }
-            for point in &dataset.data {
+            for point in dataset.data.iter().filter(|p| !p.is_missing()) {
                 // Consider y_min for floating bars
                 if let Some(y_min) = point.y_min {
                     min = min.min(y_min);
@@ -370,6 +688,36 @@ impl ChartData {
         Some((min, max))
     }
 
+    /// Smallest positive Y value across the visible datasets for which `include`
+    /// returns true, where logarithmic axes start when the data reaches zero
+    pub fn get_min_positive_y_where(&self, include: impl Fn(usize, &Dataset) -> bool) -> Option<f64> {
+        self.datasets
+            .iter()
+            .enumerate()
+            .filter(|(index, dataset)| !dataset.hidden && include(*index, dataset))
+            .flat_map(|(_, dataset)| dataset.data.iter().filter(|p| !p.is_missing()))
+            .flat_map(|point| point.y_min.into_iter().chain([point.y]))
+            .filter(|y| *y > 0.0)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Smallest positive X value across the visible datasets (the index when `x` is
+    /// unset), where logarithmic X axes start when the data reaches zero
+    pub fn get_min_positive_x(&self) -> Option<f64> {
+        self.get_x_values().into_iter().filter(|x| *x > 0.0).min_by(f64::total_cmp)
+    }
+
+    /// Get the X value of every point in visible datasets (the index when `x` is unset)
+    pub fn get_x_values(&self) -> Vec<f64> {
+        self.datasets
+            .iter()
+            .filter(|dataset| !dataset.hidden)
+            .flat_map(|dataset| {
+                dataset.data.iter().enumerate().map(|(idx, point)| point.x.unwrap_or(idx as f64))
+            })
+            .collect()
+    }
+
     /// Get min/max X values across all visible datasets
     pub fn get_x_extent(&self) -> Option<(f64, f64)> {
         let mut min = f64::MAX;
@@ -424,6 +772,89 @@ impl ChartData {
         self.datasets.is_empty() || self.datasets.iter().all(|d| d.data.is_empty())
     }
 
+    /// Append a point to a dataset, see [`Dataset::push_point`].
+    /// Returns the number of points dropped from that dataset.
+    pub fn push_point(&mut self, dataset_index: usize, point: DataPoint) -> usize {
+        match self.datasets.get_mut(dataset_index) {
+            Some(dataset) => dataset.push_point(point),
+            None => 0,
+        }
+    }
+
+    /// Append category labels for streamed points. When every visible dataset has a
+    /// max length, the oldest labels beyond the longest one are dropped; a visible
+    /// dataset without one still shows its oldest points, so every label is kept.
+    /// Returns the number of dropped labels.
+    pub fn push_labels<S: Into<String>>(&mut self, labels: Vec<S>) -> usize {
+        self.labels.extend(labels.into_iter().map(|l| l.into()));
+        let limits: Option<Vec<usize>> = self.datasets.iter().filter(|d| !d.hidden).map(|d| d.max_length).collect();
+        let Some(limit) = limits.and_then(|limits| limits.into_iter().max()) else {
+            return 0;
+        };
+        let dropped = self.labels.len().saturating_sub(limit);
+        self.labels.drain(..dropped);
+        dropped
+    }
+
+    /// `(dataset_index, data_index)` of every point in visible datasets whose X value,
+    /// given by `x_value` from the point's `x` and index, and Y value, given by `y_value`
+    /// from the point's dataset index, index and `y`, fall inside the inclusive ranges.
+    /// Sorted by dataset, then by index.
+    pub fn points_in_rect<F, G>(&self, x_range: (f64, f64), y_range: (f64, f64), x_value: F, y_value: G) -> Vec<(usize, usize)>
+    where
+        F: Fn(Option<f64>, usize) -> f64,
+        G: Fn(usize, usize, f64) -> f64,
+    {
+        let inside = |value: f64, (min, max): (f64, f64)| value >= min && value <= max;
+        let mut indices = Vec::new();
+        for (dataset_index, dataset) in self.datasets.iter().enumerate() {
+            if dataset.hidden {
+                continue;
+            }
+            for (data_index, point) in dataset.data.iter().enumerate() {
+                if point.is_missing() {
+                    continue;
+                }
+                let y = y_value(dataset_index, data_index, point.y);
+                if inside(x_value(point.x, data_index), x_range) && inside(y, y_range) {
+                    indices.push((dataset_index, data_index));
+                }
+            }
+        }
+        indices
+    }
+
+    /// What the area of a dataset is filled to, using `fallback` for datasets without a
+    /// fill target. Dataset targets come back as `FillTarget::Dataset` with an absolute index;
+    /// a hidden target passes on its own target like Chart.js does. `None` when there is
+    /// nothing to fill to.
+    pub fn resolve_fill(&self, dataset_index: usize, fallback: FillTarget) -> Option<FillTarget> {
+        let target_of = |index: usize| match self.datasets.get(index).map(|d| d.fill) {
+            Some(FillTarget::None) => fallback,
+            Some(fill) => fill,
+            None => FillTarget::None,
+        };
+        let mut seen = vec![dataset_index];
+        let (mut index, mut target) = (dataset_index, target_of(dataset_index));
+        loop {
+            let next = match target {
+                FillTarget::None => return None,
+                FillTarget::Dataset(next) => next,
+                FillTarget::Relative(offset) => index.checked_add_signed(offset)?,
+                level => return Some(level),
+            };
+            let dataset = self.datasets.get(next)?;
+            if seen.contains(&next) {
+                return None;
+            }
+            if !dataset.hidden {
+                return Some(FillTarget::Dataset(next));
+            }
+            seen.push(next);
+            (index, target) = (next, target_of(next));
+        }
+    }
+
     /// Get number of visible datasets
     pub fn visible_dataset_count(&self) -> usize {
         self.datasets.iter().filter(|d| !d.hidden).count()
@@ -442,6 +873,20 @@ impl ChartData {
             dataset.hidden = !visible;
         }
     }
+
+    /// Whether the data index is shown by charts that list labels
+    pub fn is_data_visible(&self, index: usize) -> bool {
+        !self.hidden_indices.contains(&index)
+    }
+
+    /// Toggle visibility of a data index across all datasets
+    pub fn toggle_data_visibility(&mut self, index: usize) {
+        if let Some(pos) = self.hidden_indices.iter().position(|&i| i == index) {
+            self.hidden_indices.remove(pos);
+        } else {
+            self.hidden_indices.push(index);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -472,6 +917,17 @@ mod tests {
         assert_eq!(dataset.data[2].y, 30.0);
     }
 
+    #[test]
+    fn test_point_style_options() {
+        let dataset = Dataset::new("Test").with_point_rotation(45.0);
+        assert_eq!(dataset.point_style, PointStyle::Circle);
+        assert_eq!(dataset.point_rotation, 45.0);
+        assert!(dataset.point_image.is_none());
+        assert!(!PointStyle::Star.is_stroked());
+        assert!(PointStyle::CrossRot.is_stroked());
+        assert_ne!(PointStyle::Star.shader_id(), PointStyle::Image.shader_id());
+    }
+
     #[test]
     fn test_chart_data_extent() {
         let data = ChartData::new()
@@ -480,6 +936,96 @@ mod tests {
         let (min, max) = data.get_y_extent().unwrap();
         assert_eq!(min, 10.0);
         assert_eq!(max, 50.0);
+
+        let zeros = ChartData::new()
+            .add_dataset(Dataset::new("Test").with_data(vec![0.0, 1000.0, 10.0, -5.0]));
+        assert_eq!(zeros.get_min_positive_y_where(|_, _| true), Some(10.0));
+        assert_eq!(zeros.get_min_positive_y_where(|_, _| false), None);
+        assert_eq!(zeros.get_min_positive_x(), Some(1.0));
+
+        let xy = ChartData::new()
+            .add_dataset(Dataset::new("Test").with_xy_data(vec![(0.0, 1.0), (0.5, 2.0), (-2.0, 3.0)]));
+        assert_eq!(xy.get_min_positive_x(), Some(0.5));
+    }
+
+    #[test]
+    fn test_missing_values_skipped_in_extent() {
+        let data = ChartData::new()
+            .add_dataset(Dataset::new("Test").with_optional_data(vec![Some(10.0), None, Some(30.0)]));
+
+        assert!(data.datasets[0].data[1].is_missing());
+        assert_eq!(data.get_y_extent(), Some((10.0, 30.0)));
+        let all = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(data.points_in_rect((0.0, 2.0), all, |_, i| i as f64, |_, _, y| y).len(), 2);
+    }
+
+    #[test]
+    fn test_line_runs_span_gaps() {
+        let values = vec![Some(1.0), Some(2.0), None, Some(4.0), None, None, None, Some(8.0)];
+        let dataset = Dataset::new("Test").with_optional_data(values);
+        let indices: Vec<usize> = (0..dataset.data.len()).collect();
+        let x = |_: Option<f64>, i: usize| i as f64;
+
+        assert_eq!(dataset.line_runs(&indices, x), vec![vec![0, 1], vec![3], vec![7]]);
+
+        let always = dataset.clone().with_span_gaps(SpanGaps::Always);
+        assert_eq!(always.line_runs(&indices, x), vec![vec![0, 1, 3, 7]]);
+
+        // The one-point gap is bridged, the three-point one is not
+        let short = dataset.clone().with_span_gaps(SpanGaps::MaxDistance(2.0));
+        assert_eq!(short.line_runs(&indices, x), vec![vec![0, 1, 3], vec![7]]);
+
+        // Decimated indices still break at the gaps they skip over
+        assert_eq!(dataset.line_runs(&[0, 3, 7], x), vec![vec![0], vec![3], vec![7]]);
+    }
+
+    #[test]
+    fn test_closed_line_runs() {
+        let full = Dataset::new("Test").with_data(vec![1.0, 2.0, 3.0]);
+        assert_eq!(full.closed_line_runs(3), vec![(vec![0, 1, 2], true)]);
+
+        // The run after the gap continues across the wrap into the first one
+        let gap = Dataset::new("Test").with_optional_data(vec![Some(1.0), None, Some(3.0), Some(4.0)]);
+        assert_eq!(gap.closed_line_runs(4), vec![(vec![2, 3, 0], false)]);
+
+        let spanned = gap.clone().with_span_gaps(SpanGaps::Always);
+        assert_eq!(spanned.closed_line_runs(4), vec![(vec![0, 2, 3], true)]);
+
+        // Missing values at the end break the wrap instead
+        let end = Dataset::new("Test").with_optional_data(vec![Some(1.0), Some(2.0), Some(3.0), None]);
+        assert_eq!(end.closed_line_runs(4), vec![(vec![0, 1, 2], false)]);
+        let bridged = end.with_span_gaps(SpanGaps::MaxDistance(2.0));
+        assert_eq!(bridged.closed_line_runs(4), vec![(vec![0, 1, 2], true)]);
+    }
+
+    #[test]
+    fn test_resolve_fill() {
+        let mut data = ChartData::new()
+            .add_dataset(Dataset::new("Low").with_fill(FillTarget::Start))
+            .add_dataset(Dataset::new("Mid").with_fill(FillTarget::Relative(-1)))
+            .add_dataset(Dataset::new("High").with_fill(FillTarget::Dataset(1)))
+            .add_dataset(Dataset::new("Plain"));
+
+        assert_eq!(data.resolve_fill(0, FillTarget::None), Some(FillTarget::Start));
+        assert_eq!(data.resolve_fill(1, FillTarget::None), Some(FillTarget::Dataset(0)));
+        assert_eq!(data.resolve_fill(3, FillTarget::None), None);
+        assert_eq!(data.resolve_fill(3, FillTarget::Origin), Some(FillTarget::Origin));
+
+        // A hidden target passes on its own target
+        data.datasets[1].hidden = true;
+        assert_eq!(data.resolve_fill(2, FillTarget::None), Some(FillTarget::Dataset(0)));
+        data.datasets[0].hidden = true;
+        assert_eq!(data.resolve_fill(2, FillTarget::None), Some(FillTarget::Start));
+
+        // Targets outside the datasets or pointing back in a loop fill nothing
+        let mut looped = ChartData::new()
+            .add_dataset(Dataset::new("A").with_fill(FillTarget::Dataset(1)))
+            .add_dataset(Dataset::new("B").with_fill(FillTarget::Dataset(0)));
+        looped.datasets[1].hidden = true;
+        assert_eq!(looped.resolve_fill(0, FillTarget::None), None);
+        data.datasets[3].fill = FillTarget::Relative(-4);
+        assert_eq!(data.resolve_fill(3, FillTarget::None), None);
+        assert_eq!(Dataset::new("C").with_fill(true).fill, FillTarget::Origin);
     }
 
     #[test]
@@ -490,9 +1036,76 @@ mod tests {
         assert_eq!(data.get_total(), 60.0);
     }
 
+    #[test]
+    fn test_toggle_data_visibility() {
+        let mut data = ChartData::new().with_labels(vec!["A", "B"]);
+        assert!(data.is_data_visible(1));
+        data.toggle_data_visibility(1);
+        assert!(!data.is_data_visible(1));
+        assert!(data.is_data_visible(0));
+        data.toggle_data_visibility(1);
+        assert!(data.is_data_visible(1));
+    }
+
     #[test]
     fn test_empty_data_handling() {
         let data = ChartData::new();
         assert!(data.get_y_extent().is_none());
     }
+
+    #[test]
+    fn test_push_point_max_length() {
+        let mut data = ChartData::new()
+            .add_dataset(Dataset::new("Live").with_data(vec![1.0, 2.0, 3.0]).with_max_length(3));
+
+        assert_eq!(data.push_point(0, DataPoint::from_y(4.0)), 1);
+        let values: Vec<f64> = data.datasets[0].data.iter().map(|p| p.y).collect();
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+
+        data.labels = vec!["a".into(), "b".into(), "c".into()];
+        assert_eq!(data.push_labels(vec!["d"]), 1);
+        assert_eq!(data.labels, vec!["b", "c", "d"]);
+
+        // A visible unbounded dataset keeps every label, a hidden one does not
+        data.datasets.push(Dataset::new("History").with_data(vec![1.0, 2.0]));
+        assert_eq!(data.push_labels(vec!["e"]), 0);
+        assert_eq!(data.labels, vec!["b", "c", "d", "e"]);
+        data.toggle_dataset(1);
+        assert_eq!(data.push_labels(vec!["f"]), 2);
+        assert_eq!(data.labels, vec!["d", "e", "f"]);
+        assert_eq!(ChartData::new().with_labels(vec!["a"]).push_labels(vec!["b"]), 0);
+    }
+
+    #[test]
+    fn test_push_point_time_window() {
+        let mut dataset = Dataset::new("Live")
+            .with_xy_data(vec![(0.0, 1.0), (5.0, 2.0), (9.0, 3.0)])
+            .with_time_window(10.0);
+
+        assert_eq!(dataset.push_point(DataPoint::new(12.0, 4.0)), 1);
+        assert_eq!(dataset.data.first().and_then(|p| p.x), Some(5.0));
+    }
+
+    #[test]
+    fn test_points_in_rect() {
+        let mut data = ChartData::new()
+            .add_dataset(Dataset::new("A").with_xy_data(vec![(1.0, 1.0), (2.0, 5.0), (3.0, 2.0)]))
+            .add_dataset(Dataset::new("B").with_data(vec![2.0, 3.0, 9.0]));
+        let x_value = |x: Option<f64>, index: usize| x.unwrap_or(index as f64);
+        let y_value = |_, _, y| y;
+
+        let indices = data.points_in_rect((1.5, 3.0), (0.0, 4.0), x_value, y_value);
+        assert_eq!(indices, vec![(0, 2)]);
+
+        let indices = data.points_in_rect((f64::NEG_INFINITY, f64::INFINITY), (2.0, 3.0), x_value, y_value);
+        assert_eq!(indices, vec![(0, 2), (1, 0), (1, 1)]);
+
+        // Y values are compared where they are plotted
+        let indices = data.points_in_rect((f64::NEG_INFINITY, f64::INFINITY), (4.0, 6.0), x_value, |d, _, y| y + d as f64 * 3.0);
+        assert_eq!(indices, vec![(0, 1), (1, 0), (1, 1)]);
+
+        data.toggle_dataset(0);
+        let indices = data.points_in_rect((0.0, 1.0), (f64::NEG_INFINITY, f64::INFINITY), x_value, y_value);
+        assert_eq!(indices, vec![(1, 0), (1, 1)]);
+    }
 }
@@ -0,0 +1,153 @@
+use makepad_widgets::*;
+use super::types::DecimationAlgorithm;
+
+/// Indices of the points worth drawing out of `points`, pixel positions in data
+/// order. Points outside the `left..right` pixel columns are dropped, keeping one
+/// on each side so lines still leave the chart area, and the rest are reduced
+/// with `algorithm` to about `samples` points (LTTB) or four per pixel column (min/max).
+pub fn decimate(points: &[DVec2], algorithm: DecimationAlgorithm, samples: usize, left: f64, right: f64) -> Vec<usize> {
+    let visible = visible_span(points, left, right);
+    let offset = visible.start;
+    let points = &points[visible];
+    let kept = match algorithm {
+        DecimationAlgorithm::Lttb => decimate_lttb(points, samples),
+        DecimationAlgorithm::MinMax => decimate_min_max(points),
+    };
+    kept.into_iter().map(|i| i + offset).collect()
+}
+
+/// Range of `points` between the `left` and `right` pixels plus one point on each side.
+/// Everything when the points do not go left to right.
+fn visible_span(points: &[DVec2], left: f64, right: f64) -> std::ops::Range<usize> {
+    if !points.windows(2).all(|w| w[0].x <= w[1].x) {
+        return 0..points.len();
+    }
+    let start = points.partition_point(|p| p.x < left).saturating_sub(1);
+    let end = (points.partition_point(|p| p.x <= right) + 1).min(points.len());
+    start..end.max(start)
+}
+
+/// Largest-Triangle-Three-Buckets: keep the first and last points and, from each of
+/// `samples - 2` buckets in between, the point making the largest triangle with the
+/// point kept before it and the average of the next bucket
+pub fn decimate_lttb(points: &[DVec2], samples: usize) -> Vec<usize> {
+    let n = points.len();
+    if samples < 3 || samples >= n {
+        return (0..n).collect();
+    }
+
+    let bucket_size = (n - 2) as f64 / (samples - 2) as f64;
+    let bucket_start = |bucket: usize| ((bucket as f64 * bucket_size) as usize + 1).min(n - 1);
+
+    let mut kept = Vec::with_capacity(samples);
+    kept.push(0);
+    let mut previous = points[0];
+
+    for bucket in 0..samples - 2 {
+        let (start, end) = (bucket_start(bucket), bucket_start(bucket + 1));
+
+        // The last bucket looks ahead at the last point
+        let next = &points[end..bucket_start(bucket + 2).max(end + 1).min(n)];
+        let average = next.iter().fold(dvec2(0.0, 0.0), |sum, p| sum + *p) * (1.0 / next.len() as f64);
+
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (i, p) in points.iter().enumerate().take(end).skip(start) {
+            // Twice the triangle area, the factor does not change the pick
+            let area = ((previous.x - average.x) * (p.y - previous.y) - (previous.x - p.x) * (average.y - previous.y)).abs();
+            if area > best_area {
+                best_area = area;
+                best = i;
+            }
+        }
+        kept.push(best);
+        previous = points[best];
+    }
+
+    kept.push(n - 1);
+    kept
+}
+
+/// Keep the first, lowest, highest and last point of every pixel column, which
+/// draws the same as all points while preserving every spike
+pub fn decimate_min_max(points: &[DVec2]) -> Vec<usize> {
+    let mut kept = Vec::new();
+    let mut i = 0;
+    while i < points.len() {
+        let column = points[i].x.floor();
+        let first = i;
+        let (mut min, mut max) = (i, i);
+        while i < points.len() && points[i].x.floor() == column {
+            if points[i].y < points[min].y {
+                min = i;
+            }
+            if points[i].y > points[max].y {
+                max = i;
+            }
+            i += 1;
+        }
+        let mut column_kept = [first, min, max, i - 1];
+        column_kept.sort_unstable();
+        for index in column_kept {
+            if kept.last() != Some(&index) {
+                kept.push(index);
+            }
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wave(n: usize) -> Vec<DVec2> {
+        (0..n).map(|i| dvec2(i as f64 * 0.1, (i as f64 * 0.37).sin() * 50.0)).collect()
+    }
+
+    #[test]
+    fn test_lttb_keeps_ends_and_sample_count() {
+        let points = wave(1000);
+        let kept = decimate_lttb(&points, 50);
+        assert_eq!(kept.len(), 50);
+        assert_eq!(kept.first(), Some(&0));
+        assert_eq!(kept.last(), Some(&999));
+        assert!(kept.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(decimate_lttb(&points[..10], 50), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_lttb_keeps_spike() {
+        let mut points: Vec<DVec2> = (0..100).map(|i| dvec2(i as f64, 0.0)).collect();
+        points[42].y = 100.0;
+        assert!(decimate_lttb(&points, 10).contains(&42));
+    }
+
+    #[test]
+    fn test_min_max_per_column() {
+        // Ten points per pixel column
+        let points = wave(1000);
+        let kept = decimate_min_max(&points);
+        assert!(kept.len() <= 400);
+        assert!(kept.windows(2).all(|w| w[0] < w[1]));
+
+        // Extremes of the first column survive
+        let column: Vec<usize> = (0..10).collect();
+        let max = column.iter().copied().max_by(|a, b| points[*a].y.total_cmp(&points[*b].y)).unwrap();
+        let min = column.iter().copied().min_by(|a, b| points[*a].y.total_cmp(&points[*b].y)).unwrap();
+        assert!(kept.contains(&0) && kept.contains(&9) && kept.contains(&max) && kept.contains(&min));
+    }
+
+    #[test]
+    fn test_decimate_drops_hidden_points() {
+        let points: Vec<DVec2> = (0..100).map(|i| dvec2(i as f64, 0.0)).collect();
+        let kept = decimate(&points, DecimationAlgorithm::MinMax, 0, 20.0, 30.0);
+        assert_eq!(kept, (19..=31).collect::<Vec<_>>());
+
+        // Not sorted by X: nothing is dropped before decimating
+        let mut shuffled = points.clone();
+        shuffled.swap(0, 99);
+        assert_eq!(decimate(&shuffled, DecimationAlgorithm::MinMax, 0, 20.0, 30.0).len(), 100);
+    }
+}
@@ -1,9 +1,15 @@
+mod annotation;
 mod data;
+mod decimation;
 mod options;
+mod stack;
 mod colors;
 mod types;
 
+pub use annotation::*;
 pub use data::*;
+pub use decimation::*;
 pub use options::*;
+pub use stack::*;
 pub use colors::*;
 pub use types::*;
@@ -1,5 +1,10 @@
 use makepad_widgets::*;
-use super::types::{ChartAlign, ChartPadding, LegendPosition, EasingType, InteractionMode, InteractionAxis};
+use super::annotation::Annotation;
+use super::data::{ChartData, Dataset};
+use super::types::{
+    AxisPosition, AxisType, ChartAlign, ChartPadding, DecimationAlgorithm, LegendPosition, EasingType, InteractionMode,
+    InteractionAxis, LabelAlign, LabelContent, LabelOverlap, StackMode, TimeDistribution, TimeUnit,
+};
 
 /// Main chart options
 #[derive(Clone, Debug)]
@@ -27,6 +32,17 @@ pub struct ChartOptions {
     pub interaction: InteractionOptions,
     /// Scale options (for cartesian charts)
     pub scales: ScalesOptions,
+    /// Zoom and pan options (for cartesian charts)
+    pub zoom: ZoomOptions,
+    /// Crosshair options (for line charts)
+    pub crosshair: CrosshairOptions,
+    /// Brush selection options (for scatter, line and bar charts)
+    pub brush: BrushOptions,
+    /// Data decimation options (for line charts)
+    pub decimation: DecimationOptions,
+    /// Lines, boxes, ellipses, points and labels drawn in data coordinates
+    /// (for line, bar, scatter and combo charts)
+    pub annotations: Vec<Annotation>,
 }
 
 impl Default for ChartOptions {
@@ -46,6 +62,11 @@ impl Default for ChartOptions {
             animation: AnimationOptions::default(),
             interaction: InteractionOptions::default(),
             scales: ScalesOptions::default(),
+            zoom: ZoomOptions::default(),
+            crosshair: CrosshairOptions::default(),
+            brush: BrushOptions::default(),
+            decimation: DecimationOptions::default(),
+            annotations: Vec::new(),
         }
     }
 }
@@ -70,6 +91,13 @@ impl ChartOptions {
         self
     }
 
+    /// Set title and subtitle alignment
+    pub fn with_title_align(mut self, align: ChartAlign) -> Self {
+        self.title.align = align;
+        self.subtitle.align = align;
+        self
+    }
+
     /// Configure legend
     pub fn with_legend(mut self, display: bool, position: LegendPosition) -> Self {
         self.legend.display = display;
@@ -83,6 +111,13 @@ impl ChartOptions {
         self
     }
 
+    /// Set which elements the tooltip lists and whether the cursor must be over one
+    pub fn with_tooltip_mode(mut self, mode: InteractionMode, intersect: bool) -> Self {
+        self.tooltip.mode = mode;
+        self.tooltip.intersect = intersect;
+        self
+    }
+
     /// Set animation duration in milliseconds
     pub fn with_animation_duration(mut self, duration: f64) -> Self {
         self.animation.duration = duration;
@@ -106,6 +141,111 @@ impl ChartOptions {
         self.scales.y.begin_at_zero = begin_at_zero;
         self
     }
+
+    /// Stack the datasets on the Y axis: `true` stacks them absolutely
+    pub fn with_stacked(mut self, stacked: impl Into<StackMode>) -> Self {
+        self.scales.y.stacked = stacked.into();
+        self
+    }
+
+    /// Keep the Y stack mode of `current` when these options leave it unset, so a
+    /// chart stacked with `set_stacked` stays stacked when its options are replaced
+    pub fn with_stacking_of(mut self, current: &ChartOptions) -> Self {
+        if self.scales.y.stacked == StackMode::None {
+            self.scales.y.stacked = current.scales.y.stacked;
+        }
+        self
+    }
+
+    /// Set the scale used by the X axis
+    pub fn with_x_axis_type(mut self, scale_type: AxisType) -> Self {
+        self.scales.x.scale_type = scale_type;
+        self
+    }
+
+    /// Set the scale used by the Y axis
+    pub fn with_y_axis_type(mut self, scale_type: AxisType) -> Self {
+        self.scales.y.scale_type = scale_type;
+        self
+    }
+
+    /// Enable zooming and panning along `mode` (X, Y or both axes)
+    pub fn with_zoom(mut self, mode: InteractionAxis) -> Self {
+        self.zoom.enabled = true;
+        self.zoom.mode = mode;
+        self
+    }
+
+    /// Show a crosshair with a vertical line (`X`), a horizontal line (`Y`) or both,
+    /// snapping to the nearest data index when `snap` is set
+    pub fn with_crosshair(mut self, mode: InteractionAxis, snap: bool) -> Self {
+        self.crosshair.enabled = true;
+        self.crosshair.mode = mode;
+        self.crosshair.snap = snap;
+        self
+    }
+
+    /// Select regions by dragging an X range, a Y range or a rectangle over the chart area
+    pub fn with_brush(mut self, mode: InteractionAxis) -> Self {
+        self.brush.enabled = true;
+        self.brush.mode = mode;
+        self
+    }
+
+    /// Draw large line datasets through `algorithm` instead of every point
+    pub fn with_decimation(mut self, algorithm: DecimationAlgorithm) -> Self {
+        self.decimation.enabled = true;
+        self.decimation.algorithm = algorithm;
+        self
+    }
+
+    /// Zoom options as applied by charts that brush: dragging selects instead of
+    /// panning or zooming into a rectangle while the brush is enabled
+    pub fn effective_zoom(&self) -> ZoomOptions {
+        let mut zoom = self.zoom.clone();
+        if self.brush.enabled {
+            zoom.pan = false;
+            zoom.drag = false;
+        }
+        zoom
+    }
+
+    /// Set X axis title text
+    pub fn with_x_axis_title(mut self, text: impl Into<String>) -> Self {
+        self.scales.x.title.display = true;
+        self.scales.x.title.text = text.into();
+        self
+    }
+
+    /// Set Y axis title text
+    pub fn with_y_axis_title(mut self, text: impl Into<String>) -> Self {
+        self.scales.y.title.display = true;
+        self.scales.y.title.text = text.into();
+        self
+    }
+
+    /// Add a Y axis with the id `id` on the `position` side of the chart area,
+    /// titled `title` unless it is empty. Datasets are drawn against it with
+    /// `Dataset::with_y_axis_id`; change its options through `scales.y_axes`.
+    /// Like in Chart.js only the default axis draws grid lines across the chart
+    /// area, so the new axis keeps just its tick marks.
+    pub fn with_y_axis(mut self, id: impl Into<String>, position: AxisPosition, title: impl Into<String>) -> Self {
+        let mut axis = AxisOptions {
+            position: Some(position),
+            ..Default::default()
+        };
+        axis.grid.draw_on_chart_area = false;
+        axis.title.text = title.into();
+        axis.title.display = !axis.title.text.is_empty();
+        self.scales.y_axes.push((id.into(), axis));
+        self
+    }
+
+    /// Add an annotation, drawn after the ones added before it
+    pub fn with_annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
 }
 
 /// Title and subtitle options
@@ -243,6 +383,75 @@ impl Default for TooltipOptions {
     }
 }
 
+/// Data label options of a dataset, for values printed on its bars, points or slices
+#[derive(Clone, Debug)]
+pub struct DataLabelOptions {
+    /// Point of the element the label is placed from: its base, center or end
+    pub anchor: ChartAlign,
+    /// Direction the label moves away from the anchor
+    pub align: LabelAlign,
+    /// Distance in pixels between the anchor and the label, unless centered on it
+    pub offset: f64,
+    /// Rotation in degrees, counter-clockwise
+    pub rotation: f64,
+    /// What the label shows
+    pub content: LabelContent,
+    /// Writes the label text instead of `content`
+    pub formatter: Option<fn(&DataLabelContext) -> String>,
+    /// Text color; `None` contrasts with the fill under the label
+    pub color: Option<Vec4>,
+    /// Font size
+    pub font_size: f64,
+    /// What happens to a label overlapping another
+    pub overlap: LabelOverlap,
+}
+
+impl Default for DataLabelOptions {
+    fn default() -> Self {
+        Self {
+            anchor: ChartAlign::Center,
+            align: LabelAlign::Center,
+            offset: 4.0,
+            rotation: 0.0,
+            content: LabelContent::Value,
+            formatter: None,
+            color: None,
+            font_size: 11.0,
+            overlap: LabelOverlap::Hide,
+        }
+    }
+}
+
+/// Data element a label is written for, passed to `DataLabelOptions::formatter`
+#[derive(Clone, Debug, Default)]
+pub struct DataLabelContext {
+    pub dataset_index: usize,
+    pub data_index: usize,
+    /// Data value
+    pub value: f64,
+    /// Share of the total (0-1), on pie and doughnut slices
+    pub percentage: Option<f64>,
+    /// Category label
+    pub label: String,
+}
+
+impl DataLabelContext {
+    /// Context of the point at `data_index` of the dataset at `dataset_index`
+    pub fn of(data: &ChartData, dataset_index: usize, data_index: usize) -> Self {
+        let point = data.datasets.get(dataset_index).and_then(|dataset| dataset.data.get(data_index));
+        Self {
+            dataset_index,
+            data_index,
+            value: point.map_or(0.0, |point| point.y),
+            percentage: None,
+            label: point
+                .and_then(|point| point.label.clone())
+                .or_else(|| data.labels.get(data_index).cloned())
+                .unwrap_or_default(),
+        }
+    }
+}
+
 /// Animation options
 #[derive(Clone, Debug)]
 pub struct AnimationOptions {
@@ -314,6 +523,195 @@ impl Default for InteractionOptions {
     }
 }
 
+/// Zoom and pan options
+#[derive(Clone, Debug)]
+pub struct ZoomOptions {
+    /// Enable zooming and panning
+    pub enabled: bool,
+    /// Axes that zoom and pan
+    pub mode: InteractionAxis,
+    /// Zoom with the mouse wheel
+    pub wheel: bool,
+    /// Fraction of the visible range zoomed per wheel step
+    pub wheel_speed: f64,
+    /// Zoom with a two finger pinch
+    pub pinch: bool,
+    /// Pan by dragging the chart area
+    pub pan: bool,
+    /// Zoom into a rectangle dragged over the chart area; hold shift to draw it when `pan` is on
+    pub drag: bool,
+    /// Fill color of the zoom rectangle
+    pub drag_color: Vec4,
+    /// Border color of the zoom rectangle
+    pub drag_border_color: Vec4,
+    /// Reset the zoom on double click
+    pub double_click_reset: bool,
+    /// Limits for the X axis
+    pub x_limits: ZoomLimits,
+    /// Limits for the Y axis
+    pub y_limits: ZoomLimits,
+}
+
+impl Default for ZoomOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: InteractionAxis::XY,
+            wheel: true,
+            wheel_speed: 0.1,
+            pinch: true,
+            pan: true,
+            drag: false,
+            drag_color: vec4(0.4, 0.6, 0.9, 0.2),
+            drag_border_color: vec4(0.4, 0.6, 0.9, 0.8),
+            double_click_reset: true,
+            x_limits: ZoomLimits::default(),
+            y_limits: ZoomLimits::default(),
+        }
+    }
+}
+
+/// Crosshair options
+#[derive(Clone, Debug)]
+pub struct CrosshairOptions {
+    /// Show the crosshair while the pointer is over the chart area
+    pub enabled: bool,
+    /// Lines drawn: vertical for `X`, horizontal for `Y`, or both
+    pub mode: InteractionAxis,
+    /// Snap to the nearest data index instead of following the pointer
+    pub snap: bool,
+    /// Line color
+    pub color: Vec4,
+    /// Line width
+    pub line_width: f64,
+    /// Show the values under the crosshair on the axes
+    pub show_labels: bool,
+    /// Value label font size
+    pub font_size: f64,
+    /// Value label text color
+    pub label_color: Vec4,
+    /// Value label background color
+    pub label_background: Vec4,
+}
+
+impl Default for CrosshairOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: InteractionAxis::XY,
+            snap: true,
+            color: vec4(0.4, 0.4, 0.4, 0.8),
+            line_width: 1.0,
+            show_labels: true,
+            font_size: 11.0,
+            label_color: vec4(1.0, 1.0, 1.0, 1.0),
+            label_background: vec4(0.3, 0.3, 0.3, 0.9),
+        }
+    }
+}
+
+/// Brush selection options
+#[derive(Clone, Debug)]
+pub struct BrushOptions {
+    /// Select a region by dragging over the chart area
+    pub enabled: bool,
+    /// Region dragged: an X range for `X`, a Y range for `Y`, or a rectangle
+    pub mode: InteractionAxis,
+    /// Fill color of the selected region
+    pub color: Vec4,
+    /// Border color of the selected region
+    pub border_color: Vec4,
+    /// Border dash and gap lengths in pixels, empty for a solid border
+    pub border_dash: Vec<f64>,
+    /// Pixels per second the border dash pattern moves around the region,
+    /// for a "marching ants" outline
+    pub border_dash_speed: f64,
+    /// Alpha multiplier for elements outside the selection
+    pub dimmed_alpha: f32,
+}
+
+impl Default for BrushOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: InteractionAxis::XY,
+            color: vec4(0.4, 0.6, 0.9, 0.15),
+            border_color: vec4(0.4, 0.6, 0.9, 0.8),
+            border_dash: Vec::new(),
+            border_dash_speed: 0.0,
+            dimmed_alpha: 0.25,
+        }
+    }
+}
+
+/// Data decimation options
+#[derive(Clone, Debug, Default)]
+pub struct DecimationOptions {
+    /// Reduce the points drawn and hit tested for datasets above the threshold
+    pub enabled: bool,
+    /// Algorithm picking the points to keep
+    pub algorithm: DecimationAlgorithm,
+    /// Points kept per dataset by LTTB; the chart area width in pixels when unset
+    pub samples: Option<usize>,
+    /// Datasets with more points than this are decimated; four times `samples` when unset
+    pub threshold: Option<usize>,
+}
+
+impl DecimationOptions {
+    /// Samples and threshold for a chart area `width` pixels wide
+    pub fn limits(&self, width: f64) -> (usize, usize) {
+        let samples = self.samples.unwrap_or(width.max(0.0) as usize).max(3);
+        (samples, self.threshold.unwrap_or(samples * 4))
+    }
+}
+
+/// Bounds the visible range of one axis is kept within while zooming and panning.
+/// Values are data values, or category positions on category axes.
+#[derive(Clone, Debug, Default)]
+pub struct ZoomLimits {
+    /// Lowest value that can be shown
+    pub min: Option<f64>,
+    /// Highest value that can be shown
+    pub max: Option<f64>,
+    /// Smallest range that can be zoomed into
+    pub min_range: Option<f64>,
+}
+
+impl ZoomLimits {
+    /// Fit the range `min..max` inside the limits, keeping its span where possible
+    pub fn clamp(&self, min: f64, max: f64) -> (f64, f64) {
+        let (mut min, mut max) = (min.min(max), min.max(max));
+
+        if let Some(min_range) = self.min_range {
+            if max - min < min_range {
+                let center = (min + max) / 2.0;
+                min = center - min_range / 2.0;
+                max = center + min_range / 2.0;
+            }
+        }
+
+        let span = max - min;
+        if let (Some(lo), Some(hi)) = (self.min, self.max) {
+            if span >= hi - lo {
+                return (lo, hi);
+            }
+        }
+        if let Some(lo) = self.min {
+            if min < lo {
+                min = lo;
+                max = lo + span;
+            }
+        }
+        if let Some(hi) = self.max {
+            if max > hi {
+                max = hi;
+                min = hi - span;
+            }
+        }
+        (min, max)
+    }
+}
+
 /// Combined scales options for X and Y axes
 #[derive(Clone, Debug, Default)]
 pub struct ScalesOptions {
@@ -321,11 +719,39 @@ pub struct ScalesOptions {
     pub x: AxisOptions,
     /// Y axis options
     pub y: AxisOptions,
+    /// More Y axes by id, each with its own scale, ticks and title. Datasets are
+    /// drawn against one of them with `Dataset::y_axis_id`, and against `y` otherwise.
+    pub y_axes: Vec<(String, AxisOptions)>,
+}
+
+impl ScalesOptions {
+    /// Ids of the Y axes: `None` for the default `y` axis, then the ids of `y_axes`
+    pub fn y_axis_ids(&self) -> impl Iterator<Item = Option<&str>> {
+        std::iter::once(None).chain(self.y_axes.iter().map(|(id, _)| Some(id.as_str())))
+    }
+
+    /// Options of the Y axis `id`, the default `y` axis for `None` and unknown ids
+    pub fn y_axis(&self, id: Option<&str>) -> &AxisOptions {
+        id.and_then(|id| self.y_axes.iter().find(|(other, _)| other == id))
+            .map_or(&self.y, |(_, axis)| axis)
+    }
+
+    /// Id of the Y axis `dataset` is drawn against, `None` for the default axis
+    pub fn y_axis_of<'a>(&self, dataset: &'a Dataset) -> Option<&'a str> {
+        self.y_axis_id(dataset.y_axis_id.as_deref())
+    }
+
+    /// `id` if it names one of `y_axes`, `None` for the default axis and unknown ids
+    pub fn y_axis_id<'a>(&self, id: Option<&'a str>) -> Option<&'a str> {
+        id.filter(|id| self.y_axes.iter().any(|(other, _)| other == id))
+    }
 }
 
 /// Individual axis options
 #[derive(Clone, Debug)]
 pub struct AxisOptions {
+    /// Scale used by the axis
+    pub scale_type: AxisType,
     /// Display the axis
     pub display: bool,
     /// Axis title
@@ -346,13 +772,21 @@ pub struct AxisOptions {
     pub begin_at_zero: bool,
     /// Reverse the axis direction
     pub reverse: bool,
-    /// Stack values in this axis
-    pub stacked: bool,
+    /// Side of the chart area the axis is drawn on. Y axes can be on the `Left`,
+    /// the default, or on the `Right`; X axes are always below the chart area.
+    pub position: Option<AxisPosition>,
+    /// Stack the values of datasets along this axis
+    pub stacked: StackMode,
+    /// Time scale options, used when `scale_type` is `AxisType::Time`
+    pub time: TimeOptions,
+    /// Logarithm base, used when `scale_type` is `AxisType::Logarithmic`
+    pub log_base: f64,
 }
 
 impl Default for AxisOptions {
     fn default() -> Self {
         Self {
+            scale_type: AxisType::Auto,
             display: true,
             title: AxisTitleOptions::default(),
             grid: GridOptions::default(),
@@ -363,7 +797,84 @@ impl Default for AxisOptions {
             suggested_max: None,
             begin_at_zero: false,
             reverse: false,
-            stacked: false,
+            position: None,
+            stacked: StackMode::None,
+            time: TimeOptions::default(),
+            log_base: 10.0,
+        }
+    }
+}
+
+/// Time scale options
+#[derive(Clone, Debug)]
+pub struct TimeOptions {
+    /// Fixed tick unit; picked from the visible range when `None`
+    pub unit: Option<TimeUnit>,
+    /// Smallest unit considered when picking the tick unit
+    pub min_unit: TimeUnit,
+    /// Label patterns for each unit
+    pub display_formats: TimeDisplayFormats,
+    /// Offset from UTC in minutes applied to tick placement and labels
+    pub utc_offset_minutes: i32,
+    /// How data is spread along the axis
+    pub distribution: TimeDistribution,
+}
+
+impl Default for TimeOptions {
+    fn default() -> Self {
+        Self {
+            unit: None,
+            min_unit: TimeUnit::Second,
+            display_formats: TimeDisplayFormats::default(),
+            utc_offset_minutes: 0,
+            distribution: TimeDistribution::Linear,
+        }
+    }
+}
+
+/// Tick label patterns per time unit.
+///
+/// Patterns use `yyyy`, `yy`, `Q`, `MMMM`, `MMM`, `MM`, `M`, `dd`, `d`, `EEE`,
+/// `HH`, `H`, `hh`, `h`, `mm`, `ss` and `a`; text in single quotes is copied as is.
+#[derive(Clone, Debug)]
+pub struct TimeDisplayFormats {
+    pub second: String,
+    pub minute: String,
+    pub hour: String,
+    pub day: String,
+    pub week: String,
+    pub month: String,
+    pub quarter: String,
+    pub year: String,
+}
+
+impl TimeDisplayFormats {
+    /// Pattern for `unit`
+    pub fn get(&self, unit: TimeUnit) -> &str {
+        match unit {
+            TimeUnit::Second => &self.second,
+            TimeUnit::Minute => &self.minute,
+            TimeUnit::Hour => &self.hour,
+            TimeUnit::Day => &self.day,
+            TimeUnit::Week => &self.week,
+            TimeUnit::Month => &self.month,
+            TimeUnit::Quarter => &self.quarter,
+            TimeUnit::Year => &self.year,
+        }
+    }
+}
+
+impl Default for TimeDisplayFormats {
+    fn default() -> Self {
+        Self {
+            second: "HH:mm:ss".into(),
+            minute: "HH:mm".into(),
+            hour: "HH:mm".into(),
+            day: "MMM d".into(),
+            week: "MMM d".into(),
+            month: "MMM yyyy".into(),
+            quarter: "'Q'Q yyyy".into(),
+            year: "yyyy".into(),
         }
     }
 }
@@ -404,8 +915,18 @@ pub struct GridOptions {
     pub color: Vec4,
     /// Grid line width
     pub line_width: f64,
-    /// Draw border around chart area
+    /// Grid line dash and gap lengths in pixels, empty for solid lines
+    pub dash: Vec<f64>,
+    /// Distance in pixels into the grid line dash pattern where each line starts
+    pub dash_offset: f64,
+    /// Draw the axis line along the chart area
     pub draw_border: bool,
+    /// Axis line color
+    pub border_color: Vec4,
+    /// Axis line dash and gap lengths in pixels, empty for a solid line
+    pub border_dash: Vec<f64>,
+    /// Distance in pixels into the axis line dash pattern where it starts
+    pub border_dash_offset: f64,
     /// Draw grid lines in chart area
     pub draw_on_chart_area: bool,
     /// Draw tick marks on axis
@@ -420,7 +941,12 @@ impl Default for GridOptions {
             display: true,
             color: vec4(0.9, 0.9, 0.9, 1.0),
             line_width: 1.0,
+            dash: Vec::new(),
+            dash_offset: 0.0,
             draw_border: true,
+            border_color: vec4(0.7, 0.7, 0.7, 1.0),
+            border_dash: Vec::new(),
+            border_dash_offset: 0.0,
             draw_on_chart_area: true,
             draw_ticks: true,
             tick_length: 6.0,
@@ -485,11 +1011,92 @@ mod tests {
         let options = ChartOptions::new()
             .with_title("Test Chart")
             .with_legend(true, LegendPosition::Bottom)
-            .with_begin_at_zero(true);
+            .with_begin_at_zero(true)
+            .with_stacked(true);
 
         assert!(options.title.display);
         assert_eq!(options.title.text, "Test Chart");
         assert_eq!(options.legend.position, LegendPosition::Bottom);
         assert!(options.scales.y.begin_at_zero);
+        assert_eq!(options.scales.y.stacked, StackMode::Absolute);
+        assert_eq!(ChartOptions::new().with_stacked(StackMode::Percent).scales.y.stacked, StackMode::Percent);
+    }
+
+    #[test]
+    fn test_replaced_options_keep_stacking() {
+        // As after `set_stacked(StackMode::Absolute)` and then `set_options(...)`
+        let current = ChartOptions::new().with_stacked(StackMode::Absolute);
+        let replaced = ChartOptions::new().with_title("Sales").with_stacking_of(&current);
+        assert_eq!(replaced.scales.y.stacked, StackMode::Absolute);
+
+        // Options that stack themselves win
+        let percent = ChartOptions::new().with_stacked(StackMode::Percent).with_stacking_of(&current);
+        assert_eq!(percent.scales.y.stacked, StackMode::Percent);
+    }
+
+    #[test]
+    fn test_axis_title_builder() {
+        let options = ChartOptions::new()
+            .with_x_axis_title("Month")
+            .with_y_axis_title("Revenue");
+
+        assert!(options.scales.x.title.display);
+        assert_eq!(options.scales.x.title.text, "Month");
+        assert!(options.scales.y.title.display);
+        assert_eq!(options.scales.y.title.text, "Revenue");
+    }
+
+    #[test]
+    fn test_y_axes_by_id() {
+        let options = ChartOptions::new().with_y_axis("rate", AxisPosition::Right, "Conversion (%)");
+        let scales = &options.scales;
+        assert_eq!(scales.y_axis_ids().collect::<Vec<_>>(), vec![None, Some("rate")]);
+        assert_eq!(scales.y_axis(Some("rate")).position, Some(AxisPosition::Right));
+        assert!(scales.y_axis(Some("rate")).title.display);
+        assert!(!scales.y_axis(Some("rate")).grid.draw_on_chart_area && scales.y.grid.draw_on_chart_area);
+        assert_eq!(scales.y_axis(Some("other")).position, None);
+
+        let dataset = Dataset::new("Rate").with_y_axis_id("rate");
+        assert_eq!(scales.y_axis_of(&dataset), Some("rate"));
+        assert_eq!(scales.y_axis_of(&dataset.with_y_axis_id("other")), None);
+        assert_eq!(scales.y_axis_of(&Dataset::new("Revenue")), None);
+    }
+
+    #[test]
+    fn test_data_label_context() {
+        let data = ChartData::new()
+            .with_labels(vec!["Q1", "Q2"])
+            .add_dataset(Dataset::new("Sales").with_data(vec![3.0, 5.0]));
+        let context = DataLabelContext::of(&data, 0, 1);
+        assert_eq!((context.value, context.label.as_str(), context.percentage), (5.0, "Q2", None));
+        assert_eq!(DataLabelContext::of(&data, 1, 0).value, 0.0);
+    }
+
+    #[test]
+    fn test_zoom_limits_clamp() {
+        let limits = ZoomLimits {
+            min: Some(0.0),
+            max: Some(100.0),
+            min_range: Some(10.0),
+        };
+
+        // Panning past a limit keeps the span
+        assert_eq!(limits.clamp(-20.0, 30.0), (0.0, 50.0));
+        assert_eq!(limits.clamp(90.0, 120.0), (70.0, 100.0));
+        // Zooming in stops at the minimum range, zooming out at the limits
+        assert_eq!(limits.clamp(48.0, 50.0), (44.0, 54.0));
+        assert_eq!(limits.clamp(-50.0, 150.0), (0.0, 100.0));
+        assert_eq!(ZoomLimits::default().clamp(5.0, -5.0), (-5.0, 5.0));
+    }
+
+    #[test]
+    fn test_brush_takes_over_dragging() {
+        let options = ChartOptions::new().with_zoom(InteractionAxis::X);
+        assert!(options.effective_zoom().pan);
+
+        let options = options.with_brush(InteractionAxis::X);
+        let zoom = options.effective_zoom();
+        assert!(zoom.enabled && zoom.wheel);
+        assert!(!zoom.pan && !zoom.drag);
     }
 }
@@ -0,0 +1,149 @@
+use super::data::ChartData;
+use super::options::ScalesOptions;
+use super::types::StackMode;
+
+/// Stack of every dataset, numbered in order of first appearance among the visible
+/// datasets, and the number of stacks. Datasets with the same `stack` id on the same
+/// Y axis share a stack and datasets without one share the default stack of their
+/// axis; hidden datasets have none. Axis ids are resolved through `scales`, so
+/// datasets naming an unknown axis stack on the default one.
+pub fn stack_groups(data: &ChartData, scales: &ScalesOptions) -> (Vec<Option<usize>>, usize) {
+    let mut ids: Vec<(Option<&str>, Option<&str>)> = Vec::new();
+    let groups = data.datasets.iter().map(|dataset| {
+        if dataset.hidden {
+            return None;
+        }
+        let id = (scales.y_axis_of(dataset), dataset.stack.as_deref());
+        Some(ids.iter().position(|other| *other == id).unwrap_or_else(|| {
+            ids.push(id);
+            ids.len() - 1
+        }))
+    }).collect();
+    (groups, ids.len())
+}
+
+/// Where every value of the visible datasets sits when stacked, per dataset and
+/// data index, as `(base, top)` on the value axis. Values at the same index and in
+/// the same stack (see `stack_groups`) are stacked in dataset order, positive values
+/// up from zero and negative values down from it. `Percent` stacks each value's
+/// share of its stack's total at the index in percent. Hidden datasets and missing
+/// values have no place (`None`).
+pub fn stack_values(data: &ChartData, scales: &ScalesOptions, mode: StackMode) -> Vec<Vec<Option<(f64, f64)>>> {
+    let (groups, count) = stack_groups(data, scales);
+    let len = data.datasets.iter().filter(|d| !d.hidden).map(|d| d.data.len()).max().unwrap_or(0);
+
+    // Sum of the magnitudes at each index of each stack, for percentages
+    let mut totals = vec![vec![0.0; len]; count];
+    if mode == StackMode::Percent {
+        for (dataset, group) in data.datasets.iter().zip(&groups) {
+            let Some(group) = *group else { continue };
+            for (total, point) in totals[group].iter_mut().zip(&dataset.data) {
+                if !point.is_missing() {
+                    *total += point.y.abs();
+                }
+            }
+        }
+    }
+
+    let mut positive = vec![vec![0.0; len]; count];
+    let mut negative = vec![vec![0.0; len]; count];
+    data.datasets.iter().zip(&groups).map(|(dataset, group)| {
+        let Some(group) = *group else {
+            return vec![None; dataset.data.len()];
+        };
+        dataset.data.iter().enumerate().map(|(i, point)| {
+            if point.is_missing() {
+                return None;
+            }
+            let total = totals[group][i];
+            let value = match mode {
+                StackMode::Percent if total > 0.0 => point.y / total * 100.0,
+                StackMode::Percent => 0.0,
+                _ => point.y,
+            };
+            let running = if value < 0.0 { &mut negative[group][i] } else { &mut positive[group][i] };
+            let base = *running;
+            *running += value;
+            Some((base, *running))
+        }).collect()
+    }).collect()
+}
+
+/// Lowest and highest point of the stacks from `stack_values`, always including zero
+pub fn stack_extent<'a>(stacks: impl IntoIterator<Item = &'a Vec<Option<(f64, f64)>>>) -> Option<(f64, f64)> {
+    let mut places = stacks.into_iter().flatten().flatten().peekable();
+    places.peek()?;
+    Some(places.fold((0.0f64, 0.0f64), |(min, max), (base, top)| {
+        (min.min(*base).min(*top), max.max(*base).max(*top))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AxisPosition, ChartOptions, Dataset};
+
+    fn data() -> ChartData {
+        ChartData::new()
+            .add_dataset(Dataset::new("A").with_data(vec![10.0, 20.0, -5.0]))
+            .add_dataset(Dataset::new("B").with_optional_data(vec![Some(30.0), None, Some(-15.0)]))
+            .add_dataset(Dataset::new("C").with_data(vec![60.0, 20.0, 5.0]))
+    }
+
+    #[test]
+    fn test_stack_values_absolute() {
+        let stacks = stack_values(&data(), &ScalesOptions::default(), StackMode::Absolute);
+        assert_eq!(stacks[0], vec![Some((0.0, 10.0)), Some((0.0, 20.0)), Some((0.0, -5.0))]);
+        assert_eq!(stacks[1], vec![Some((10.0, 40.0)), None, Some((-5.0, -20.0))]);
+        // Positive values stack on the positives only
+        assert_eq!(stacks[2], vec![Some((40.0, 100.0)), Some((20.0, 40.0)), Some((0.0, 5.0))]);
+        assert_eq!(stack_extent(&stacks), Some((-20.0, 100.0)));
+    }
+
+    #[test]
+    fn test_stack_values_percent_and_hidden() {
+        let mut data = data();
+        data.datasets[1].hidden = true;
+        let stacks = stack_values(&data, &ScalesOptions::default(), StackMode::Percent);
+        assert_eq!(stacks[1], vec![None, None, None]);
+        let (base, top) = stacks[0][0].unwrap();
+        assert!(base == 0.0 && (top - 100.0 / 7.0).abs() < 1e-9);
+        assert_eq!(stacks[2][1], Some((50.0, 100.0)));
+        // Shares of the magnitudes, split by sign
+        assert_eq!(stacks[2][2], Some((0.0, 50.0)));
+        assert_eq!(stack_extent(&stacks), Some((-50.0, 100.0)));
+        assert_eq!(stack_extent(&stack_values(&ChartData::new(), &ScalesOptions::default(), StackMode::Percent)), None);
+    }
+
+    #[test]
+    fn test_stack_groups() {
+        let mut data = ChartData::new()
+            .add_dataset(Dataset::new("A").with_data(vec![10.0, -20.0]).with_stack("x"))
+            .add_dataset(Dataset::new("B").with_data(vec![30.0, 40.0]))
+            .add_dataset(Dataset::new("C").with_data(vec![5.0, -5.0]).with_stack("x"))
+            .add_dataset(Dataset::new("D").with_data(vec![1.0, 1.0]).with_stack("y"));
+        data.datasets[3].hidden = true;
+        let scales = ChartOptions::new().with_y_axis("y1", AxisPosition::Right, "Other").scales;
+        assert_eq!(stack_groups(&data, &scales), (vec![Some(0), Some(1), Some(0), None], 2));
+
+        // Stacks never span Y axes
+        let mut other_axis = data.clone();
+        other_axis.datasets[2].y_axis_id = Some("y1".into());
+        assert_eq!(stack_groups(&other_axis, &scales), (vec![Some(0), Some(1), Some(2), None], 3));
+
+        // An unknown axis id is the default axis
+        other_axis.datasets[2].y_axis_id = Some("y9".into());
+        assert_eq!(stack_groups(&other_axis, &scales), (vec![Some(0), Some(1), Some(0), None], 2));
+
+        // Each stack starts from zero
+        let stacks = stack_values(&data, &scales, StackMode::Absolute);
+        assert_eq!(stacks[1], vec![Some((0.0, 30.0)), Some((0.0, 40.0))]);
+        assert_eq!(stacks[2], vec![Some((10.0, 15.0)), Some((-20.0, -25.0))]);
+        assert_eq!(stack_extent(&stacks), Some((-25.0, 40.0)));
+
+        // Percentages are shares of the stack's own total
+        let stacks = stack_values(&data, &scales, StackMode::Percent);
+        assert_eq!(stacks[1][0], Some((0.0, 100.0)));
+        assert_eq!(stacks[2][1], Some((-80.0, -100.0)));
+    }
+}
@@ -89,6 +89,340 @@ pub enum AxisOrientation {
     Vertical,
 }
 
+/// Scale used by an axis
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisType {
+    /// The chart's natural scale (category for bar/line X axes, linear otherwise)
+    #[default]
+    Auto,
+    Linear,
+    Category,
+    /// Unix timestamps in seconds
+    Time,
+    /// Powers of `AxisOptions::log_base`, for positive data spanning orders of magnitude
+    Logarithmic,
+}
+
+/// Calendar unit used for time scale ticks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    #[default]
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// ISO weeks, starting on Monday
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TimeUnit {
+    /// All units from smallest to largest
+    pub const ALL: [TimeUnit; 8] = [
+        TimeUnit::Second,
+        TimeUnit::Minute,
+        TimeUnit::Hour,
+        TimeUnit::Day,
+        TimeUnit::Week,
+        TimeUnit::Month,
+        TimeUnit::Quarter,
+        TimeUnit::Year,
+    ];
+
+    /// Approximate length of the unit in seconds
+    pub fn seconds(&self) -> f64 {
+        match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => 60.0,
+            TimeUnit::Hour => 3_600.0,
+            TimeUnit::Day => 86_400.0,
+            TimeUnit::Week => 604_800.0,
+            TimeUnit::Month => 2_628_000.0,
+            TimeUnit::Quarter => 7_884_000.0,
+            TimeUnit::Year => 31_536_000.0,
+        }
+    }
+
+    /// Step multiples that read well for this unit
+    pub fn steps(&self) -> &'static [u32] {
+        match self {
+            TimeUnit::Second | TimeUnit::Minute => &[1, 2, 5, 10, 15, 30],
+            TimeUnit::Hour => &[1, 2, 3, 6, 12],
+            TimeUnit::Day => &[1, 2, 5],
+            TimeUnit::Week => &[1, 2, 4],
+            TimeUnit::Month => &[1, 2, 3],
+            TimeUnit::Quarter => &[1, 2],
+            TimeUnit::Year => &[1, 2, 5, 10, 20, 50, 100],
+        }
+    }
+}
+
+/// How a time scale spreads data along the axis, mirroring Chart.js `distribution`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TimeDistribution {
+    /// Position proportional to time
+    #[default]
+    Linear,
+    /// Data points equally spaced regardless of the time between them
+    Series,
+}
+
+/// How line datasets with many more points than pixels are reduced, mirroring Chart.js `decimation.algorithm`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DecimationAlgorithm {
+    /// Largest-Triangle-Three-Buckets: a fixed number of points that keep the shape of the line
+    #[default]
+    Lttb,
+    /// First, lowest, highest and last point of every pixel column, keeping every spike
+    MinMax,
+}
+
+/// Shape used to draw data points, mirroring Chart.js `pointStyle`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PointStyle {
+    #[default]
+    Circle,
+    /// Axis aligned square (Chart.js `rect`)
+    Square,
+    /// Square with rounded corners (Chart.js `rectRounded`)
+    RoundedSquare,
+    Triangle,
+    /// Square rotated by 45 degrees (Chart.js `rectRot`)
+    Diamond,
+    /// Filled five-pointed star
+    Star,
+    /// Plus sign, stroked
+    Cross,
+    /// Cross rotated by 45 degrees, stroked
+    CrossRot,
+    /// Horizontal line from the center to the right edge, stroked
+    Dash,
+    /// Horizontal line through the center, stroked
+    Line,
+    /// Dataset `point_image` texture stretched over the point box
+    Image,
+}
+
+impl PointStyle {
+    /// Shape id understood by the `DrawPoint` shader
+    pub fn shader_id(self) -> f32 {
+        match self {
+            PointStyle::Circle => 0.0,
+            PointStyle::Square => 1.0,
+            PointStyle::RoundedSquare => 2.0,
+            PointStyle::Triangle => 3.0,
+            PointStyle::Diamond => 4.0,
+            PointStyle::Star => 5.0,
+            PointStyle::Cross => 6.0,
+            PointStyle::CrossRot => 7.0,
+            PointStyle::Dash => 8.0,
+            PointStyle::Line => 9.0,
+            PointStyle::Image => 10.0,
+        }
+    }
+
+    /// Whether the shape is drawn as strokes only, without a fill
+    pub fn is_stroked(self) -> bool {
+        matches!(self, PointStyle::Cross | PointStyle::CrossRot | PointStyle::Dash | PointStyle::Line)
+    }
+}
+
+/// How stroked line segments meet, mirroring Chart.js `borderJoinStyle`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LineJoin {
+    /// Outer edges extended to a point, bevelled when the point gets too long
+    #[default]
+    Miter,
+    Round,
+    /// Outer corners connected by a straight edge
+    Bevel,
+}
+
+/// How the open ends of a stroked line finish, mirroring Chart.js `borderCapStyle`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LineCap {
+    /// Square end at the end point
+    #[default]
+    Butt,
+    Round,
+    /// Square end extended by half the line width
+    Square,
+}
+
+/// Side of the chart area an axis is drawn on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisPosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// How the values of datasets add up on a stacked value axis
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StackMode {
+    /// Every dataset is drawn from zero
+    #[default]
+    None,
+    /// Each dataset is drawn on top of the ones before it
+    Absolute,
+    /// Like `Absolute` with the values at each index scaled to add up to 100 percent
+    Percent,
+}
+
+impl From<bool> for StackMode {
+    fn from(stacked: bool) -> Self {
+        if stacked { StackMode::Absolute } else { StackMode::None }
+    }
+}
+
+/// What the area of a line dataset is filled to, mirroring Chart.js `fill`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FillTarget {
+    /// No area fill
+    #[default]
+    None,
+    /// Zero, or the nearest edge of the Y axis when zero is outside it
+    Origin,
+    /// The bottom (minimum) of the Y axis
+    Start,
+    /// The top (maximum) of the Y axis
+    End,
+    /// A fixed Y value
+    Value(f64),
+    /// The line of the dataset at this index
+    Dataset(usize),
+    /// The line of the dataset this many indices away, e.g. `-1` for the previous one
+    Relative(isize),
+}
+
+impl From<bool> for FillTarget {
+    fn from(fill: bool) -> Self {
+        if fill { FillTarget::Origin } else { FillTarget::None }
+    }
+}
+
+/// Whether lines continue across missing values, mirroring Chart.js `spanGaps`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SpanGaps {
+    /// Break the line at every missing value
+    #[default]
+    Never,
+    /// Join the points on either side of missing values
+    Always,
+    /// Join the points on either side of missing values up to this far apart on X
+    /// (data units, or indices on category axes); break the line at wider gaps
+    MaxDistance(f64),
+}
+
+/// Radii of the corners of a bar in pixels, mirroring Chart.js `borderRadius`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CornerRadius {
+    pub top_left: f64,
+    pub top_right: f64,
+    pub bottom_right: f64,
+    pub bottom_left: f64,
+}
+
+impl CornerRadius {
+    /// Same radius for every corner
+    pub fn all(radius: f64) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+impl From<f64> for CornerRadius {
+    fn from(radius: f64) -> Self {
+        Self::all(radius)
+    }
+}
+
+/// Thickness of bars along the category axis, mirroring Chart.js `barThickness`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BarThickness {
+    /// `bar_percentage` of the bar's share of `category_percentage` of the
+    /// smallest distance between neighbouring categories
+    #[default]
+    Auto,
+    /// Fixed thickness in pixels
+    Fixed(f64),
+    /// Like `Auto`, but from the distance to the bar's own neighbours, so bars at
+    /// uneven X values grow as wide as the gaps around them allow
+    Flex,
+}
+
+impl From<f64> for BarThickness {
+    fn from(thickness: f64) -> Self {
+        Self::Fixed(thickness)
+    }
+}
+
+/// Which side of a bar has no border and square corners, mirroring Chart.js `borderSkipped`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BorderSkipped {
+    /// Border and rounded corners all around
+    None,
+    /// The side the bar grows from, at its base value
+    #[default]
+    Start,
+    /// The side at the bar's value, opposite the base
+    End,
+    Left,
+    Top,
+    Right,
+    Bottom,
+    /// No border and no rounded corners at all
+    All,
+}
+
+/// Direction a data label moves away from its anchor, mirroring the Chart.js
+/// datalabels `align` option
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LabelAlign {
+    /// Centered on the anchor
+    #[default]
+    Center,
+    /// Back towards the element's base: down a positive bar, into a slice
+    Start,
+    /// Away from the element's base: above a positive bar, out of a slice
+    End,
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// What a data label shows when it has no formatter
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LabelContent {
+    /// The data value
+    #[default]
+    Value,
+    /// The share of the total, on pie and doughnut slices; the value elsewhere
+    Percentage,
+    /// The category label
+    Label,
+}
+
+/// What happens to a data label overlapping one drawn before it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LabelOverlap {
+    /// Leave it out
+    #[default]
+    Hide,
+    /// Move it clear of the others, leaving it out when there is no room
+    Nudge,
+    /// Draw it anyway
+    Show,
+}
+
 /// Chart padding structure
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ChartPadding {
@@ -0,0 +1,134 @@
+use makepad_widgets::*;
+
+/// Triangles covering the area between `line` and `boundary`, both polylines in pixels
+/// going one way along X, over the X range they share. Each triangle comes with whether
+/// the line is above the boundary there (smaller pixel Y); where they cross the area is
+/// split so either side can take its own color. Vertical steps are followed exactly.
+pub fn fill_between(line: &[DVec2], boundary: &[DVec2]) -> Vec<([DVec2; 3], bool)> {
+    if line.len() < 2 || boundary.len() < 2 {
+        return Vec::new();
+    }
+    let line = left_to_right(line);
+    let boundary = left_to_right(boundary);
+    let start = line[0].x.max(boundary[0].x);
+    let end = line[line.len() - 1].x.min(boundary[boundary.len() - 1].x);
+    if end <= start {
+        return Vec::new();
+    }
+
+    let mut xs: Vec<f64> = line.iter().chain(boundary.iter())
+        .map(|p| p.x)
+        .filter(|x| *x > start && *x < end)
+        .collect();
+    xs.push(start);
+    xs.push(end);
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+
+    let mut triangles = Vec::new();
+    for pair in xs.windows(2) {
+        let (x0, x1) = (pair[0], pair[1]);
+        let (a0, a1) = (right_of(&line, x0), left_of(&line, x1));
+        let (b0, b1) = (right_of(&boundary, x0), left_of(&boundary, x1));
+        let (d0, d1) = (a0 - b0, a1 - b1);
+        if d0 * d1 < 0.0 {
+            // Split where the line crosses the boundary
+            let t = d0 / (d0 - d1);
+            let x = x0 + (x1 - x0) * t;
+            let y = a0 + (a1 - a0) * t;
+            push_band(&mut triangles, x0, a0, b0, x, y, y);
+            push_band(&mut triangles, x, y, y, x1, a1, b1);
+        } else {
+            push_band(&mut triangles, x0, a0, b0, x1, a1, b1);
+        }
+    }
+    triangles
+}
+
+/// The points in order of increasing X
+fn left_to_right(points: &[DVec2]) -> Vec<DVec2> {
+    let mut points = points.to_vec();
+    if points[0].x > points[points.len() - 1].x {
+        points.reverse();
+    }
+    points
+}
+
+/// Y of the polyline arriving at `x` from the left, the top of a step at `x`
+fn left_of(points: &[DVec2], x: f64) -> f64 {
+    let i = points.partition_point(|p| p.x < x).clamp(1, points.len() - 1);
+    interpolate(points[i - 1], points[i], x)
+}
+
+/// Y of the polyline leaving `x` to the right, the bottom of a step at `x`
+fn right_of(points: &[DVec2], x: f64) -> f64 {
+    let i = points.partition_point(|p| p.x <= x).clamp(1, points.len() - 1);
+    interpolate(points[i - 1], points[i], x)
+}
+
+fn interpolate(a: DVec2, b: DVec2, x: f64) -> f64 {
+    if b.x - a.x <= f64::EPSILON {
+        return if x < b.x { a.y } else { b.y };
+    }
+    a.y + (b.y - a.y) * ((x - a.x) / (b.x - a.x)).clamp(0.0, 1.0)
+}
+
+/// Two triangles between the line and the boundary from `x0` to `x1`
+fn push_band(triangles: &mut Vec<([DVec2; 3], bool)>, x0: f64, a0: f64, b0: f64, x1: f64, a1: f64, b1: f64) {
+    if x1 - x0 <= f64::EPSILON {
+        return;
+    }
+    let above = a0 + a1 < b0 + b1;
+    triangles.push(([dvec2(x0, a0), dvec2(x1, a1), dvec2(x0, b0)], above));
+    triangles.push(([dvec2(x1, a1), dvec2(x1, b1), dvec2(x0, b0)], above));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Area covered by the triangles on each side
+    fn areas(triangles: &[([DVec2; 3], bool)]) -> (f64, f64) {
+        let mut sums = (0.0, 0.0);
+        for ([a, b, c], above) in triangles {
+            let area = ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0;
+            if *above {
+                sums.0 += area;
+            } else {
+                sums.1 += area;
+            }
+        }
+        sums
+    }
+
+    #[test]
+    fn test_fill_to_level() {
+        let line = [dvec2(0.0, 0.0), dvec2(10.0, 0.0)];
+        let triangles = fill_between(&line, &[dvec2(0.0, 10.0), dvec2(10.0, 10.0)]);
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(areas(&triangles), (100.0, 0.0));
+    }
+
+    #[test]
+    fn test_fill_splits_at_crossing() {
+        // Crosses the boundary halfway, above first
+        let line = [dvec2(0.0, 0.0), dvec2(10.0, 10.0)];
+        let boundary = [dvec2(0.0, 5.0), dvec2(10.0, 5.0)];
+        let (above, below) = areas(&fill_between(&line, &boundary));
+        assert!((above - 12.5).abs() < 1e-9 && (below - 12.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fill_shared_range_and_steps() {
+        // The boundary only covers the middle, and the line steps down at x = 5
+        let line = [dvec2(0.0, 0.0), dvec2(5.0, 0.0), dvec2(5.0, 20.0), dvec2(10.0, 20.0)];
+        let boundary = [dvec2(2.0, 10.0), dvec2(8.0, 10.0)];
+        let (above, below) = areas(&fill_between(&line, &boundary));
+        assert!((above - 30.0).abs() < 1e-9 && (below - 30.0).abs() < 1e-9);
+
+        // Either direction along X
+        let reversed: Vec<DVec2> = boundary.iter().rev().copied().collect();
+        assert_eq!(areas(&fill_between(&line, &reversed)), (above, below));
+        assert!(fill_between(&line, &[dvec2(20.0, 0.0), dvec2(30.0, 0.0)]).is_empty());
+    }
+}
@@ -1,22 +1,58 @@
 use makepad_widgets::*;
+use crate::core::{AxisPosition, BorderSkipped, CornerRadius};
 
 live_design! {
     use link::shaders::*;
 
     pub DrawBar = {{DrawBar}} {
+        // Signed distance from p to the edge of a size sized box at the origin
+        // with the corner radii clockwise from the top left, negative inside
+        fn box_distance(self, p: vec2, size: vec2, radius: vec4) -> float {
+            let half = size * 0.5;
+            let r = radius.x;
+            if p.x >= half.x {
+                r = radius.y;
+                if p.y >= half.y {
+                    r = radius.z;
+                }
+            } else {
+                if p.y >= half.y {
+                    r = radius.w;
+                }
+            }
+            let q = abs(p - half) - half + vec2(r, r);
+            return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0, 0.0))) - r;
+        }
+
         fn pixel(self) -> vec4 {
             let uv = self.pos;
 
             // Calculate final color with gradient support
+            let color = self.color;
             if self.gradient_enabled > 0.5 {
                 // Vertical gradient: bottom color at bottom, top color at top
                 // Note: UV y=0 is top, y=1 is bottom in screen space
                 let t = 1.0 - uv.y; // Invert so gradient goes bottom to top
-                let final_color = mix(self.gradient_bottom_color, self.gradient_top_color, t);
-                return vec4(final_color.rgb * final_color.a, final_color.a);
+                color = mix(self.gradient_bottom_color, self.gradient_top_color, t);
+            }
+
+            let p = uv * self.rect_size;
+            let outer = self.box_distance(p, self.rect_size, self.radius);
+
+            // The border lies between the outline and the bar shrunk by the border
+            // width on the bordered sides
+            if self.border_width > 0.0 {
+                let width = self.border_width;
+                let inset_start = vec2(self.border_sides.x, self.border_sides.y) * width;
+                let inset_end = vec2(self.border_sides.z, self.border_sides.w) * width;
+                let inner_size = max(self.rect_size - inset_start - inset_end, vec2(0.0, 0.0));
+                let inner_radius = max(self.radius - vec4(width, width, width, width), vec4(0.0, 0.0, 0.0, 0.0));
+                let inner = self.box_distance(p - inset_start, inner_size, inner_radius);
+                color = mix(color, self.border_color, clamp(0.5 + inner, 0.0, 1.0));
             }
 
-            return vec4(self.color.rgb * self.color.a, self.color.a);
+            let coverage = clamp(0.5 - outer, 0.0, 1.0);
+            return vec4(color.rgb * color.a, color.a) * coverage;
         }
     }
 }
@@ -32,6 +68,14 @@ pub struct DrawBar {
     #[live] pub gradient_bottom_color: Vec4,
     /// Top color for vertical gradient
     #[live] pub gradient_top_color: Vec4,
+    /// Corner radii in pixels, clockwise from the top left
+    #[live] pub radius: Vec4,
+    /// Border width in pixels, drawn inside the bar (0.0 = no border)
+    #[live(0.0)] pub border_width: f32,
+    /// Border color
+    #[live] pub border_color: Vec4,
+    /// Sides with a border: left, top, right, bottom (1.0 = drawn, 0.0 = skipped)
+    #[live] pub border_sides: Vec4,
 }
 
 impl DrawBar {
@@ -39,8 +83,28 @@ impl DrawBar {
         self.draw_abs(cx, rect);
     }
 
-    pub fn set_top_radius(&mut self, _radius: f32) {
-        // Simplified - no rounded corners for now
+    /// Round the top corners by `radius` pixels and keep the bottom ones square
+    pub fn set_top_radius(&mut self, radius: f32) {
+        self.radius = vec4(radius, radius, 0.0, 0.0);
+    }
+
+    /// Set the corner radii in pixels, clockwise from the top left, and the sides
+    /// with a border (left, top, right, bottom; 1.0 = drawn, 0.0 = skipped), as
+    /// returned by `bar_corners`
+    pub fn set_shape(&mut self, radius: Vec4, border_sides: Vec4) {
+        self.radius = radius;
+        self.border_sides = border_sides;
+    }
+
+    /// Draw a border `width` pixels wide inside the bar on the sides set with `set_shape`
+    pub fn set_border(&mut self, width: f32, color: Vec4) {
+        self.border_width = width;
+        self.border_color = color;
+    }
+
+    /// Draw bars without a border
+    pub fn disable_border(&mut self) {
+        self.border_width = 0.0;
     }
 
     /// Enable vertical gradient (bottom to top)
@@ -55,3 +119,75 @@ impl DrawBar {
         self.gradient_enabled = 0.0;
     }
 }
+
+/// Corner radii (clockwise from the top left) and bordered sides (left, top, right,
+/// bottom; 1.0 or 0.0) of a bar of `size` growing from its `base` side. Corners
+/// touching the skipped side stay square, and radii are limited to half the
+/// bar's width and height.
+pub fn bar_corners(size: DVec2, base: AxisPosition, radius: CornerRadius, skipped: BorderSkipped) -> (Vec4, Vec4) {
+    let opposite = match base {
+        AxisPosition::Left => AxisPosition::Right,
+        AxisPosition::Right => AxisPosition::Left,
+        AxisPosition::Top => AxisPosition::Bottom,
+        AxisPosition::Bottom => AxisPosition::Top,
+    };
+    let skipped_side = match skipped {
+        BorderSkipped::None | BorderSkipped::All => None,
+        BorderSkipped::Start => Some(base),
+        BorderSkipped::End => Some(opposite),
+        BorderSkipped::Left => Some(AxisPosition::Left),
+        BorderSkipped::Top => Some(AxisPosition::Top),
+        BorderSkipped::Right => Some(AxisPosition::Right),
+        BorderSkipped::Bottom => Some(AxisPosition::Bottom),
+    };
+    let drawn = |side: AxisPosition| skipped != BorderSkipped::All && skipped_side != Some(side);
+    let [left, top, right, bottom] = [AxisPosition::Left, AxisPosition::Top, AxisPosition::Right, AxisPosition::Bottom].map(drawn);
+
+    let max_radius = (size.x.min(size.y) / 2.0).max(0.0);
+    let corner = |radius: f64, square: bool| if square { 0.0 } else { radius.clamp(0.0, max_radius) as f32 };
+    let radii = vec4(
+        corner(radius.top_left, !top || !left),
+        corner(radius.top_right, !top || !right),
+        corner(radius.bottom_right, !bottom || !right),
+        corner(radius.bottom_left, !bottom || !left),
+    );
+    let flag = |drawn: bool| if drawn { 1.0 } else { 0.0 };
+    (radii, vec4(flag(left), flag(top), flag(right), flag(bottom)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_corners_round_the_end() {
+        let size = dvec2(20.0, 100.0);
+        let (radii, sides) = bar_corners(size, AxisPosition::Bottom, CornerRadius::all(6.0), BorderSkipped::Start);
+        assert_eq!(radii, vec4(6.0, 6.0, 0.0, 0.0));
+        assert_eq!(sides, vec4(1.0, 1.0, 1.0, 0.0));
+
+        // Negative bars grow down from the top, horizontal ones from the left
+        let (radii, sides) = bar_corners(size, AxisPosition::Top, CornerRadius::all(6.0), BorderSkipped::Start);
+        assert_eq!(radii, vec4(0.0, 0.0, 6.0, 6.0));
+        assert_eq!(sides, vec4(1.0, 0.0, 1.0, 1.0));
+        let (radii, _) = bar_corners(size, AxisPosition::Left, CornerRadius::all(6.0), BorderSkipped::Start);
+        assert_eq!(radii, vec4(0.0, 6.0, 6.0, 0.0));
+    }
+
+    #[test]
+    fn test_bar_corners_skipped_sides_and_limits() {
+        let size = dvec2(8.0, 100.0);
+        let radius = CornerRadius { top_left: 10.0, top_right: 2.0, ..Default::default() };
+        let (radii, sides) = bar_corners(size, AxisPosition::Bottom, radius, BorderSkipped::None);
+        assert_eq!(radii, vec4(4.0, 2.0, 0.0, 0.0));
+        assert_eq!(sides, vec4(1.0, 1.0, 1.0, 1.0));
+
+        let (radii, sides) = bar_corners(size, AxisPosition::Bottom, radius, BorderSkipped::End);
+        assert_eq!(radii, vec4(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(sides, vec4(1.0, 0.0, 1.0, 1.0));
+
+        let (radii, sides) = bar_corners(size, AxisPosition::Bottom, CornerRadius::all(3.0), BorderSkipped::All);
+        assert_eq!(radii, vec4(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(sides, vec4(0.0, 0.0, 0.0, 0.0));
+    }
+}
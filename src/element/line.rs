@@ -1,7 +1,9 @@
 use makepad_widgets::*;
+use super::stroke::dash_vectors;
 
 live_design! {
     use link::shaders::*;
+    use crate::element::stroke::ChartDash;
 
     pub DrawChartLine = {{DrawChartLine}} {
         fn pixel(self) -> vec4 {
@@ -36,6 +38,11 @@ live_design! {
             let aa = 0.01;
             let alpha = 1.0 - smoothstep(half_width - aa, half_width + aa, dist);
 
+            // Dash pattern in pixels from the start of the line
+            let pixel_vec = line_vec * self.rect_size;
+            let along = self.along + dot((p - p1) * self.rect_size, pixel_vec) / length(pixel_vec);
+            alpha = alpha * ChartDash::coverage(self.dash, self.dash_tail, self.dash_offset, along);
+
             if alpha < 0.01 {
                 return vec4(0.0, 0.0, 0.0, 0.0);
             }
@@ -55,10 +62,44 @@ pub struct DrawChartLine {
     #[live] pub x2: f32,
     #[live] pub y2: f32,
     #[live] pub line_width: f32,
+    /// Distance along the path in pixels at the start of the line
+    #[live] pub along: f32,
+    /// First four dash and gap lengths from `dash_pattern`, all zero for a solid line
+    #[live] pub dash: Vec4,
+    /// Last four dash and gap lengths from `dash_pattern`
+    #[live] pub dash_tail: Vec4,
+    /// Distance in pixels into the dash pattern at the start of the path
+    #[live] pub dash_offset: f32,
 }
 
 impl DrawChartLine {
+    /// Dash the lines drawn next with alternating dash and gap lengths in pixels,
+    /// starting `offset` pixels into the pattern; an empty pattern draws solid lines
+    pub fn set_dash(&mut self, dash: &[f64], offset: f64) {
+        (self.dash, self.dash_tail) = dash_vectors(dash);
+        self.dash_offset = offset as f32;
+    }
+
+    /// Draw the segments through `points`, back to the first one when `closed`,
+    /// with the dash pattern running on from one segment to the next
+    pub fn draw_path(&mut self, cx: &mut Cx2d, points: &[DVec2], width: f64, closed: bool) {
+        let count = if closed && points.len() > 2 { points.len() } else { points.len().saturating_sub(1) };
+        let mut along = 0.0;
+        for i in 0..count {
+            let (p1, p2) = (points[i], points[(i + 1) % points.len()]);
+            self.draw_line_at(cx, p1, p2, width, along);
+            along += (p2 - p1).length();
+        }
+    }
+
     pub fn draw_line(&mut self, cx: &mut Cx2d, p1: DVec2, p2: DVec2, width: f64) {
+        self.draw_line_at(cx, p1, p2, width, 0.0);
+    }
+
+    /// Draw a line whose dash pattern starts `along` pixels into the path
+    fn draw_line_at(&mut self, cx: &mut Cx2d, p1: DVec2, p2: DVec2, width: f64, along: f64) {
+        self.along = along as f32;
+
         // Calculate bounding box with padding for line width
         let padding = width * 2.0;
         let min_x = p1.x.min(p2.x) - padding;
@@ -98,6 +139,7 @@ impl DrawChartLine {
         self.x2 = 1.0;
         self.y2 = 0.5;
         self.line_width = 1.0;
+        self.along = 0.0;
         self.draw_abs(cx, rect);
     }
 }
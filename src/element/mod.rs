@@ -1,22 +1,32 @@
+pub mod area;
 pub mod bar;
 pub mod line;
+pub mod polyline;
+pub mod stroke;
 pub mod point;
 pub mod arc;
 pub mod triangle;
 pub mod grid;
+pub mod text;
 
+pub use area::*;
 pub use bar::*;
 pub use line::*;
+pub use polyline::*;
+pub use stroke::*;
 pub use point::*;
 pub use arc::*;
 pub use triangle::*;
 pub use grid::*;
+pub use text::*;
 
 use makepad_widgets::*;
 
 pub fn live_design(cx: &mut Cx) {
+    stroke::live_design(cx);
     bar::live_design(cx);
     line::live_design(cx);
+    polyline::live_design(cx);
     point::live_design(cx);
     arc::live_design(cx);
     triangle::live_design(cx);
@@ -1,31 +1,98 @@
 use makepad_widgets::*;
+use crate::core::Dataset;
+
+pub use crate::core::PointStyle;
 
 live_design! {
     use link::shaders::*;
 
     pub DrawPoint = {{DrawPoint}} {
-        fn pixel(self) -> vec4 {
-            let uv = self.pos;
-            let center = vec2(0.5, 0.5);
-            let dist = distance(uv, center) * 2.0; // 0 at center, 1 at edge
+        texture image: texture2d
 
-            // Check if inside circle
-            if dist > 1.0 {
-                return vec4(0.0, 0.0, 0.0, 0.0);
+        fn fill_color(self) -> vec4 {
+            if self.gradient_enabled > 0.5 {
+                // Radial gradient: center color at center, outer color at edge
+                let dist = clamp(distance(self.pos, vec2(0.5, 0.5)) * 2.0, 0.0, 1.0);
+                return mix(self.gradient_center_color, self.gradient_outer_color, dist);
             }
+            return self.color;
+        }
 
-            // Anti-alias the edge
-            let aa = 0.02;
-            let alpha = 1.0 - smoothstep(1.0 - aa, 1.0, dist);
+        fn pixel(self) -> vec4 {
+            // Image points: sample the texture over the whole box
+            if self.shape > 9.5 {
+                let img = sample2d(self.image, self.pos);
+                return vec4(img.rgb * img.a, img.a) * self.color.a;
+            }
 
-            // Calculate color with gradient support
-            if self.gradient_enabled > 0.5 {
-                // Radial gradient: center color at center, outer color at edge
-                let final_color = mix(self.gradient_center_color, self.gradient_outer_color, dist);
-                return vec4(final_color.rgb * final_color.a * alpha, final_color.a * alpha);
+            let size = self.rect_size;
+            let sdf = Sdf2d::viewport(self.pos * size);
+            let c = size * 0.5;
+            let bw = self.border_width;
+            let r = max(min(c.x, c.y) - max(bw, 1.0) * 0.5, 0.5);
+            let fill = self.fill_color();
+
+            sdf.rotate(self.rotation, c.x, c.y);
+
+            // Stroked shapes: cross, crossRot, dash, line
+            if self.shape > 5.5 {
+                let stroke_color = mix(fill, self.border_color, step(0.001, bw));
+                if self.shape < 6.5 {
+                    sdf.move_to(c.x, c.y - r);
+                    sdf.line_to(c.x, c.y + r);
+                    sdf.move_to(c.x - r, c.y);
+                    sdf.line_to(c.x + r, c.y);
+                } else if self.shape < 7.5 {
+                    let d = r * 0.7071;
+                    sdf.move_to(c.x - d, c.y - d);
+                    sdf.line_to(c.x + d, c.y + d);
+                    sdf.move_to(c.x + d, c.y - d);
+                    sdf.line_to(c.x - d, c.y + d);
+                } else if self.shape < 8.5 {
+                    sdf.move_to(c.x, c.y);
+                    sdf.line_to(c.x + r, c.y);
+                } else {
+                    sdf.move_to(c.x - r, c.y);
+                    sdf.line_to(c.x + r, c.y);
+                }
+                sdf.stroke(stroke_color, max(bw, 1.5));
+                return sdf.result;
+            }
+
+            // Filled shapes: circle, square, rounded square, triangle, diamond, star
+            if self.shape < 0.5 {
+                sdf.circle(c.x, c.y, r);
+            } else if self.shape < 1.5 {
+                sdf.rect(c.x - r, c.y - r, r * 2.0, r * 2.0);
+            } else if self.shape < 2.5 {
+                sdf.box(c.x - r, c.y - r, r * 2.0, r * 2.0, r * 0.35);
+            } else if self.shape < 3.5 {
+                sdf.move_to(c.x, c.y - r);
+                sdf.line_to(c.x + r * 0.866, c.y + r * 0.5);
+                sdf.line_to(c.x - r * 0.866, c.y + r * 0.5);
+                sdf.close_path();
+            } else if self.shape < 4.5 {
+                sdf.move_to(c.x, c.y - r);
+                sdf.line_to(c.x + r, c.y);
+                sdf.line_to(c.x, c.y + r);
+                sdf.line_to(c.x - r, c.y);
+                sdf.close_path();
+            } else {
+                // Star: alternate between outer and inner radius over 10 vertices
+                sdf.move_to(c.x, c.y - r);
+                for i in 1..10 {
+                    let a = float(i) * PI / 5.0 - PI * 0.5;
+                    let rr = mix(r, r * 0.4, mod(float(i), 2.0));
+                    sdf.line_to(c.x + cos(a) * rr, c.y + sin(a) * rr);
+                }
+                sdf.close_path();
             }
 
-            return vec4(self.color.rgb * self.color.a * alpha, self.color.a * alpha);
+            sdf.fill_keep(fill);
+            if bw > 0.0 {
+                sdf.stroke(self.border_color, bw);
+            }
+            return sdf.result;
         }
     }
 }
@@ -41,6 +108,14 @@ pub struct DrawPoint {
     #[live] pub gradient_center_color: Vec4,
     /// Outer color for radial gradient
     #[live] pub gradient_outer_color: Vec4,
+    /// Shape id, see `PointStyle::shader_id`
+    #[live(0.0)] pub shape: f32,
+    /// Shape rotation in radians
+    #[live(0.0)] pub rotation: f32,
+    /// Border color (stroke color for stroked shapes)
+    #[live] pub border_color: Vec4,
+    /// Border width in pixels (0.0 = no border)
+    #[live(0.0)] pub border_width: f32,
 }
 
 impl DrawPoint {
@@ -59,15 +134,44 @@ impl DrawPoint {
     pub fn disable_gradient(&mut self) {
         self.gradient_enabled = 0.0;
     }
-}
 
-/// Point styles for scatter charts
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub enum PointStyle {
-    #[default]
-    Circle,
-    Square,
-    Triangle,
-    Diamond,
-    Cross,
+    /// Set the point shape
+    pub fn set_style(&mut self, style: PointStyle) {
+        self.shape = style.shader_id();
+    }
+
+    /// Set the shape rotation in degrees
+    pub fn set_rotation(&mut self, degrees: f64) {
+        self.rotation = degrees.to_radians() as f32;
+    }
+
+    /// Set the border color and width (width 0.0 disables the border)
+    pub fn set_border(&mut self, color: Vec4, width: f64) {
+        self.border_color = color;
+        self.border_width = width as f32;
+    }
+
+    /// Set the texture sampled by `PointStyle::Image` points.
+    /// Starts a new draw call so points drawn with other textures are not merged.
+    pub fn set_image(&mut self, cx: &mut Cx2d, image: &Texture) {
+        self.draw_vars.set_texture(0, image);
+        self.new_draw_call(cx);
+    }
+
+    /// Apply the point style, rotation, border and image of `dataset`.
+    /// The fill color is left to the caller, which handles hover and gradients.
+    pub fn apply_dataset(&mut self, cx: &mut Cx2d, dataset: &Dataset, dataset_index: usize) {
+        let style = match (dataset.point_style, &dataset.point_image) {
+            (PointStyle::Image, Some(image)) => {
+                self.set_image(cx, image);
+                PointStyle::Image
+            }
+            // No texture to sample, fall back to the default shape
+            (PointStyle::Image, None) => PointStyle::Circle,
+            (style, _) => style,
+        };
+        self.set_style(style);
+        self.set_rotation(dataset.point_rotation);
+        self.set_border(dataset.get_point_border_color(dataset_index), dataset.point_border_width);
+    }
 }
@@ -0,0 +1,155 @@
+use makepad_widgets::*;
+use crate::core::{LineCap, LineJoin};
+use super::stroke::{dash_vectors, stroke_polyline, StrokePiece};
+
+live_design! {
+    use link::shaders::*;
+    use crate::element::stroke::ChartDash;
+
+    pub DrawPolyline = {{DrawPolyline}} {
+        varying world: vec2
+
+        // Place the unit quad on the four corners of the piece instead of its bounding box
+        fn vertex(self) -> vec4 {
+            let start = mix(self.p0, self.p1, self.geom_pos.y);
+            let end = mix(self.p2, self.p3, self.geom_pos.y);
+            let world = mix(start, end, self.geom_pos.x);
+            self.pos = self.geom_pos;
+            self.world = world;
+            return self.camera_projection * (self.camera_view * (
+                self.view_transform * vec4(world.x, world.y, self.draw_depth + self.draw_zbias, 1.)
+            ));
+        }
+
+        fn pixel(self) -> vec4 {
+            // The corners are not clamped to the clip rect like quads are, so clip here
+            if self.world.x < self.draw_clip.x || self.world.y < self.draw_clip.y
+                || self.world.x > self.draw_clip.z || self.world.y > self.draw_clip.w {
+                return vec4(0.0, 0.0, 0.0, 0.0);
+            }
+
+            // Distance in pixels to the outside edge, fading over the last pixel
+            let coverage = 0.0;
+            let along = self.along;
+            if self.shape < 0.5 {
+                // Band: edges along both long sides, distance measured along its center line
+                let width = length(mix(self.p1 - self.p0, self.p3 - self.p2, self.pos.x));
+                coverage = clamp(min(self.pos.y, 1.0 - self.pos.y) * width, 0.0, 1.0);
+                let start = (self.p0 + self.p1) * 0.5;
+                let end = (self.p2 + self.p3) * 0.5;
+                along = self.along + dot(self.world - start, normalize(end - start));
+            } else if self.shape < 1.5 {
+                // Wedge: the edge between the outer corners, `extent` away from the joint
+                coverage = clamp((1.0 - self.pos.x) * self.extent, 0.0, 1.0);
+            } else {
+                // Disc of radius `extent`
+                let center = (self.p0 + self.p3) * 0.5;
+                coverage = clamp(self.extent - length(self.world - center), 0.0, 1.0);
+            }
+            coverage = coverage * ChartDash::coverage(self.dash, self.dash_tail, self.dash_offset, along);
+            return vec4(self.color.rgb * self.color.a * coverage, self.color.a * coverage);
+        }
+    }
+}
+
+/// Polyline stroked as one batch of instances, with joins and caps and a width
+/// in true pixels. Each instance is a band, wedge or disc from `stroke_polyline`
+/// whose corners the vertex shader places directly. Set a dash pattern with
+/// `set_dash`; it runs on across segments and joins of the whole line.
+#[derive(Live, LiveHook, LiveRegister)]
+#[repr(C)]
+pub struct DrawPolyline {
+    #[deref] pub draw_super: DrawQuad,
+    #[live] pub color: Vec4,
+    #[live] pub p0: Vec2,
+    #[live] pub p1: Vec2,
+    #[live] pub p2: Vec2,
+    #[live] pub p3: Vec2,
+    /// Piece shape (0.0 = band, 1.0 = wedge, 2.0 = disc)
+    #[live] pub shape: f32,
+    /// Wedge height or disc radius in pixels
+    #[live] pub extent: f32,
+    /// Distance along the line in pixels at the start of the piece
+    #[live] pub along: f32,
+    /// First four dash and gap lengths from `dash_pattern`, all zero for a solid line
+    #[live] pub dash: Vec4,
+    /// Last four dash and gap lengths from `dash_pattern`
+    #[live] pub dash_tail: Vec4,
+    /// Distance in pixels into the dash pattern at the start of the line
+    #[live] pub dash_offset: f32,
+}
+
+impl DrawPolyline {
+    /// Dash the lines drawn next with alternating dash and gap lengths in pixels,
+    /// starting `offset` pixels into the pattern; an empty pattern draws solid lines
+    pub fn set_dash(&mut self, dash: &[f64], offset: f64) {
+        (self.dash, self.dash_tail) = dash_vectors(dash);
+        self.dash_offset = offset as f32;
+    }
+
+    /// Stroke the line through `points`, `width` pixels wide
+    pub fn draw_polyline(&mut self, cx: &mut Cx2d, points: &[DVec2], width: f64, join: LineJoin, cap: LineCap) {
+        self.draw_stroke(cx, points, width, join, cap, false);
+    }
+
+    /// Stroke the closed outline through `points`, `width` pixels wide
+    pub fn draw_polygon(&mut self, cx: &mut Cx2d, points: &[DVec2], width: f64, join: LineJoin) {
+        self.draw_stroke(cx, points, width, join, LineCap::Butt, true);
+    }
+
+    fn draw_stroke(&mut self, cx: &mut Cx2d, points: &[DVec2], width: f64, join: LineJoin, cap: LineCap, closed: bool) {
+        // Half a pixel on either side is left for the antialiased edge
+        let pieces = stroke_polyline(points, width + 1.0, join, cap, closed);
+        if pieces.is_empty() {
+            return;
+        }
+
+        self.begin_many_instances(cx);
+        for piece in pieces {
+            match piece {
+                StrokePiece::Band(corners, along) => {
+                    self.shape = 0.0;
+                    self.extent = 0.0;
+                    self.along = along as f32;
+                    self.draw_piece(cx, corners);
+                }
+                StrokePiece::Wedge([joint, a, b], along) => {
+                    // Height of the triangle over its outer edge
+                    let edge = b - a;
+                    let to_joint = joint - a;
+                    let edge_length = edge.length();
+                    if edge_length < 1e-6 {
+                        continue;
+                    }
+                    self.shape = 1.0;
+                    self.extent = ((edge.x * to_joint.y - edge.y * to_joint.x).abs() / edge_length) as f32;
+                    self.along = along as f32;
+                    self.draw_piece(cx, [joint, joint, a, b]);
+                }
+                StrokePiece::Disc { center, radius, along } => {
+                    self.shape = 2.0;
+                    self.extent = radius as f32;
+                    self.along = along as f32;
+                    let (min, max) = (center - dvec2(radius, radius), center + dvec2(radius, radius));
+                    self.draw_piece(cx, [min, dvec2(min.x, max.y), dvec2(max.x, min.y), max]);
+                }
+            }
+        }
+        self.end_many_instances(cx);
+    }
+
+    fn draw_piece(&mut self, cx: &mut Cx2d, corners: [DVec2; 4]) {
+        let to_vec2 = |p: DVec2| vec2(p.x as f32, p.y as f32);
+        self.p0 = to_vec2(corners[0]);
+        self.p1 = to_vec2(corners[1]);
+        self.p2 = to_vec2(corners[2]);
+        self.p3 = to_vec2(corners[3]);
+
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for corner in &corners[1..] {
+            min = dvec2(min.x.min(corner.x), min.y.min(corner.y));
+            max = dvec2(max.x.max(corner.x), max.y.max(corner.y));
+        }
+        self.draw_abs(cx, Rect { pos: min, size: max - min });
+    }
+}
@@ -0,0 +1,292 @@
+use makepad_widgets::*;
+use crate::core::{LineCap, LineJoin};
+
+live_design! {
+    use link::shaders::*;
+
+    pub ChartDash = {
+        // How much of the dash pattern is on `along` pixels into the line, fading over a pixel at dash ends.
+        // `head` and `tail` hold the eight lengths from `dash_pattern`, `offset` is the pattern offset.
+        fn coverage(head: vec4, tail: vec4, offset: float, along: float) -> float {
+            let period = head.x + head.y + head.z + head.w + tail.x + tail.y + tail.z + tail.w;
+            if period <= 0.0 {
+                return 1.0;
+            }
+            let d = along + offset;
+            let t = d - period * floor(d / period);
+            let start = 0.0;
+            let on = clamp(min(t - start, start + head.x - t) + 0.5, 0.0, 1.0);
+            start = start + head.x + head.y;
+            on = max(on, clamp(min(t - start, start + head.z - t) + 0.5, 0.0, 1.0));
+            start = start + head.z + head.w;
+            on = max(on, clamp(min(t - start, start + tail.x - t) + 0.5, 0.0, 1.0));
+            start = start + tail.x + tail.y;
+            on = max(on, clamp(min(t - start, start + tail.z - t) + 0.5, 0.0, 1.0));
+            // The first dash of the next period starting
+            return max(on, clamp(t - period + 0.5, 0.0, 1.0));
+        }
+    }
+}
+
+/// Longest miter join, in half line widths, before it is bevelled instead
+/// (the canvas default `miterLimit`)
+pub const MITER_LIMIT: f64 = 10.0;
+
+/// A piece of the triangle geometry covering a stroked polyline. Each piece
+/// carries its distance along the path so dash patterns run on across pieces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StrokePiece {
+    /// Band along a segment between its start and end cross sections,
+    /// as start left, start right, end left, end right, and the distance
+    /// along the path at the start cross section
+    Band([DVec2; 4], f64),
+    /// Triangle filling the outside of a bevelled or rounded join,
+    /// as the joint followed by the two outer corners, and the distance at the joint
+    Wedge([DVec2; 3], f64),
+    /// Disc rounding a join or an end
+    Disc { center: DVec2, radius: f64, along: f64 },
+}
+
+/// Longest dash pattern the line shaders take, in lengths
+pub const MAX_DASH_LENGTHS: usize = 8;
+
+/// Dash pattern as the line shaders take it: up to four dash and gap pairs in pixels.
+/// An odd number of lengths is repeated like canvas `setLineDash` does, so
+/// `[5, 1, 2]` dashes as `[5, 1, 2, 5, 1, 2]`. Patterns longer than
+/// `MAX_DASH_LENGTHS` after that are cut to their first eight lengths. An empty
+/// pattern, all zeros or a negative or non-finite length draws a solid line.
+pub fn dash_pattern(dash: &[f64]) -> [f32; MAX_DASH_LENGTHS] {
+    let mut pattern = [0.0; MAX_DASH_LENGTHS];
+    if dash.iter().any(|d| !d.is_finite() || *d < 0.0) {
+        return pattern;
+    }
+    let repeats = if dash.len() % 2 == 1 { 2 } else { 1 };
+    for (slot, d) in pattern.iter_mut().zip(dash.iter().cycle().take(dash.len() * repeats)) {
+        *slot = *d as f32;
+    }
+    pattern
+}
+
+/// `dash_pattern` of `dash` as the `dash` and `dash_tail` vectors of the line shaders
+pub fn dash_vectors(dash: &[f64]) -> (Vec4, Vec4) {
+    let p = dash_pattern(dash);
+    (vec4(p[0], p[1], p[2], p[3]), vec4(p[4], p[5], p[6], p[7]))
+}
+
+fn dot(a: DVec2, b: DVec2) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+fn length(v: DVec2) -> f64 {
+    dot(v, v).sqrt()
+}
+
+/// Left-hand normal of a direction
+fn normal(d: DVec2) -> DVec2 {
+    dvec2(-d.y, d.x)
+}
+
+/// Cover the line through `points`, `width` pixels wide, with bands, wedges and discs.
+/// Repeated points are skipped; a `closed` line also joins its last point to the first.
+pub fn stroke_polyline(points: &[DVec2], width: f64, join: LineJoin, cap: LineCap, closed: bool) -> Vec<StrokePiece> {
+    let mut path: Vec<DVec2> = Vec::with_capacity(points.len());
+    for &point in points {
+        if path.last().is_none_or(|last| length(point - *last) > 1e-6) {
+            path.push(point);
+        }
+    }
+    if closed && path.len() > 2 && length(path[0] - path[path.len() - 1]) <= 1e-6 {
+        path.pop();
+    }
+
+    let half = width / 2.0;
+    let n = path.len();
+    if n < 2 || half <= 0.0 {
+        return Vec::new();
+    }
+    let closed = closed && n > 2;
+    let segments = if closed { n } else { n - 1 };
+    let direction = |s: usize| {
+        let d = path[(s + 1) % n] - path[s];
+        d * (1.0 / length(d))
+    };
+
+    // Cross sections (left, right) at the start and end of every segment
+    // and the distance along the path at every point
+    let mut starts = Vec::with_capacity(segments);
+    let mut ends = Vec::with_capacity(segments);
+    let mut distances = Vec::with_capacity(n);
+    let mut distance = 0.0;
+    for s in 0..segments {
+        let offset = normal(direction(s)) * half;
+        let (a, b) = (path[s], path[(s + 1) % n]);
+        starts.push((a + offset, a - offset));
+        ends.push((b + offset, b - offset));
+        distances.push(distance);
+        distance += length(b - a);
+    }
+    if !closed {
+        distances.push(distance);
+    }
+    let mut alongs = distances[..segments].to_vec();
+
+    let mut extra = Vec::new();
+
+    let joints = if closed { 0..n } else { 1..n - 1 };
+    for v in joints {
+        let (prev, next) = ((v + segments - 1) % segments, v % segments);
+        let (d0, d1) = (direction(prev), direction(next));
+        let (n0, n1) = (normal(d0), normal(d1));
+        let joint = path[v];
+
+        let bisector = n0 + n1;
+        let bisector_length = length(bisector);
+        if bisector_length > 1e-9 {
+            let miter = bisector * (1.0 / bisector_length);
+            // Cosine of half the angle between the segments' normals
+            let cos = dot(miter, n0);
+            if cos > 1.0 - 1e-9 {
+                // Straight on, the cross sections already meet
+                continue;
+            }
+            if join == LineJoin::Miter && cos * MITER_LIMIT > 1.0 {
+                let offset = miter * (half / cos);
+                let section = (joint + offset, joint - offset);
+                ends[prev] = section;
+                starts[next] = section;
+                continue;
+            }
+        }
+
+        // The outer side is the one the line turns away from
+        let side = if dot(d1, n0) > 0.0 { -half } else { half };
+        let along = distances[v];
+        extra.push(StrokePiece::Wedge([joint, joint + n0 * side, joint + n1 * side], along));
+        if join == LineJoin::Round {
+            extra.push(StrokePiece::Disc { center: joint, radius: half, along });
+        }
+    }
+
+    if !closed {
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let back = direction(0) * half;
+                starts[0] = (starts[0].0 - back, starts[0].1 - back);
+                alongs[0] = -half;
+                let forward = direction(segments - 1) * half;
+                let last = &mut ends[segments - 1];
+                *last = (last.0 + forward, last.1 + forward);
+            }
+            LineCap::Round => {
+                extra.push(StrokePiece::Disc { center: path[0], radius: half, along: 0.0 });
+                extra.push(StrokePiece::Disc { center: path[n - 1], radius: half, along: distance });
+            }
+        }
+    }
+
+    let mut pieces: Vec<StrokePiece> = starts
+        .into_iter()
+        .zip(ends)
+        .zip(alongs)
+        .map(|((start, end), along)| StrokePiece::Band([start.0, start.1, end.0, end.1], along))
+        .collect();
+    pieces.extend(extra);
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: DVec2, b: DVec2) -> bool {
+        length(a - b) < 1e-9
+    }
+
+    #[test]
+    fn test_straight_line_band() {
+        let pieces = stroke_polyline(&[dvec2(0.0, 0.0), dvec2(10.0, 0.0), dvec2(10.0, 0.0)], 4.0, LineJoin::Miter, LineCap::Butt, false);
+        assert_eq!(pieces.len(), 1);
+        let StrokePiece::Band([a0, a1, b0, b1], _) = pieces[0] else {
+            panic!("expected a band");
+        };
+        assert!(close(a0, dvec2(0.0, 2.0)) && close(a1, dvec2(0.0, -2.0)));
+        assert!(close(b0, dvec2(10.0, 2.0)) && close(b1, dvec2(10.0, -2.0)));
+    }
+
+    #[test]
+    fn test_miter_join_shares_cross_section() {
+        let points = [dvec2(0.0, 0.0), dvec2(10.0, 0.0), dvec2(10.0, 10.0)];
+        let pieces = stroke_polyline(&points, 2.0, LineJoin::Miter, LineCap::Butt, false);
+        assert_eq!(pieces.len(), 2);
+        let (StrokePiece::Band(first, _), StrokePiece::Band(second, _)) = (pieces[0], pieces[1]) else {
+            panic!("expected bands");
+        };
+        assert!(close(first[2], second[0]) && close(first[3], second[1]));
+        // The outer corner of a right angle sits on both outer edges
+        assert!(close(first[3], dvec2(11.0, -1.0)) || close(first[2], dvec2(11.0, -1.0)));
+    }
+
+    #[test]
+    fn test_sharp_miter_falls_back_to_bevel() {
+        let points = [dvec2(0.0, 0.0), dvec2(100.0, 0.0), dvec2(0.0, 1.0)];
+        let pieces = stroke_polyline(&points, 2.0, LineJoin::Miter, LineCap::Butt, false);
+        assert!(pieces.iter().any(|p| matches!(p, StrokePiece::Wedge(..))));
+    }
+
+    #[test]
+    fn test_round_join_and_caps() {
+        let points = [dvec2(0.0, 0.0), dvec2(10.0, 0.0), dvec2(10.0, 10.0)];
+        let pieces = stroke_polyline(&points, 2.0, LineJoin::Round, LineCap::Round, false);
+        let wedges = pieces.iter().filter(|p| matches!(p, StrokePiece::Wedge(..))).count();
+        let discs = pieces.iter().filter(|p| matches!(p, StrokePiece::Disc { .. })).count();
+        assert_eq!((wedges, discs), (1, 3));
+    }
+
+    #[test]
+    fn test_square_cap_extends_ends() {
+        let pieces = stroke_polyline(&[dvec2(0.0, 0.0), dvec2(10.0, 0.0)], 4.0, LineJoin::Bevel, LineCap::Square, false);
+        let StrokePiece::Band([a0, _, b0, _], _) = pieces[0] else {
+            panic!("expected a band");
+        };
+        assert!(close(a0, dvec2(-2.0, 2.0)) && close(b0, dvec2(12.0, 2.0)));
+    }
+
+    #[test]
+    fn test_closed_line_joins_every_corner() {
+        let points = [dvec2(0.0, 0.0), dvec2(10.0, 0.0), dvec2(0.0, 10.0), dvec2(0.0, 0.0)];
+        let pieces = stroke_polyline(&points, 2.0, LineJoin::Bevel, LineCap::Round, true);
+        let bands = pieces.iter().filter(|p| matches!(p, StrokePiece::Band(..))).count();
+        let wedges = pieces.iter().filter(|p| matches!(p, StrokePiece::Wedge(..))).count();
+        assert_eq!((bands, wedges), (3, 3));
+        assert!(!pieces.iter().any(|p| matches!(p, StrokePiece::Disc { .. })));
+    }
+
+    #[test]
+    fn test_pieces_carry_distance_along_path() {
+        let points = [dvec2(0.0, 0.0), dvec2(10.0, 0.0), dvec2(10.0, 5.0)];
+        let pieces = stroke_polyline(&points, 2.0, LineJoin::Round, LineCap::Square, false);
+        let alongs: Vec<f64> = pieces
+            .iter()
+            .map(|piece| match *piece {
+                StrokePiece::Band(_, along) | StrokePiece::Wedge(_, along) => along,
+                StrokePiece::Disc { along, .. } => along,
+            })
+            .collect();
+        // Square cap start, second segment, then the round join's wedge and disc
+        assert_eq!(alongs, vec![-1.0, 10.0, 10.0, 10.0]);
+    }
+
+    #[test]
+    fn test_dash_pattern() {
+        assert_eq!(dash_pattern(&[]), [0.0; 8]);
+        assert_eq!(dash_pattern(&[6.0, 3.0]), [6.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        // Odd patterns repeat whole, long ones are cut to eight lengths
+        assert_eq!(dash_pattern(&[4.0]), [4.0, 4.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(dash_pattern(&[5.0, 1.0, 2.0]), [5.0, 1.0, 2.0, 5.0, 1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(dash_pattern(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0]);
+        assert_eq!(dash_pattern(&[1.0, 2.0, 3.0, 4.0, 5.0]), [1.0, 2.0, 3.0, 4.0, 5.0, 1.0, 2.0, 3.0]);
+        // Invalid lengths draw solid
+        assert_eq!(dash_pattern(&[4.0, -1.0]), [0.0; 8]);
+    }
+}
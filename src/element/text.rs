@@ -0,0 +1,92 @@
+use makepad_widgets::*;
+
+/// Measure the size of a single line of text with the current text style
+pub fn measure_text(cx: &mut Cx2d, draw_text: &DrawText, text: &str) -> DVec2 {
+    if text.is_empty() {
+        return dvec2(0.0, 0.0);
+    }
+    let laidout = draw_text.layout(cx, 0.0, 0.0, None, false, Align::default(), text);
+    dvec2(laidout.size_in_lpxs.width as f64, laidout.size_in_lpxs.height as f64)
+}
+
+/// Set font size and color on a DrawText in one call
+pub fn set_text_style(draw_text: &mut DrawText, font_size: f64, color: Vec4) {
+    draw_text.text_style.font_size = font_size as _;
+    draw_text.color = color;
+}
+
+/// Draw text with its top-left corner at `pos`
+pub fn draw_text_at(cx: &mut Cx2d, draw_text: &mut DrawText, pos: DVec2, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    draw_text.draw_abs(cx, pos, text);
+}
+
+/// Draw text aligned relative to an anchor point
+///
+/// `align` works like a turtle alignment: (0, 0) puts the anchor at the
+/// top-left of the text, (0.5, 0.5) centers it, (1, 1) puts it bottom-right.
+pub fn draw_text_aligned(cx: &mut Cx2d, draw_text: &mut DrawText, anchor: DVec2, align: DVec2, text: &str) -> Rect {
+    let size = measure_text(cx, draw_text, text);
+    let pos = dvec2(anchor.x - size.x * align.x, anchor.y - size.y * align.y);
+    draw_text_at(cx, draw_text, pos, text);
+    Rect { pos, size }
+}
+
+/// Draw text along a rotated baseline
+///
+/// Makepad text cannot be rotated on the GPU, so glyphs stay upright and are
+/// laid out one by one along the rotated baseline. `angle` is in degrees,
+/// positive values rotate counter-clockwise. The text starts at `pos`.
+pub fn draw_text_rotated(cx: &mut Cx2d, draw_text: &mut DrawText, pos: DVec2, angle: f64, text: &str) {
+    if angle.abs() < 0.5 {
+        draw_text_at(cx, draw_text, pos, text);
+        return;
+    }
+
+    let rad = angle.to_radians();
+    let dir = dvec2(rad.cos(), -rad.sin());
+    let mut offset = 0.0;
+    let mut buf = [0u8; 4];
+
+    for ch in text.chars() {
+        let glyph = ch.encode_utf8(&mut buf);
+        let advance = measure_text(cx, draw_text, glyph).x;
+        if !ch.is_whitespace() {
+            draw_text_at(cx, draw_text, dvec2(pos.x + dir.x * offset, pos.y + dir.y * offset), glyph);
+        }
+        offset += advance;
+    }
+}
+
+/// Draw text as a vertical column of upright glyphs, reading top to bottom
+pub fn draw_text_vertical(cx: &mut Cx2d, draw_text: &mut DrawText, pos: DVec2, text: &str) {
+    let mut y = pos.y;
+    let mut buf = [0u8; 4];
+
+    for ch in text.chars() {
+        let glyph = ch.encode_utf8(&mut buf);
+        let size = measure_text(cx, draw_text, glyph);
+        if !ch.is_whitespace() {
+            draw_text_at(cx, draw_text, dvec2(pos.x, y), glyph);
+        }
+        y += size.y.max(draw_text.text_style.font_size as f64);
+    }
+}
+
+/// Size of text drawn by `draw_text_vertical`
+pub fn measure_text_vertical(cx: &mut Cx2d, draw_text: &DrawText, text: &str) -> DVec2 {
+    let mut width = 0.0f64;
+    let mut height = 0.0;
+    let mut buf = [0u8; 4];
+
+    for ch in text.chars() {
+        let glyph = ch.encode_utf8(&mut buf);
+        let size = measure_text(cx, draw_text, glyph);
+        width = width.max(size.x);
+        height += size.y.max(draw_text.text_style.font_size as f64);
+    }
+
+    dvec2(width, height)
+}
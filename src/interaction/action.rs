@@ -0,0 +1,251 @@
+use makepad_widgets::*;
+use crate::core::{ChartData, DataPoint};
+
+/// A chart element picked by the pointer
+#[derive(Clone, Debug, Default)]
+pub struct ChartHit {
+    /// Index of the dataset the element belongs to
+    pub dataset_index: usize,
+    /// Index of the element within its dataset
+    pub data_index: usize,
+    /// The data point behind the element
+    pub point: DataPoint,
+}
+
+impl ChartHit {
+    pub fn new(dataset_index: usize, data_index: usize, point: DataPoint) -> Self {
+        Self {
+            dataset_index,
+            data_index,
+            point,
+        }
+    }
+
+    /// Look up the data point at `(dataset_index, data_index)`
+    pub fn from_data(data: &ChartData, dataset_index: usize, data_index: usize) -> Option<Self> {
+        let point = data.datasets.get(dataset_index)?.data.get(data_index)?;
+        Some(Self::new(dataset_index, data_index, point.clone()))
+    }
+}
+
+/// Data range shown by a cartesian chart after zooming or panning.
+/// Category axes report fractional category positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VisibleRange {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+    /// Whether any axis is zoomed or panned away from the data range
+    pub zoomed: bool,
+}
+
+/// Region selected with the brush and the elements inside it. Category axes use
+/// fractional category positions; an axis the brush does not span is unbounded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChartSelection {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+    /// `(dataset_index, data_index)` of the selected elements, sorted
+    pub indices: Vec<(usize, usize)>,
+}
+
+impl ChartSelection {
+    /// Whether the element at `(dataset_index, data_index)` is selected
+    pub fn contains(&self, dataset_index: usize, data_index: usize) -> bool {
+        self.indices.binary_search(&(dataset_index, data_index)).is_ok()
+    }
+}
+
+/// An annotation picked by the pointer
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnnotationHit {
+    /// Index of the annotation in the chart options
+    pub index: usize,
+    /// Id given with `Annotation::with_id`
+    pub id: String,
+}
+
+/// Actions emitted by every chart widget
+#[derive(Clone, Debug, DefaultNone)]
+pub enum ChartAction {
+    /// An element was clicked
+    ElementClicked(ChartHit),
+    /// The pointer moved onto a different element
+    ElementHovered(ChartHit),
+    /// The pointer left the last hovered element
+    HoverCleared,
+    /// A dataset was shown or hidden
+    DatasetToggled { dataset_index: usize, visible: bool },
+    /// A data index was shown or hidden (charts whose legend lists labels)
+    DataToggled { data_index: usize, visible: bool },
+    /// The visible range changed by zooming, panning or resetting the zoom
+    RangeChanged(VisibleRange),
+    /// A region was selected with the brush
+    SelectionChanged(ChartSelection),
+    /// The brush selection was cleared by clicking the chart area
+    SelectionCleared,
+    /// An annotation was clicked
+    AnnotationClicked(AnnotationHit),
+    /// The pointer moved onto an annotation
+    AnnotationEntered(AnnotationHit),
+    /// The pointer left an annotation
+    AnnotationLeft(AnnotationHit),
+    None,
+}
+
+/// Actions of a chart widget, read from its `*Ref`. Charts implement `chart_uid`
+/// and get the rest.
+pub trait ChartActions {
+    /// Uid of the chart widget whose actions are read
+    fn chart_uid(&self) -> WidgetUid;
+
+    /// Element clicked in `actions`, if any
+    fn clicked(&self, actions: &Actions) -> Option<ChartHit> {
+        ChartAction::clicked(actions, self.chart_uid())
+    }
+
+    /// Element newly hovered in `actions`, if any
+    fn hovered(&self, actions: &Actions) -> Option<ChartHit> {
+        ChartAction::hovered(actions, self.chart_uid())
+    }
+
+    /// Whether the pointer left the hovered element
+    fn hover_cleared(&self, actions: &Actions) -> bool {
+        ChartAction::hover_cleared(actions, self.chart_uid())
+    }
+
+    /// Dataset toggled in `actions` and its new visibility, if any
+    fn dataset_toggled(&self, actions: &Actions) -> Option<(usize, bool)> {
+        ChartAction::dataset_toggled(actions, self.chart_uid())
+    }
+
+    /// Data index toggled in `actions` and its new visibility, if any (charts whose legend lists labels)
+    fn data_toggled(&self, actions: &Actions) -> Option<(usize, bool)> {
+        ChartAction::data_toggled(actions, self.chart_uid())
+    }
+}
+
+/// Actions of cartesian charts: zooming and panning, brush selections on line, bar
+/// and scatter charts, and annotations on line, bar, scatter and combo charts
+pub trait CartesianChartActions: ChartActions {
+    /// Visible range in `actions` after zooming or panning, if it changed
+    fn range_changed(&self, actions: &Actions) -> Option<VisibleRange> {
+        ChartAction::range_changed(actions, self.chart_uid())
+    }
+
+    /// Selection brushed in `actions`, if any
+    fn selection_changed(&self, actions: &Actions) -> Option<ChartSelection> {
+        ChartAction::selection_changed(actions, self.chart_uid())
+    }
+
+    /// Whether the brush selection was cleared in `actions`
+    fn selection_cleared(&self, actions: &Actions) -> bool {
+        ChartAction::selection_cleared(actions, self.chart_uid())
+    }
+
+    /// Annotation clicked in `actions`, if any
+    fn annotation_clicked(&self, actions: &Actions) -> Option<AnnotationHit> {
+        ChartAction::annotation_clicked(actions, self.chart_uid())
+    }
+
+    /// Annotation the pointer moved onto in `actions`, if any
+    fn annotation_entered(&self, actions: &Actions) -> Option<AnnotationHit> {
+        ChartAction::annotation_entered(actions, self.chart_uid())
+    }
+
+    /// Annotation the pointer left in `actions`, if any
+    fn annotation_left(&self, actions: &Actions) -> Option<AnnotationHit> {
+        ChartAction::annotation_left(actions, self.chart_uid())
+    }
+}
+
+impl ChartAction {
+    /// Element clicked on the widget `uid`, if any
+    pub fn clicked(actions: &Actions, uid: WidgetUid) -> Option<ChartHit> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::ElementClicked(hit) => Some(hit),
+            _ => None,
+        })
+    }
+
+    /// Element newly hovered on the widget `uid`, if any
+    pub fn hovered(actions: &Actions, uid: WidgetUid) -> Option<ChartHit> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::ElementHovered(hit) => Some(hit),
+            _ => None,
+        })
+    }
+
+    /// Whether the hover on the widget `uid` was cleared
+    pub fn hover_cleared(actions: &Actions, uid: WidgetUid) -> bool {
+        actions
+            .filter_widget_actions(uid)
+            .any(|action| matches!(action.cast(), ChartAction::HoverCleared))
+    }
+
+    /// Dataset toggled on the widget `uid` and its new visibility, if any
+    pub fn dataset_toggled(actions: &Actions, uid: WidgetUid) -> Option<(usize, bool)> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::DatasetToggled { dataset_index, visible } => Some((dataset_index, visible)),
+            _ => None,
+        })
+    }
+
+    /// Visible range after zooming or panning the widget `uid`, if it changed
+    pub fn range_changed(actions: &Actions, uid: WidgetUid) -> Option<VisibleRange> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::RangeChanged(range) => Some(range),
+            _ => None,
+        })
+    }
+
+    /// Selection brushed on the widget `uid`, if any
+    pub fn selection_changed(actions: &Actions, uid: WidgetUid) -> Option<ChartSelection> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::SelectionChanged(selection) => Some(selection),
+            _ => None,
+        })
+    }
+
+    /// Whether the brush selection on the widget `uid` was cleared
+    pub fn selection_cleared(actions: &Actions, uid: WidgetUid) -> bool {
+        actions
+            .filter_widget_actions(uid)
+            .any(|action| matches!(action.cast(), ChartAction::SelectionCleared))
+    }
+
+    /// Data index toggled on the widget `uid` and its new visibility, if any
+    pub fn data_toggled(actions: &Actions, uid: WidgetUid) -> Option<(usize, bool)> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::DataToggled { data_index, visible } => Some((data_index, visible)),
+            _ => None,
+        })
+    }
+
+    /// Annotation clicked on the widget `uid`, if any
+    pub fn annotation_clicked(actions: &Actions, uid: WidgetUid) -> Option<AnnotationHit> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::AnnotationClicked(hit) => Some(hit),
+            _ => None,
+        })
+    }
+
+    /// Annotation the pointer moved onto on the widget `uid`, if any
+    pub fn annotation_entered(actions: &Actions, uid: WidgetUid) -> Option<AnnotationHit> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::AnnotationEntered(hit) => Some(hit),
+            _ => None,
+        })
+    }
+
+    /// Annotation the pointer left on the widget `uid`, if any
+    pub fn annotation_left(actions: &Actions, uid: WidgetUid) -> Option<AnnotationHit> {
+        actions.filter_widget_actions(uid).find_map(|action| match action.cast() {
+            ChartAction::AnnotationLeft(hit) => Some(hit),
+            _ => None,
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use makepad_widgets::*;
+use crate::core::{InteractionAxis, InteractionMode, InteractionOptions};
 
 /// Hit testing utility for chart interactions
 #[derive(Clone, Debug, Default)]
@@ -24,10 +25,60 @@ pub enum HitData {
     Point { dataset_index: usize, data_index: usize },
     /// A slice in a pie chart
     Slice { index: usize },
+    /// A ribbon between two groups in a chord chart
+    Chord { source_index: usize, target_index: usize },
     /// Custom data
     Custom(String),
 }
 
+impl HitData {
+    /// Dataset this element belongs to, if any
+    pub fn dataset_index(&self) -> Option<usize> {
+        match self {
+            HitData::Bar { dataset_index, .. } | HitData::Point { dataset_index, .. } => Some(*dataset_index),
+            HitData::Slice { .. } | HitData::Chord { .. } => Some(0),
+            HitData::Custom(_) => None,
+        }
+    }
+
+    /// Index of the element within its dataset, if any
+    pub fn data_index(&self) -> Option<usize> {
+        match self {
+            HitData::Bar { data_index, .. } | HitData::Point { data_index, .. } => Some(*data_index),
+            HitData::Slice { index } => Some(*index),
+            HitData::Chord { source_index, .. } => Some(*source_index),
+            HitData::Custom(_) => None,
+        }
+    }
+
+    /// `(dataset_index, data_index)` of this element, if any
+    pub fn indices(&self) -> Option<(usize, usize)> {
+        Some((self.dataset_index()?, self.data_index()?))
+    }
+}
+
+impl HitRegion {
+    /// Center of the region
+    pub fn center(&self) -> DVec2 {
+        dvec2(
+            self.rect.pos.x + self.rect.size.x / 2.0,
+            self.rect.pos.y + self.rect.size.y / 2.0,
+        )
+    }
+
+    /// Distance from the region center to `pos`, measured along `axis`
+    pub fn distance(&self, pos: DVec2, axis: InteractionAxis) -> f64 {
+        let center = self.center();
+        let dx = pos.x - center.x;
+        let dy = pos.y - center.y;
+        match axis {
+            InteractionAxis::X => dx.abs(),
+            InteractionAxis::Y => dy.abs(),
+            InteractionAxis::XY => (dx * dx + dy * dy).sqrt(),
+        }
+    }
+}
+
 impl HitTester {
     pub fn new() -> Self {
         Self { regions: Vec::new() }
@@ -84,4 +135,154 @@ impl HitTester {
 
         nearest
     }
+
+    /// Number of registered regions
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Whether no regions are registered
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Select the regions an interaction at `pos` refers to
+    ///
+    /// Follows the Chart.js interaction modes: `intersect` restricts the
+    /// anchor element to regions under the cursor, `axis` decides how
+    /// distance is measured when looking for the nearest element.
+    pub fn query(
+        &self,
+        pos: DVec2,
+        mode: InteractionMode,
+        intersect: bool,
+        axis: InteractionAxis,
+    ) -> Vec<&HitRegion> {
+        match mode {
+            InteractionMode::Point => self.hit_test_all(pos),
+            InteractionMode::Nearest => self.nearest(pos, intersect, axis).into_iter().collect(),
+            InteractionMode::Index => {
+                let Some(index) = self.nearest(pos, intersect, axis).and_then(|r| r.data.data_index()) else {
+                    return Vec::new();
+                };
+                self.regions
+                    .iter()
+                    .filter(|r| r.data.data_index() == Some(index))
+                    .collect()
+            }
+            InteractionMode::Dataset => {
+                let Some(dataset) = self.nearest(pos, intersect, axis).and_then(|r| r.data.dataset_index()) else {
+                    return Vec::new();
+                };
+                self.regions
+                    .iter()
+                    .filter(|r| r.data.dataset_index() == Some(dataset))
+                    .collect()
+            }
+            InteractionMode::X => self.along_axis(pos, intersect, InteractionAxis::X),
+            InteractionMode::Y => self.along_axis(pos, intersect, InteractionAxis::Y),
+        }
+    }
+
+    /// Nearest region to `pos`, optionally limited to regions containing it
+    pub fn nearest(&self, pos: DVec2, intersect: bool, axis: InteractionAxis) -> Option<&HitRegion> {
+        self.regions
+            .iter()
+            .filter(|r| !intersect || r.rect.contains(pos))
+            .min_by(|a, b| a.distance(pos, axis).total_cmp(&b.distance(pos, axis)))
+    }
+
+    /// `(dataset_index, data_index)` of the region nearest to `pos` according to the interaction options
+    pub fn element_at(&self, pos: DVec2, interaction: &InteractionOptions) -> Option<(usize, usize)> {
+        self.nearest(pos, interaction.intersect, interaction.axis)
+            .and_then(|region| region.data.indices())
+    }
+
+    /// Regions whose extent along one axis covers `pos`; without `intersect`
+    /// falls back to the regions closest along that axis
+    fn along_axis(&self, pos: DVec2, intersect: bool, axis: InteractionAxis) -> Vec<&HitRegion> {
+        let covers = |r: &HitRegion| match axis {
+            InteractionAxis::Y => pos.y >= r.rect.pos.y && pos.y <= r.rect.pos.y + r.rect.size.y,
+            _ => pos.x >= r.rect.pos.x && pos.x <= r.rect.pos.x + r.rect.size.x,
+        };
+
+        let hits: Vec<&HitRegion> = self.regions.iter().filter(|r| covers(r)).collect();
+        if !hits.is_empty() || intersect {
+            return hits;
+        }
+
+        let Some(min) = self.regions
+            .iter()
+            .map(|r| r.distance(pos, axis))
+            .min_by(|a, b| a.total_cmp(b))
+        else {
+            return Vec::new();
+        };
+        self.regions
+            .iter()
+            .filter(|r| (r.distance(pos, axis) - min).abs() < 0.5)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64, y: f64, w: f64, h: f64) -> Rect {
+        Rect { pos: dvec2(x, y), size: dvec2(w, h) }
+    }
+
+    /// Two datasets of two bars each, grouped by category
+    fn grouped_bars() -> HitTester {
+        let mut tester = HitTester::new();
+        tester.register(rect(0.0, 50.0, 10.0, 50.0), HitData::Bar { dataset_index: 0, data_index: 0 });
+        tester.register(rect(10.0, 20.0, 10.0, 80.0), HitData::Bar { dataset_index: 1, data_index: 0 });
+        tester.register(rect(40.0, 60.0, 10.0, 40.0), HitData::Bar { dataset_index: 0, data_index: 1 });
+        tester.register(rect(50.0, 30.0, 10.0, 70.0), HitData::Bar { dataset_index: 1, data_index: 1 });
+        tester
+    }
+
+    fn ids(regions: Vec<&HitRegion>) -> Vec<usize> {
+        regions.iter().map(|r| r.id).collect()
+    }
+
+    #[test]
+    fn test_nearest_intersect() {
+        let tester = grouped_bars();
+        let hits = tester.query(dvec2(15.0, 40.0), InteractionMode::Nearest, true, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![1]);
+
+        // Above the first bar of dataset 0 nothing is intersected
+        let hits = tester.query(dvec2(5.0, 10.0), InteractionMode::Nearest, true, InteractionAxis::XY);
+        assert!(hits.is_empty());
+
+        let hits = tester.query(dvec2(5.0, 10.0), InteractionMode::Nearest, false, InteractionAxis::X);
+        assert_eq!(ids(hits), vec![0]);
+    }
+
+    #[test]
+    fn test_index_and_dataset_modes() {
+        let tester = grouped_bars();
+        let hits = tester.query(dvec2(45.0, 80.0), InteractionMode::Index, true, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![2, 3]);
+
+        let hits = tester.query(dvec2(45.0, 80.0), InteractionMode::Dataset, true, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_axis_modes() {
+        let tester = grouped_bars();
+        let hits = tester.query(dvec2(55.0, 0.0), InteractionMode::X, true, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![3]);
+
+        let hits = tester.query(dvec2(30.0, 25.0), InteractionMode::Y, true, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![1]);
+
+        // Between the groups only the non-intersecting variant finds bars
+        assert!(tester.query(dvec2(30.0, 0.0), InteractionMode::X, true, InteractionAxis::XY).is_empty());
+        let hits = tester.query(dvec2(33.0, 0.0), InteractionMode::X, false, InteractionAxis::XY);
+        assert_eq!(ids(hits), vec![2]);
+    }
 }
@@ -1,4 +1,10 @@
-// Interaction module - hit testing and event handling
+// Interaction module - hit testing, event handling and widget actions
 mod hit_test;
+mod action;
+mod picker;
+mod toggle;
 
 pub use hit_test::*;
+pub use action::*;
+pub use picker::*;
+pub use toggle::*;
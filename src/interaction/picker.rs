@@ -0,0 +1,48 @@
+use makepad_widgets::*;
+use crate::core::ChartData;
+use super::action::{ChartAction, ChartHit};
+
+/// Element under the pointer of a chart, as `(dataset_index, data_index)`. Sends
+/// `ChartAction::ElementHovered` or `HoverCleared` when it changes and
+/// `ElementClicked` on clicks, so every chart reports the pointer the same way.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElementPicker {
+    active: Option<(usize, usize)>,
+}
+
+impl ElementPicker {
+    /// Element under the pointer, if any
+    pub fn active(&self) -> Option<(usize, usize)> {
+        self.active
+    }
+
+    /// Make `element` the one under the pointer, sending an action when it changed
+    pub fn set_active(
+        &mut self,
+        cx: &mut Cx,
+        scope: &mut Scope,
+        uid: WidgetUid,
+        data: &ChartData,
+        element: Option<(usize, usize)>,
+    ) {
+        if element == self.active {
+            return;
+        }
+        self.active = element;
+
+        let action = match element.and_then(|(dataset_index, data_index)| ChartHit::from_data(data, dataset_index, data_index)) {
+            Some(hit) => ChartAction::ElementHovered(hit),
+            None => ChartAction::HoverCleared,
+        };
+        cx.widget_action(uid, &scope.path, action);
+    }
+
+    /// Send `ElementClicked` for `element` when it is a data point of `data`
+    pub fn click(&self, cx: &mut Cx, scope: &mut Scope, uid: WidgetUid, data: &ChartData, element: Option<(usize, usize)>) {
+        let hit = element.and_then(|(dataset_index, data_index)| ChartHit::from_data(data, dataset_index, data_index));
+        if let Some(hit) = hit {
+            cx.widget_action(uid, &scope.path, ChartAction::ElementClicked(hit));
+        }
+    }
+}
+
@@ -0,0 +1,28 @@
+use makepad_widgets::*;
+use crate::core::ChartData;
+use super::action::ChartAction;
+
+/// Showing and hiding datasets from the legend, the same way in every chart with
+/// one: the chart is laid out again for the visible datasets and redrawn in place,
+/// without replaying its intro animation.
+pub trait DatasetToggle: Widget {
+    /// Data whose datasets the legend shows and hides
+    fn toggled_data(&mut self) -> &mut ChartData;
+
+    /// Recompute the ranges, and whatever else follows the visible datasets
+    fn refresh_ranges(&mut self);
+
+    /// Show or hide dataset `index`, redraw and send `ChartAction::DatasetToggled`
+    fn toggle_dataset(&mut self, cx: &mut Cx, scope: &mut Scope, index: usize) {
+        let data = self.toggled_data();
+        data.toggle_dataset(index);
+        let visible = data.datasets.get(index).map(|dataset| !dataset.hidden);
+        self.refresh_ranges();
+        self.redraw(cx);
+
+        if let Some(visible) = visible {
+            let uid = self.widget_uid();
+            cx.widget_action(uid, &scope.path, ChartAction::DatasetToggled { dataset_index: index, visible });
+        }
+    }
+}
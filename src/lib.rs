@@ -13,20 +13,25 @@ pub mod chart;
 
 // Re-exports for convenience
 pub use core::{
-    ChartData, Dataset, DataPoint,
+    ChartData, Dataset, DataPoint, ChartAlign,
     ChartOptions, TitleOptions, LegendOptions, LegendPosition,
     TooltipOptions, AnimationOptions, EasingType, InteractionMode,
-    AxisOptions, GridOptions, TickOptions,
+    AxisOptions, GridOptions, TickOptions, AxisType,
+    TimeOptions, TimeDisplayFormats, TimeUnit, TimeDistribution,
+    ZoomOptions, ZoomLimits, InteractionAxis, CrosshairOptions, BrushOptions,
+    DecimationOptions, DecimationAlgorithm, LineJoin, LineCap, SpanGaps, FillTarget, StackMode, AxisPosition,
+    CornerRadius, BorderSkipped, BarThickness, DataLabelOptions, DataLabelContext, LabelAlign, LabelContent, LabelOverlap,
+    Annotation, AnnotationKind, AnnotationDrawTime,
     get_color, get_color_alpha, lighten, darken, CHART_COLORS,
 };
 
-pub use scale::{Scale, Tick, LinearScale, CategoryScale};
-pub use coord::{CartesianCoord, PolarCoord, ChartArea, ScaleType};
-pub use element::{DrawBar, DrawChartLine, DrawPoint, DrawArc, PointStyle};
-pub use component::{ChartAxis, ChartGrid, ChartLegend, ChartTooltip, ChartTitle};
+pub use scale::{Scale, Tick, LinearScale, LogarithmicScale, CategoryScale, TimeScale, format_time};
+pub use coord::{CartesianCoord, PolarCoord, ChartArea, ScaleType, AnnotationShape};
+pub use element::{DrawBar, DrawChartLine, DrawPolyline, DrawPoint, DrawArc, PointStyle};
+pub use component::{ChartAxis, ChartGrid, ChartLegend, LegendItemData, ChartTooltip, TooltipContent, TooltipItem, ChartTitle, ChartZoom, ChartCrosshair, ChartBrush, ChartDataLabels, ChartAnnotations};
 pub use chart::{BarChart, LineChart, PieChart, ScatterChart};
 pub use animation::{ChartAnimator, AnimationManager, apply_easing, interpolate};
-pub use interaction::{HitTester, HitRegion, HitData};
+pub use interaction::{HitTester, HitRegion, HitData, ChartAction, ChartHit, VisibleRange, ChartSelection, AnnotationHit, ChartActions, CartesianChartActions, ElementPicker};
 
 /// Register all live designs for makepad-charts
 pub fn live_design(cx: &mut Cx) {
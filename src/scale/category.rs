@@ -14,6 +14,12 @@ pub struct CategoryScale {
     // Options
     /// Offset the bars/points from grid lines
     offset: bool,
+
+    /// Fraction of a band every category is moved by, used to scroll streamed data
+    shift: f64,
+
+    /// Visible range of category positions, all categories when `None`
+    view: Option<(f64, f64)>,
 }
 
 impl CategoryScale {
@@ -24,6 +30,8 @@ impl CategoryScale {
             pixel_start: 0.0,
             pixel_end: 100.0,
             offset: true, // Default: center items between grid lines
+            shift: 0.0,
+            view: None,
         }
     }
 
@@ -41,6 +49,35 @@ impl CategoryScale {
         self
     }
 
+    /// Replace the labels, keeping the pixel range and options
+    pub fn set_labels(&mut self, labels: Vec<String>) {
+        self.labels = labels;
+    }
+
+    /// Move every category by `shift` bands (positive moves towards the end of the axis)
+    pub fn set_shift(&mut self, shift: f64) {
+        self.shift = shift;
+    }
+
+    /// Current shift in bands
+    pub fn shift(&self) -> f64 {
+        self.shift
+    }
+
+    /// Show only the category positions `min..=max`, which may be fractional (used by zoom and pan).
+    /// `None` shows every category.
+    pub fn set_view(&mut self, view: Option<(f64, f64)>) {
+        self.view = view.filter(|(min, max)| max >= min);
+    }
+
+    /// First visible position and the number of bands across the axis
+    fn window(&self) -> (f64, f64) {
+        match self.view {
+            Some((min, max)) => (min, max - min + 1.0),
+            None => (0.0, self.labels.len() as f64),
+        }
+    }
+
     /// Get the number of categories
     pub fn len(&self) -> usize {
         self.labels.len()
@@ -67,52 +104,70 @@ impl CategoryScale {
             return 0.0;
         }
 
-        let pixel_range = (self.pixel_end - self.pixel_start).abs();
-        pixel_range / self.labels.len() as f64
+        self.get_band_step().abs()
     }
 
     /// Get the bar width (actual bar width, accounting for padding)
     /// bar_percent is typically 0.8 (80% of band width)
+    #[deprecated(note = "use BarRuler, which sizes bars per dataset")]
     pub fn get_bar_width(&self, bar_percent: f64) -> f64 {
         self.get_band_width() * bar_percent.clamp(0.1, 1.0)
     }
 
+    /// Signed distance between neighbouring bands, negative for reversed pixel ranges
+    fn get_band_step(&self) -> f64 {
+        if self.labels.is_empty() {
+            return 0.0;
+        }
+        (self.pixel_end - self.pixel_start) / self.window().1
+    }
+
     /// Get pixel position for a category index
     pub fn get_pixel_for_index(&self, index: usize) -> f64 {
+        self.get_pixel_for_position(index as f64)
+    }
+
+    /// Get pixel position for a fractional category position
+    fn get_pixel_for_position(&self, position: f64) -> f64 {
         if self.labels.is_empty() {
             return self.pixel_start;
         }
 
-        let band_width = self.get_band_width();
-        let base_pixel = self.pixel_start + (index as f64 * band_width);
+        let band_step = self.get_band_step();
+        let base_pixel = self.pixel_start + ((position - self.window().0 + self.shift) * band_step);
 
         if self.offset {
             // Center of the band
-            base_pixel + band_width / 2.0
+            base_pixel + band_step / 2.0
         } else {
             // Start of the band (on grid line)
             base_pixel
         }
     }
 
-    /// Get category index for a pixel position
-    pub fn get_index_for_pixel(&self, pixel: f64) -> usize {
-        if self.labels.is_empty() {
-            return 0;
-        }
-
-        let band_width = self.get_band_width();
-        if band_width == 0.0 {
-            return 0;
+    /// Get the fractional category position for a pixel position
+    pub fn get_position_for_pixel(&self, pixel: f64) -> f64 {
+        let band_step = self.get_band_step();
+        if band_step == 0.0 {
+            return 0.0;
         }
 
         let adjusted_pixel = if self.offset {
-            pixel - band_width / 2.0
+            pixel - band_step / 2.0
         } else {
             pixel
         };
 
-        let index = ((adjusted_pixel - self.pixel_start) / band_width).round() as i64;
+        (adjusted_pixel - self.pixel_start) / band_step - self.shift + self.window().0
+    }
+
+    /// Get category index for a pixel position
+    pub fn get_index_for_pixel(&self, pixel: f64) -> usize {
+        if self.labels.is_empty() {
+            return 0;
+        }
+
+        let index = self.get_position_for_pixel(pixel).round() as i64;
         index.clamp(0, (self.labels.len() - 1) as i64) as usize
     }
 }
@@ -140,7 +195,7 @@ impl Scale for CategoryScale {
 
     fn get_pixel_for_value(&self, value: f64) -> f64 {
         // Value is interpreted as category index
-        self.get_pixel_for_index(value.round() as usize)
+        self.get_pixel_for_position(value)
     }
 
     fn get_value_for_pixel(&self, pixel: f64) -> f64 {
@@ -151,15 +206,20 @@ impl Scale for CategoryScale {
     fn build_ticks(&self, options: &TickOptions) -> Vec<Tick> {
         let mut ticks = Vec::with_capacity(self.labels.len());
 
+        // Only the categories inside the view get ticks
+        let (first, count) = self.window();
+        let visible = first.ceil().max(0.0) as usize..=(first + count - 1.0 + 1e-9).floor().max(0.0) as usize;
+        let visible_count = if self.view.is_some() { count.floor().max(1.0) as usize } else { self.labels.len() };
+
         // Apply max_ticks_limit
-        let step = if self.labels.len() > options.max_ticks_limit && options.max_ticks_limit > 0 {
-            (self.labels.len() as f64 / options.max_ticks_limit as f64).ceil() as usize
+        let step = if visible_count > options.max_ticks_limit && options.max_ticks_limit > 0 {
+            (visible_count as f64 / options.max_ticks_limit as f64).ceil() as usize
         } else {
             1
         };
 
         for (i, label) in self.labels.iter().enumerate() {
-            if i % step == 0 {
+            if i % step == 0 && visible.contains(&i) {
                 ticks.push(Tick::new(i as f64, label.clone()));
             }
         }
@@ -169,7 +229,7 @@ impl Scale for CategoryScale {
 
     fn get_data_bounds(&self) -> (f64, f64) {
         // Return index range
-        (0.0, (self.labels.len().saturating_sub(1)) as f64)
+        self.view.unwrap_or((0.0, (self.labels.len().saturating_sub(1)) as f64))
     }
 
     fn get_pixel_bounds(&self) -> (f64, f64) {
@@ -220,6 +280,8 @@ impl CategoryScaleBuilder {
             pixel_start: 0.0,
             pixel_end: 100.0,
             offset: self.offset,
+            shift: 0.0,
+            view: None,
         }
     }
 }
@@ -315,6 +377,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_bar_width() {
         let mut scale = CategoryScale::new()
             .with_labels(vec!["A".into(), "B".into(), "C".into(), "D".into()]);
@@ -324,4 +387,44 @@ mod tests {
         assert_eq!(scale.get_bar_width(0.8), 80.0);
         assert_eq!(scale.get_bar_width(0.5), 50.0);
     }
+
+    #[test]
+    fn test_category_scale_reversed() {
+        let mut scale = CategoryScale::new()
+            .with_offset(true)
+            .with_labels(vec!["A".into(), "B".into(), "C".into(), "D".into()]);
+        scale.set_pixel_range(400.0, 0.0);
+
+        assert_eq!(scale.get_band_width(), 100.0);
+        assert_eq!(scale.get_pixel_for_index(0), 350.0);
+        assert_eq!(scale.get_index_for_pixel(60.0), 3);
+    }
+
+    #[test]
+    fn test_category_scale_shift() {
+        let mut scale = CategoryScale::new()
+            .with_offset(false)
+            .with_labels(vec!["A".into(), "B".into(), "C".into(), "D".into()]);
+        scale.set_pixel_range(0.0, 400.0);
+        scale.set_shift(0.5);
+
+        assert_eq!(scale.get_pixel_for_index(1), 150.0);
+        assert_eq!(scale.get_index_for_pixel(150.0), 1);
+    }
+
+    #[test]
+    fn test_category_scale_view() {
+        let mut scale = CategoryScale::new()
+            .with_offset(false)
+            .with_labels(vec!["A".into(), "B".into(), "C".into(), "D".into()]);
+        scale.set_pixel_range(0.0, 400.0);
+        scale.set_view(Some((1.0, 2.0)));
+
+        assert_eq!(scale.get_band_width(), 200.0);
+        assert_eq!(scale.get_pixel_for_index(2), 200.0);
+        assert_eq!(scale.get_position_for_pixel(100.0), 1.5);
+
+        let labels: Vec<String> = scale.build_ticks(&TickOptions::default()).into_iter().map(|t| t.label).collect();
+        assert_eq!(labels, vec!["B", "C"]);
+    }
 }
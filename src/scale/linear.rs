@@ -1,6 +1,6 @@
 use super::traits::{Scale, Tick};
 use super::utils::{nice_step, nice_bounds, format_number};
-use crate::core::TickOptions;
+use crate::core::{AxisOptions, TickOptions};
 
 /// Linear scale for continuous numeric data
 #[derive(Clone, Debug)]
@@ -17,6 +17,12 @@ pub struct LinearScale {
     begin_at_zero: bool,
     nice: bool,
     clamp: bool,
+
+    // Bounds from axis options
+    fixed_min: Option<f64>,
+    fixed_max: Option<f64>,
+    suggested_min: Option<f64>,
+    suggested_max: Option<f64>,
 }
 
 impl LinearScale {
@@ -30,6 +36,10 @@ impl LinearScale {
             begin_at_zero: false,
             nice: true,
             clamp: false,
+            fixed_min: None,
+            fixed_max: None,
+            suggested_min: None,
+            suggested_max: None,
         }
     }
 
@@ -51,6 +61,37 @@ impl LinearScale {
         self
     }
 
+    /// Set fixed bounds that override the data range (and nice rounding)
+    pub fn with_bounds(mut self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.fixed_min = min;
+        self.fixed_max = max;
+        self
+    }
+
+    /// Set suggested bounds, used only when the data does not go beyond them
+    pub fn with_suggested_bounds(mut self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.suggested_min = min;
+        self.suggested_max = max;
+        self
+    }
+
+    /// Apply `begin_at_zero`, `min`/`max` and `suggested_min`/`suggested_max` from axis options
+    pub fn apply_axis_options(&mut self, axis: &AxisOptions) {
+        self.begin_at_zero = axis.begin_at_zero;
+        self.fixed_min = axis.min;
+        self.fixed_max = axis.max;
+        self.suggested_min = axis.suggested_min;
+        self.suggested_max = axis.suggested_max;
+    }
+
+    /// Show exactly `min..max`, skipping nice rounding and axis bounds (used by zoom and pan)
+    pub fn set_view_range(&mut self, min: f64, max: f64) {
+        if max > min {
+            self.data_min = min;
+            self.data_max = max;
+        }
+    }
+
     /// Create with specific data range
     pub fn with_range(mut self, min: f64, max: f64) -> Self {
         self.set_data_range(min, max);
@@ -91,8 +132,8 @@ impl Scale for LinearScale {
     }
 
     fn set_data_range(&mut self, min: f64, max: f64) {
-        let mut min = min;
-        let mut max = max;
+        let mut min = self.suggested_min.map_or(min, |s| s.min(min));
+        let mut max = self.suggested_max.map_or(max, |s| s.max(max));
 
         // Handle begin at zero FIRST (before nice bounds)
         // This ensures y=0 aligns with chart_area.bottom for bar charts
@@ -118,6 +159,14 @@ impl Scale for LinearScale {
             }
         }
 
+        // Fixed bounds win over the data and nice rounding
+        if let Some(fixed) = self.fixed_min {
+            min = fixed;
+        }
+        if let Some(fixed) = self.fixed_max {
+            max = fixed;
+        }
+
         // Ensure we have a valid range
         if (max - min).abs() < f64::EPSILON {
             min -= 1.0;
@@ -266,4 +315,27 @@ mod tests {
         // Last tick should be at or near 100
         assert!(ticks.last().unwrap().value >= 100.0);
     }
+
+    #[test]
+    fn test_fixed_bounds_override_nice_range() {
+        let scale = LinearScale::new()
+            .with_bounds(Some(5.0), None)
+            .with_range(7.0, 93.0);
+        assert_eq!(scale.get_data_bounds(), (5.0, 100.0));
+    }
+
+    #[test]
+    fn test_suggested_bounds_only_extend() {
+        let scale = LinearScale::new()
+            .with_nice(false)
+            .with_suggested_bounds(Some(10.0), Some(50.0))
+            .with_range(0.0, 80.0);
+        assert_eq!(scale.get_data_bounds(), (0.0, 80.0));
+
+        let scale = LinearScale::new()
+            .with_nice(false)
+            .with_suggested_bounds(None, Some(200.0))
+            .with_range(0.0, 80.0);
+        assert_eq!(scale.get_data_bounds(), (0.0, 200.0));
+    }
 }
@@ -0,0 +1,322 @@
+use super::traits::{Scale, Tick};
+use super::utils::format_number;
+use crate::core::{AxisOptions, TickOptions};
+
+/// Logarithmic scale for positive data spanning several orders of magnitude.
+///
+/// Zero and negative values have no logarithm; they are mapped to the bottom
+/// of the scale so bars and areas still reach the axis. When the data range
+/// reaches zero or below, the scale starts at the smallest positive value set
+/// with `set_min_positive`, which also makes `begin_at_zero` have no effect.
+#[derive(Clone, Debug)]
+pub struct LogarithmicScale {
+    // Data range, always positive
+    data_min: f64,
+    data_max: f64,
+
+    // Pixel range
+    pixel_start: f64,
+    pixel_end: f64,
+
+    // Options
+    base: f64,
+    nice: bool,
+
+    // Bounds from axis options
+    fixed_min: Option<f64>,
+    fixed_max: Option<f64>,
+    suggested_min: Option<f64>,
+    suggested_max: Option<f64>,
+
+    // Smallest positive data value
+    min_positive: Option<f64>,
+}
+
+impl LogarithmicScale {
+    /// Create a new base 10 logarithmic scale
+    pub fn new() -> Self {
+        Self {
+            data_min: 1.0,
+            data_max: 10.0,
+            pixel_start: 0.0,
+            pixel_end: 100.0,
+            base: 10.0,
+            nice: true,
+            fixed_min: None,
+            fixed_max: None,
+            suggested_min: None,
+            suggested_max: None,
+            min_positive: None,
+        }
+    }
+
+    /// Set the logarithm base (values <= 1 fall back to 10)
+    pub fn with_base(mut self, base: f64) -> Self {
+        self.base = if base > 1.0 { base } else { 10.0 };
+        self
+    }
+
+    /// Set whether to round the bounds out to powers of the base
+    pub fn with_nice(mut self, nice: bool) -> Self {
+        self.nice = nice;
+        self
+    }
+
+    /// Apply `min`/`max`, `suggested_min`/`suggested_max` and `log_base` from axis options
+    pub fn apply_axis_options(&mut self, axis: &AxisOptions) {
+        self.base = if axis.log_base > 1.0 { axis.log_base } else { 10.0 };
+        self.fixed_min = axis.min.filter(|v| *v > 0.0);
+        self.fixed_max = axis.max.filter(|v| *v > 0.0);
+        self.suggested_min = axis.suggested_min.filter(|v| *v > 0.0);
+        self.suggested_max = axis.suggested_max.filter(|v| *v > 0.0);
+    }
+
+    /// Start the scale at `value`, the smallest positive data value, when the data
+    /// range reaches zero or below
+    pub fn set_min_positive(&mut self, value: Option<f64>) {
+        self.min_positive = value.filter(|v| *v > 0.0 && v.is_finite());
+    }
+
+    /// Show exactly `min..max`, skipping rounding to powers and axis bounds (used by zoom and pan)
+    pub fn set_view_range(&mut self, min: f64, max: f64) {
+        if min > 0.0 && max > min {
+            self.data_min = min;
+            self.data_max = max;
+        }
+    }
+
+    /// Logarithm base
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
+    fn log(&self, value: f64) -> f64 {
+        value.ln() / self.base.ln()
+    }
+
+    /// Largest power of the base not above `value`, tolerant to rounding in `log`
+    fn floor_power(&self, value: f64) -> i32 {
+        (self.log(value) + 1e-9).floor() as i32
+    }
+
+    /// Smallest power of the base not below `value`, tolerant to rounding in `log`
+    fn ceil_power(&self, value: f64) -> i32 {
+        (self.log(value) - 1e-9).ceil() as i32
+    }
+
+    /// Position of `value` between the data bounds, 0.0 at min and 1.0 at max
+    fn get_ratio(&self, value: f64) -> f64 {
+        if value <= 0.0 {
+            return 0.0;
+        }
+        let min = self.log(self.data_min);
+        let range = self.log(self.data_max) - min;
+        if range.abs() < f64::EPSILON {
+            0.5
+        } else {
+            (self.log(value) - min) / range
+        }
+    }
+}
+
+impl Default for LogarithmicScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scale for LogarithmicScale {
+    fn scale_type(&self) -> &'static str {
+        "logarithmic"
+    }
+
+    fn set_data_range(&mut self, min: f64, max: f64) {
+        // Zero or negative minimums start at the smallest positive data value
+        let min = if min > 0.0 { Some(min) } else { self.min_positive };
+        let min = match (min, self.suggested_min) {
+            (Some(min), Some(suggested)) => Some(min.min(suggested)),
+            (min, suggested) => min.or(suggested),
+        };
+        let max = self.suggested_max.map_or(max, |s| s.max(max));
+
+        // Without positive data show the first decade
+        let mut max = if max > 0.0 { max } else { self.base };
+        // Without a positive minimum start one power below the maximum
+        let mut min = min.unwrap_or_else(|| self.base.powi(self.floor_power(max) - 1));
+
+        if self.nice {
+            min = self.base.powi(self.floor_power(min));
+            max = self.base.powi(self.ceil_power(max));
+        }
+
+        if let Some(fixed) = self.fixed_min {
+            min = fixed;
+        }
+        if let Some(fixed) = self.fixed_max {
+            max = fixed;
+        }
+
+        // Ensure we have a valid range
+        if max <= min {
+            max = min * self.base;
+        }
+
+        self.data_min = min;
+        self.data_max = max;
+    }
+
+    fn set_pixel_range(&mut self, start: f64, end: f64) {
+        self.pixel_start = start;
+        self.pixel_end = end;
+    }
+
+    fn get_pixel_for_value(&self, value: f64) -> f64 {
+        self.pixel_start + self.get_ratio(value) * (self.pixel_end - self.pixel_start)
+    }
+
+    fn get_value_for_pixel(&self, pixel: f64) -> f64 {
+        let pixel_range = self.pixel_end - self.pixel_start;
+        if pixel_range.abs() < f64::EPSILON {
+            return self.data_min;
+        }
+        let ratio = (pixel - self.pixel_start) / pixel_range;
+        let min = self.log(self.data_min);
+        let max = self.log(self.data_max);
+        self.base.powf(min + ratio * (max - min))
+    }
+
+    fn build_ticks(&self, options: &TickOptions) -> Vec<Tick> {
+        let epsilon = 1e-9;
+        let first_power = self.floor_power(self.data_min);
+        let last_power = self.ceil_power(self.data_max);
+        let decades = (last_power - first_power).max(1) as usize;
+
+        // Skip powers (and drop minor ticks) when there are too many decades
+        let max_ticks = options.max_ticks_limit.max(2);
+        let power_step = decades.div_ceil(max_ticks).max(1) as i32;
+        let with_minor = power_step == 1 && self.base.fract() == 0.0;
+        // Label the 2x and 5x minor ticks only when a few decades are visible
+        let label_minor = with_minor && decades <= 2;
+
+        let in_range = |v: f64| v >= self.data_min * (1.0 - epsilon) && v <= self.data_max * (1.0 + epsilon);
+
+        let mut ticks = Vec::new();
+
+        if options.include_bounds {
+            ticks.push(Tick::new(self.data_min, format_number(self.data_min)));
+        }
+
+        let mut power = first_power;
+        while power <= last_power {
+            let major = self.base.powi(power);
+            if in_range(major) {
+                ticks.push(Tick::new(major, format_number(major)));
+            }
+            if with_minor {
+                for m in 2..self.base as u32 {
+                    let value = major * m as f64;
+                    if !in_range(value) {
+                        continue;
+                    }
+                    let label = if label_minor && (m == 2 || m == 5) { format_number(value) } else { String::new() };
+                    ticks.push(Tick::minor(value, label));
+                }
+            }
+            power += power_step;
+        }
+
+        if options.include_bounds {
+            ticks.push(Tick::new(self.data_max, format_number(self.data_max)));
+        }
+
+        // Bounds that land on a power duplicate it
+        ticks.sort_by(|a, b| a.value.total_cmp(&b.value));
+        ticks.dedup_by(|b, a| (a.value - b.value).abs() <= a.value.abs() * epsilon);
+        ticks
+    }
+
+    fn get_data_bounds(&self) -> (f64, f64) {
+        (self.data_min, self.data_max)
+    }
+
+    fn get_pixel_bounds(&self) -> (f64, f64) {
+        (self.pixel_start, self.pixel_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_mapping() {
+        let mut scale = LogarithmicScale::new();
+        scale.set_data_range(3.0, 700.0);
+        scale.set_pixel_range(0.0, 300.0);
+
+        assert_eq!(scale.get_data_bounds(), (1.0, 1000.0));
+        assert!((scale.get_pixel_for_value(10.0) - 100.0).abs() < 1e-9);
+        assert!((scale.get_pixel_for_value(100.0) - 200.0).abs() < 1e-9);
+        assert!((scale.get_value_for_pixel(200.0) - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zero_and_negative_values() {
+        let mut scale = LogarithmicScale::new();
+        scale.set_data_range(0.0, 500.0);
+        scale.set_pixel_range(0.0, 100.0);
+
+        assert_eq!(scale.get_data_bounds(), (10.0, 1000.0));
+        assert_eq!(scale.get_pixel_for_value(0.0), 0.0);
+        assert_eq!(scale.get_pixel_for_value(-5.0), 0.0);
+
+        scale.set_data_range(-10.0, -1.0);
+        assert_eq!(scale.get_data_bounds(), (1.0, 10.0));
+    }
+
+    #[test]
+    fn test_zero_in_data_keeps_smallest_positive_value() {
+        // Data [0, 1, 10, 100, 1000], or a range forced to zero by begin_at_zero
+        let mut scale = LogarithmicScale::new();
+        scale.set_min_positive(Some(1.0));
+        scale.set_data_range(0.0, 1000.0);
+        assert_eq!(scale.get_data_bounds(), (1.0, 1000.0));
+
+        scale.set_min_positive(Some(3.0));
+        scale.set_data_range(0.0, 700.0);
+        assert_eq!(scale.get_data_bounds(), (1.0, 1000.0));
+    }
+
+    #[test]
+    fn test_major_and_minor_ticks() {
+        let mut scale = LogarithmicScale::new();
+        scale.set_data_range(1.0, 100.0);
+
+        let ticks = scale.build_ticks(&TickOptions::default());
+        let majors: Vec<f64> = ticks.iter().filter(|t| t.major).map(|t| t.value).collect();
+        assert_eq!(majors, vec![1.0, 10.0, 100.0]);
+        assert_eq!(ticks.iter().filter(|t| !t.major).count(), 16);
+        assert!(ticks.iter().any(|t| !t.major && t.value == 20.0 && t.label == "20"));
+        assert!(ticks.iter().any(|t| !t.major && t.value == 30.0 && t.label.is_empty()));
+    }
+
+    #[test]
+    fn test_many_decades_skip_powers() {
+        let mut scale = LogarithmicScale::new();
+        scale.set_data_range(1.0, 1e20);
+
+        let ticks = scale.build_ticks(&TickOptions::default());
+        assert!(ticks.iter().all(|t| t.major));
+        assert!(ticks.len() <= 12);
+    }
+
+    #[test]
+    fn test_custom_base() {
+        let mut scale = LogarithmicScale::new().with_base(2.0);
+        scale.set_data_range(1.0, 16.0);
+
+        let options = TickOptions { include_bounds: false, ..TickOptions::default() };
+        let values: Vec<f64> = scale.build_ticks(&options).iter().map(|t| t.value).collect();
+        assert_eq!(values, vec![1.0, 2.0, 4.0, 8.0, 16.0]);
+    }
+}
@@ -1,9 +1,13 @@
 mod traits;
 mod linear;
+mod logarithmic;
 mod category;
+mod time;
 mod utils;
 
 pub use traits::*;
 pub use linear::*;
+pub use logarithmic::*;
 pub use category::*;
+pub use time::*;
 pub use utils::*;
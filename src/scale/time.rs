@@ -0,0 +1,602 @@
+use super::traits::{Scale, Tick};
+use super::utils::nice_step;
+use crate::core::{AxisOptions, TickOptions, TimeDistribution, TimeOptions, TimeUnit};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday",
+];
+
+/// Calendar date and time broken down from a Unix timestamp
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalendarTime {
+    pub year: i64,
+    /// Month of the year, 1-12
+    pub month: u32,
+    /// Day of the month, 1-31
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    /// Day of the week, 0 = Monday
+    pub weekday: u32,
+}
+
+impl CalendarTime {
+    /// Break down a Unix timestamp in seconds (proleptic Gregorian calendar)
+    pub fn from_timestamp(timestamp: i64) -> Self {
+        let days = timestamp.div_euclid(SECONDS_PER_DAY);
+        let secs = timestamp.rem_euclid(SECONDS_PER_DAY) as u32;
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year,
+            month,
+            day,
+            hour: secs / 3_600,
+            minute: secs / 60 % 60,
+            second: secs % 60,
+            // 1970-01-01 was a Thursday
+            weekday: (days + 3).rem_euclid(7) as u32,
+        }
+    }
+
+    /// Unix timestamp in seconds of this date and time
+    pub fn to_timestamp(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * SECONDS_PER_DAY
+            + (self.hour * 3_600 + self.minute * 60 + self.second) as i64
+    }
+}
+
+/// Days since 1970-01-01 for a civil date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date for a number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Format a Unix timestamp (seconds) with a pattern, shifted by a fixed UTC offset.
+///
+/// Supported tokens: `yyyy`, `yy`, `Q`, `MMMM`, `MMM`, `MM`, `M`, `dd`, `d`,
+/// `EEEE`, `EEE`, `HH`, `H`, `hh`, `h`, `mm`, `m`, `ss`, `s` and `a`.
+/// Text inside single quotes is copied as is, `''` gives a single quote.
+pub fn format_time(timestamp: f64, pattern: &str, utc_offset_minutes: i32) -> String {
+    let local = timestamp.floor() as i64 + utc_offset_minutes as i64 * 60;
+    let t = CalendarTime::from_timestamp(local);
+    let hour12 = match t.hour % 12 {
+        0 => 12,
+        h => h,
+    };
+
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\'' {
+            if chars.get(i + 1) == Some(&'\'') {
+                out.push('\'');
+                i += 2;
+                continue;
+            }
+            i += 1;
+            while i < chars.len() && chars[i] != '\'' {
+                out.push(chars[i]);
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        let mut run = 1;
+        while i + run < chars.len() && chars[i + run] == c {
+            run += 1;
+        }
+
+        match (c, run) {
+            ('y', 2) => out.push_str(&format!("{:02}", t.year.rem_euclid(100))),
+            ('y', _) => out.push_str(&t.year.to_string()),
+            ('Q', _) => out.push_str(&((t.month - 1) / 3 + 1).to_string()),
+            ('M', 1) => out.push_str(&t.month.to_string()),
+            ('M', 2) => out.push_str(&format!("{:02}", t.month)),
+            ('M', 3) => out.push_str(&MONTH_NAMES[t.month as usize - 1][..3]),
+            ('M', _) => out.push_str(MONTH_NAMES[t.month as usize - 1]),
+            ('d', 1) => out.push_str(&t.day.to_string()),
+            ('d', _) => out.push_str(&format!("{:02}", t.day)),
+            ('E', n) if n >= 4 => out.push_str(WEEKDAY_NAMES[t.weekday as usize]),
+            ('E', _) => out.push_str(&WEEKDAY_NAMES[t.weekday as usize][..3]),
+            ('H', 1) => out.push_str(&t.hour.to_string()),
+            ('H', _) => out.push_str(&format!("{:02}", t.hour)),
+            ('h', 1) => out.push_str(&hour12.to_string()),
+            ('h', _) => out.push_str(&format!("{:02}", hour12)),
+            ('m', 1) => out.push_str(&t.minute.to_string()),
+            ('m', _) => out.push_str(&format!("{:02}", t.minute)),
+            ('s', 1) => out.push_str(&t.second.to_string()),
+            ('s', _) => out.push_str(&format!("{:02}", t.second)),
+            ('a', _) => out.push_str(if t.hour < 12 { "AM" } else { "PM" }),
+            _ => {
+                for _ in 0..run {
+                    out.push(c);
+                }
+            }
+        }
+        i += run;
+    }
+    out
+}
+
+/// Start of the `step` x `unit` interval containing the local timestamp `t`
+fn floor_to_unit(t: i64, unit: TimeUnit, step: u32) -> i64 {
+    let step = step.max(1) as i64;
+    match unit {
+        TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour | TimeUnit::Day => {
+            let size = unit.seconds() as i64 * step;
+            t.div_euclid(size) * size
+        }
+        TimeUnit::Week => {
+            // Weeks start on Monday, counted from the one before 1970-01-01 (a Thursday)
+            let monday = -3 * SECONDS_PER_DAY;
+            let size = 7 * SECONDS_PER_DAY * step;
+            monday + (t - monday).div_euclid(size) * size
+        }
+        TimeUnit::Month | TimeUnit::Quarter => {
+            let months = if unit == TimeUnit::Quarter { step * 3 } else { step };
+            let c = CalendarTime::from_timestamp(t);
+            let month0 = c.month as i64 - 1;
+            let month0 = month0 - month0 % months;
+            days_from_civil(c.year, month0 as u32 + 1, 1) * SECONDS_PER_DAY
+        }
+        TimeUnit::Year => {
+            let c = CalendarTime::from_timestamp(t);
+            let year = c.year - c.year.rem_euclid(step);
+            days_from_civil(year, 1, 1) * SECONDS_PER_DAY
+        }
+    }
+}
+
+/// Advance the local timestamp `t` by `step` calendar units
+fn add_units(t: i64, unit: TimeUnit, step: u32) -> i64 {
+    let months = match unit {
+        TimeUnit::Month => step as i64,
+        TimeUnit::Quarter => step as i64 * 3,
+        TimeUnit::Year => step as i64 * 12,
+        _ => return t + unit.seconds() as i64 * step as i64,
+    };
+    let c = CalendarTime::from_timestamp(t);
+    let total = c.year * 12 + (c.month as i64 - 1) + months;
+    let date = CalendarTime {
+        year: total.div_euclid(12),
+        month: total.rem_euclid(12) as u32 + 1,
+        ..c
+    };
+    date.to_timestamp()
+}
+
+/// Least room in pixels between the ticks of a fixed unit and step size
+const MIN_TICK_SPACING: f64 = 40.0;
+
+/// Time scale for Unix timestamps (in seconds) with calendar aligned ticks
+#[derive(Clone, Debug)]
+pub struct TimeScale {
+    // Data range
+    data_min: f64,
+    data_max: f64,
+
+    // Pixel range
+    pixel_start: f64,
+    pixel_end: f64,
+
+    // Options
+    options: TimeOptions,
+
+    // Bounds from axis options
+    fixed_min: Option<f64>,
+    fixed_max: Option<f64>,
+    suggested_min: Option<f64>,
+    suggested_max: Option<f64>,
+
+    /// Sorted, distinct data timestamps used by the series distribution
+    timestamps: Vec<f64>,
+}
+
+impl TimeScale {
+    /// Create a new time scale
+    pub fn new() -> Self {
+        Self {
+            data_min: 0.0,
+            data_max: SECONDS_PER_DAY as f64,
+            pixel_start: 0.0,
+            pixel_end: 100.0,
+            options: TimeOptions::default(),
+            fixed_min: None,
+            fixed_max: None,
+            suggested_min: None,
+            suggested_max: None,
+            timestamps: Vec::new(),
+        }
+    }
+
+    /// Set all time options at once
+    pub fn with_options(mut self, options: TimeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Use a fixed tick unit instead of picking one from the range
+    pub fn with_unit(mut self, unit: TimeUnit) -> Self {
+        self.options.unit = Some(unit);
+        self
+    }
+
+    /// Set the fixed UTC offset in minutes used for tick placement and labels
+    pub fn with_utc_offset(mut self, minutes: i32) -> Self {
+        self.options.utc_offset_minutes = minutes;
+        self
+    }
+
+    /// Set how data is spread along the axis
+    pub fn with_distribution(mut self, distribution: TimeDistribution) -> Self {
+        self.options.distribution = distribution;
+        self
+    }
+
+    /// Set the data timestamps used by `TimeDistribution::Series`
+    pub fn with_timestamps(mut self, timestamps: Vec<f64>) -> Self {
+        self.set_timestamps(timestamps);
+        self
+    }
+
+    /// Replace the data timestamps used by `TimeDistribution::Series`
+    pub fn set_timestamps(&mut self, mut timestamps: Vec<f64>) {
+        timestamps.retain(|t| t.is_finite());
+        timestamps.sort_by(|a, b| a.total_cmp(b));
+        timestamps.dedup();
+        self.timestamps = timestamps;
+    }
+
+    /// Show exactly `min..max`, skipping axis bounds (used by zoom and pan)
+    pub fn set_view_range(&mut self, min: f64, max: f64) {
+        if max > min {
+            self.data_min = min;
+            self.data_max = max;
+        }
+    }
+
+    /// Apply `min`/`max`, `suggested_min`/`suggested_max` and the time options of an axis
+    pub fn apply_axis_options(&mut self, axis: &AxisOptions) {
+        self.options = axis.time.clone();
+        self.fixed_min = axis.min;
+        self.fixed_max = axis.max;
+        self.suggested_min = axis.suggested_min;
+        self.suggested_max = axis.suggested_max;
+    }
+
+    /// Current time options
+    pub fn options(&self) -> &TimeOptions {
+        &self.options
+    }
+
+    /// Format a timestamp with the display format of `unit`
+    pub fn format(&self, timestamp: f64, unit: TimeUnit) -> String {
+        format_time(timestamp, self.options.display_formats.get(unit), self.options.utc_offset_minutes)
+    }
+
+    /// Unit and step (in multiples of the unit) used for ticks over the current range
+    pub fn tick_unit(&self, options: &TickOptions) -> (TimeUnit, u32) {
+        let span = (self.data_max - self.data_min).max(1.0);
+        let max_ticks = options.max_ticks_limit.max(2) as f64;
+        let fixed_step = options.step_size.map(|s| s.round().max(1.0) as u32);
+        let fits = |unit: TimeUnit, step: u32| span / (unit.seconds() * step as f64) <= max_ticks;
+
+        if let Some(unit) = self.options.unit {
+            let step = fixed_step
+                .map(|step| self.fit_to_width(unit, step, span))
+                .or_else(|| unit.steps().iter().copied().find(|&s| fits(unit, s)))
+                .unwrap_or_else(|| Self::fallback_step(unit, span, max_ticks));
+            return (unit, step);
+        }
+
+        for unit in TimeUnit::ALL.into_iter().filter(|u| *u >= self.options.min_unit) {
+            let step = match fixed_step {
+                Some(step) => fits(unit, step).then_some(step),
+                None => unit.steps().iter().copied().find(|&s| fits(unit, s)),
+            };
+            if let Some(step) = step {
+                return (unit, step);
+            }
+        }
+
+        let step = fixed_step.unwrap_or_else(|| Self::fallback_step(TimeUnit::Year, span, max_ticks));
+        (TimeUnit::Year, step)
+    }
+
+    /// `step`, raised to a multiple of itself when its ticks over `span` would be
+    /// closer than `MIN_TICK_SPACING` along the axis
+    fn fit_to_width(&self, unit: TimeUnit, step: u32, span: f64) -> u32 {
+        let capacity = ((self.pixel_end - self.pixel_start).abs() / MIN_TICK_SPACING).max(2.0);
+        let count = span / (unit.seconds() * step as f64);
+        if count <= capacity {
+            return step;
+        }
+        (step as f64 * (count / capacity).ceil()).min(u32::MAX as f64) as u32
+    }
+
+    /// Step for ranges too long for the preset steps of `unit`
+    fn fallback_step(unit: TimeUnit, span: f64, max_ticks: f64) -> u32 {
+        nice_step(span / unit.seconds(), max_ticks as usize).ceil().max(1.0) as u32
+    }
+
+    fn offset_seconds(&self) -> i64 {
+        self.options.utc_offset_minutes as i64 * 60
+    }
+
+    fn is_series(&self) -> bool {
+        self.options.distribution == TimeDistribution::Series && self.timestamps.len() >= 2
+    }
+
+    /// Fractional index of `value` in the data timestamps, extrapolated past both ends
+    fn series_index(&self, value: f64) -> f64 {
+        let ts = &self.timestamps;
+        let last = ts.len() - 1;
+        let i = ts.partition_point(|t| *t <= value).clamp(1, last);
+        let (a, b) = (ts[i - 1], ts[i]);
+        (i - 1) as f64 + (value - a) / (b - a)
+    }
+
+    /// Timestamp at a fractional index of the data timestamps
+    fn series_value(&self, index: f64) -> f64 {
+        let ts = &self.timestamps;
+        let last = ts.len() - 1;
+        let i = (index.floor().max(0.0) as usize).min(last - 1);
+        let (a, b) = (ts[i], ts[i + 1]);
+        a + (index - i as f64) * (b - a)
+    }
+
+    /// Position of `value` between the data bounds, 0.0 at min and 1.0 at max
+    fn get_ratio(&self, value: f64) -> f64 {
+        if self.is_series() {
+            let min = self.series_index(self.data_min);
+            let max = self.series_index(self.data_max);
+            if (max - min).abs() < f64::EPSILON {
+                return 0.5;
+            }
+            return (self.series_index(value) - min) / (max - min);
+        }
+        let range = self.data_max - self.data_min;
+        if range.abs() < f64::EPSILON {
+            0.5
+        } else {
+            (value - self.data_min) / range
+        }
+    }
+
+    fn get_value_for_ratio(&self, ratio: f64) -> f64 {
+        if self.is_series() {
+            let min = self.series_index(self.data_min);
+            let max = self.series_index(self.data_max);
+            return self.series_value(min + ratio * (max - min));
+        }
+        self.data_min + ratio * (self.data_max - self.data_min)
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scale for TimeScale {
+    fn scale_type(&self) -> &'static str {
+        "time"
+    }
+
+    fn set_data_range(&mut self, min: f64, max: f64) {
+        let mut min = self.suggested_min.map_or(min, |s| s.min(min));
+        let mut max = self.suggested_max.map_or(max, |s| s.max(max));
+
+        if let Some(fixed) = self.fixed_min {
+            min = fixed;
+        }
+        if let Some(fixed) = self.fixed_max {
+            max = fixed;
+        }
+
+        // A single instant is shown as the day around it
+        if (max - min).abs() < f64::EPSILON {
+            min -= SECONDS_PER_DAY as f64 / 2.0;
+            max += SECONDS_PER_DAY as f64 / 2.0;
+        }
+
+        self.data_min = min;
+        self.data_max = max;
+    }
+
+    fn set_pixel_range(&mut self, start: f64, end: f64) {
+        self.pixel_start = start;
+        self.pixel_end = end;
+    }
+
+    fn get_pixel_for_value(&self, value: f64) -> f64 {
+        self.pixel_start + self.get_ratio(value) * (self.pixel_end - self.pixel_start)
+    }
+
+    fn get_value_for_pixel(&self, pixel: f64) -> f64 {
+        let pixel_range = self.pixel_end - self.pixel_start;
+        if pixel_range.abs() < f64::EPSILON {
+            return self.data_min;
+        }
+        self.get_value_for_ratio((pixel - self.pixel_start) / pixel_range)
+    }
+
+    fn build_ticks(&self, options: &TickOptions) -> Vec<Tick> {
+        let (unit, step) = self.tick_unit(options);
+        let offset = self.offset_seconds();
+
+        // Ticks are placed on calendar boundaries of the local time
+        let local_min = self.data_min.floor() as i64 + offset;
+        let local_max = self.data_max.ceil() as i64 + offset;
+
+        let mut ticks = Vec::new();
+        let mut t = floor_to_unit(local_min, unit, step);
+        while t <= local_max {
+            if t >= local_min {
+                let value = (t - offset) as f64;
+                if value >= self.data_min && value <= self.data_max {
+                    ticks.push(Tick::new(value, self.format(value, unit)));
+                }
+            }
+            t = add_units(t, unit, step);
+        }
+
+        // Bounds are only added when they do not crowd the nearest calendar tick
+        if options.include_bounds {
+            let min_gap = unit.seconds() * step as f64 / 2.0;
+            let first = ticks.first().map(|t| t.value).unwrap_or(f64::MAX);
+            if first - self.data_min >= min_gap {
+                ticks.insert(0, Tick::new(self.data_min, self.format(self.data_min, unit)));
+            }
+            let last = ticks.last().map(|t| t.value).unwrap_or(f64::MIN);
+            if self.data_max - last >= min_gap {
+                ticks.push(Tick::new(self.data_max, self.format(self.data_max, unit)));
+            }
+        }
+
+        ticks
+    }
+
+    fn get_data_bounds(&self) -> (f64, f64) {
+        (self.data_min, self.data_max)
+    }
+
+    fn get_pixel_bounds(&self) -> (f64, f64) {
+        (self.pixel_start, self.pixel_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-03-10 14:30:15 UTC, a Sunday
+    const T: i64 = 1_710_081_015;
+
+    #[test]
+    fn test_calendar_round_trip() {
+        let c = CalendarTime::from_timestamp(T);
+        assert_eq!((c.year, c.month, c.day), (2024, 3, 10));
+        assert_eq!((c.hour, c.minute, c.second), (14, 30, 15));
+        assert_eq!(c.weekday, 6);
+        assert_eq!(c.to_timestamp(), T);
+
+        let c = CalendarTime::from_timestamp(-1);
+        assert_eq!((c.year, c.month, c.day, c.hour), (1969, 12, 31, 23));
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(T as f64, "yyyy-MM-dd HH:mm:ss", 0), "2024-03-10 14:30:15");
+        assert_eq!(format_time(T as f64, "EEE MMM d, h:mm a", 0), "Sun Mar 10, 2:30 PM");
+        assert_eq!(format_time(T as f64, "'Q'Q yy", 0), "Q1 24");
+        // Fixed offsets move the local date
+        assert_eq!(format_time(T as f64, "MMMM d HH:mm", 600), "March 11 00:30");
+        assert_eq!(format_time(T as f64, "HH:mm", -330), "09:00");
+    }
+
+    #[test]
+    fn test_calendar_stepping() {
+        let jan31 = days_from_civil(2024, 1, 31) * SECONDS_PER_DAY;
+        let month = floor_to_unit(jan31, TimeUnit::Month, 1);
+        assert_eq!(add_units(month, TimeUnit::Month, 1), days_from_civil(2024, 2, 1) * SECONDS_PER_DAY);
+        assert_eq!(floor_to_unit(T, TimeUnit::Week, 1), days_from_civil(2024, 3, 4) * SECONDS_PER_DAY);
+        assert_eq!(floor_to_unit(T, TimeUnit::Quarter, 1), days_from_civil(2024, 1, 1) * SECONDS_PER_DAY);
+        assert_eq!(floor_to_unit(T, TimeUnit::Minute, 15), T - 15);
+        // Steps of days and weeks count from the epoch
+        assert_eq!(floor_to_unit(T, TimeUnit::Day, 1), days_from_civil(2024, 3, 10) * SECONDS_PER_DAY);
+        assert_eq!(floor_to_unit(T, TimeUnit::Day, 3), days_from_civil(2024, 3, 9) * SECONDS_PER_DAY);
+        assert_eq!(floor_to_unit(T, TimeUnit::Week, 2), days_from_civil(2024, 2, 26) * SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn test_unit_selection() {
+        let ticks = TickOptions::default();
+
+        let mut scale = TimeScale::new();
+        scale.set_data_range(T as f64, T as f64 + 3_600.0);
+        assert_eq!(scale.tick_unit(&ticks), (TimeUnit::Minute, 10));
+
+        scale.set_data_range(T as f64, T as f64 + 7.0 * 86_400.0);
+        assert_eq!(scale.tick_unit(&ticks), (TimeUnit::Day, 1));
+
+        scale.set_data_range(T as f64, T as f64 + 3.0 * 365.0 * 86_400.0);
+        assert_eq!(scale.tick_unit(&ticks), (TimeUnit::Quarter, 2));
+    }
+
+    #[test]
+    fn test_fixed_unit_ticks_fit_the_width() {
+        let mut scale = TimeScale::new().with_unit(TimeUnit::Second);
+        scale.set_pixel_range(0.0, 800.0);
+        let options = TickOptions { include_bounds: false, step_size: Some(5.0), ..TickOptions::default() };
+
+        // A minute in 5 second steps fits
+        scale.set_data_range(T as f64, T as f64 + 60.0);
+        assert_eq!(scale.tick_unit(&options), (TimeUnit::Second, 5));
+
+        // A year in seconds keeps the unit with a step of whole 5 seconds
+        scale.set_data_range(T as f64, T as f64 + 365.0 * 86_400.0);
+        let (unit, step) = scale.tick_unit(&options);
+        assert_eq!((unit, step % 5), (TimeUnit::Second, 0));
+        assert!(scale.build_ticks(&options).len() <= 21);
+    }
+
+    #[test]
+    fn test_month_ticks_are_calendar_aligned() {
+        let mut scale = TimeScale::new().with_unit(TimeUnit::Month);
+        let start = days_from_civil(2024, 1, 15) * SECONDS_PER_DAY;
+        let end = days_from_civil(2024, 5, 15) * SECONDS_PER_DAY;
+        scale.set_data_range(start as f64, end as f64);
+
+        let options = TickOptions { include_bounds: false, ..TickOptions::default() };
+        let labels: Vec<String> = scale.build_ticks(&options).into_iter().map(|t| t.label).collect();
+        assert_eq!(labels, vec!["Feb 2024", "Mar 2024", "Apr 2024", "May 2024"]);
+    }
+
+    #[test]
+    fn test_series_distribution() {
+        let times = vec![0.0, 10.0, 1_000.0];
+        let mut scale = TimeScale::new()
+            .with_distribution(TimeDistribution::Series)
+            .with_timestamps(times);
+        scale.set_data_range(0.0, 1_000.0);
+        scale.set_pixel_range(0.0, 200.0);
+
+        // Points are equally spaced regardless of the gap in time
+        assert_eq!(scale.get_pixel_for_value(10.0), 100.0);
+        assert_eq!(scale.get_pixel_for_value(505.0), 150.0);
+        assert_eq!(scale.get_value_for_pixel(100.0), 10.0);
+    }
+}